@@ -1,7 +1,7 @@
 use tauri::{AppHandle, State};
 use crate::config::ConfigManager;
-use crate::models::{ApiResult, LastNote};
-use crate::shortcuts::ShortcutManager;
+use crate::models::{ApiResult, LastNote, ShortcutActionInfo};
+use crate::shortcuts::{Action, ShortcutManager};
 
 /// Gets the last opened note information.
 /// 
@@ -57,16 +57,58 @@ pub async fn get_global_shortcut(config: State<'_, ConfigManager>) -> Result<Str
 pub async fn set_global_shortcut(
     key: String,
     app: AppHandle,
-    config: State<'_, ConfigManager>,
     shortcut_manager: State<'_, ShortcutManager>,
 ) -> Result<ApiResult, String> {
-    // Validate and register the new shortcut
-    if let Err(e) = shortcut_manager.update_toggle_shortcut(&app, &key) {
-        return Ok(ApiResult::error(e));
+    // rebind_action re-registers the hotkey and persists the change to config
+    match shortcut_manager.rebind_action(&app, Action::ToggleWindow, &key) {
+        Ok(()) => Ok(ApiResult::success()),
+        Err(e) => Ok(ApiResult::error(e)),
     }
-    
-    // Update config (already done in update_toggle_shortcut, but ensure it's saved)
-    config.set_global_shortcut(key);
-    config.schedule_save().await;
-    Ok(ApiResult::success())
+}
+
+/// Gets the quick-capture overlay shortcut configuration.
+///
+/// Returns the current quick-capture shortcut string.
+#[tauri::command]
+pub async fn get_quick_capture_shortcut(config: State<'_, ConfigManager>) -> Result<String, String> {
+    Ok(config.get_quick_note_shortcut())
+}
+
+/// Sets the quick-capture overlay shortcut configuration.
+///
+/// Validates the shortcut, re-registers it with the system, and persists to config.
+///
+/// # Arguments
+/// * `key` - The new shortcut string
+#[tauri::command]
+pub async fn set_quick_capture_shortcut(
+    key: String,
+    app: AppHandle,
+    shortcut_manager: State<'_, ShortcutManager>,
+) -> Result<ApiResult, String> {
+    match shortcut_manager.rebind_action(&app, Action::QuickNote, &key) {
+        Ok(()) => Ok(ApiResult::success()),
+        Err(e) => Ok(ApiResult::error(e)),
+    }
+}
+
+/// Lists every shortcut action with its description and currently bound key.
+///
+/// Intended for a settings/help UI to render a full editable keybinding table.
+///
+/// # Requirements
+/// Validates: Requirements 7.4, 7.5
+#[tauri::command]
+pub async fn list_shortcut_actions(
+    shortcut_manager: State<'_, ShortcutManager>,
+) -> Result<Vec<ShortcutActionInfo>, String> {
+    Ok(shortcut_manager
+        .list_actions()
+        .into_iter()
+        .map(|(id, description, current_key)| ShortcutActionInfo {
+            id,
+            description,
+            current_key,
+        })
+        .collect())
 }
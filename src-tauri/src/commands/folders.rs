@@ -1,6 +1,6 @@
 use tauri::State;
 use crate::filesystem::FileSystem;
-use crate::models::{ApiResult, FolderInfo};
+use crate::models::{ApiResult, BatchFolderResult, FolderInfo, TrashEntry};
 
 /// Lists all folders in the notes directory.
 /// 
@@ -14,10 +14,13 @@ pub async fn list_folders(filesystem: State<'_, FileSystem>) -> Result<Vec<Folde
 }
 
 /// Creates a new folder in the notes directory.
-/// 
+///
+/// `name` may nest a folder under others with a `/`-separated path (e.g.
+/// `"Projects/2024/Research"`); missing parent segments are created too.
+///
 /// # Arguments
-/// * `name` - The name of the folder to create
-/// 
+/// * `name` - The name (or nested path) of the folder to create
+///
 /// # Requirements
 /// Validates: Requirements 10.2
 #[tauri::command]
@@ -26,7 +29,7 @@ pub async fn create_folder(name: String, filesystem: State<'_, FileSystem>) -> R
         return Ok(ApiResult::error("Folder name cannot be empty or whitespace only".to_string()));
     }
 
-    if name.contains("..") || name.contains('/') || name.contains('\\') {
+    if name.contains("..") || name.contains('\\') {
         return Ok(ApiResult::error("Folder name cannot contain path traversal or separators".to_string()));
     }
 
@@ -40,20 +43,32 @@ pub async fn create_folder(name: String, filesystem: State<'_, FileSystem>) -> R
     }
 }
 
-/// Deletes a folder and all its contents from the notes directory.
-/// 
+/// Deletes a folder from the notes directory.
+///
+/// By default the folder is moved to the trash and can be restored with
+/// [`restore_folder`]. Pass `permanent: true` to delete it immediately
+/// instead. A folder that still holds notes or subfolders is refused unless
+/// `recursive` is `true`.
+///
 /// # Arguments
 /// * `name` - The name of the folder to delete
-/// 
+/// * `permanent` - If `true`, skips the trash and deletes irreversibly
+/// * `recursive` - If `false`, refuses to delete a non-empty folder
+///
 /// # Requirements
 /// Validates: Requirements 10.3
 #[tauri::command]
-pub async fn delete_folder(name: String, filesystem: State<'_, FileSystem>) -> Result<ApiResult, String> {
+pub async fn delete_folder(
+    name: String,
+    permanent: bool,
+    recursive: bool,
+    filesystem: State<'_, FileSystem>,
+) -> Result<ApiResult, String> {
     if name.trim().is_empty() {
         return Ok(ApiResult::error("Folder name cannot be empty or whitespace only".to_string()));
     }
 
-    if name.contains("..") || name.contains('/') || name.contains('\\') {
+    if name.contains("..") || name.contains('\\') {
         return Ok(ApiResult::error("Folder name cannot contain path traversal or separators".to_string()));
     }
 
@@ -61,55 +76,191 @@ pub async fn delete_folder(name: String, filesystem: State<'_, FileSystem>) -> R
         return Ok(ApiResult::error(format!("Cannot delete protected folder '{}'", name)));
     }
 
-    match filesystem.delete_folder(&name) {
+    match filesystem.delete_folder(&name, permanent, recursive) {
         Ok(()) => Ok(ApiResult::success()),
         Err(e) => Ok(ApiResult::error(e)),
     }
 }
 
-/// Renames a folder in the notes directory.
-/// 
+/// Lists every folder and note currently in the trash.
+///
+/// # Requirements
+/// Validates: Requirements 10.3
+#[tauri::command]
+pub async fn list_trash(filesystem: State<'_, FileSystem>) -> Result<Vec<TrashEntry>, String> {
+    filesystem.list_trash()
+}
+
+/// Restores a trashed folder to its original location.
+///
 /// # Arguments
-/// * `old_name` - The current name of the folder
-/// * `new_name` - The new name for the folder
-/// 
+/// * `trash_id` - The id of the trash entry to restore, from [`list_trash`]
+///
+/// # Requirements
+/// Validates: Requirements 10.3
+#[tauri::command]
+pub async fn restore_folder(trash_id: String, filesystem: State<'_, FileSystem>) -> Result<ApiResult, String> {
+    match filesystem.restore_folder(&trash_id) {
+        Ok(_) => Ok(ApiResult::success()),
+        Err(e) => Ok(ApiResult::error(e)),
+    }
+}
+
+/// Permanently deletes every folder and note currently in the trash.
+///
+/// # Requirements
+/// Validates: Requirements 10.3
+#[tauri::command]
+pub async fn empty_trash(filesystem: State<'_, FileSystem>) -> Result<ApiResult, String> {
+    match filesystem.empty_trash() {
+        Ok(()) => Ok(ApiResult::success()),
+        Err(e) => Ok(ApiResult::error(e)),
+    }
+}
+
+/// Finds every folder in the notes tree that holds no notes, directly or in
+/// any of its subfolders.
+///
+/// # Requirements
+/// Validates: Requirements 10.3
+#[tauri::command]
+pub async fn find_empty_folders(filesystem: State<'_, FileSystem>) -> Result<Vec<String>, String> {
+    filesystem.find_empty_folders()
+}
+
+/// Deletes every folder found by [`find_empty_folders`] in one pass.
+///
+/// # Requirements
+/// Validates: Requirements 10.3
+#[tauri::command]
+pub async fn remove_empty_folders(filesystem: State<'_, FileSystem>) -> Result<Vec<String>, String> {
+    filesystem.remove_empty_folders()
+}
+
+/// Deletes every top-level folder matching a `*`/`?` wildcard pattern.
+///
+/// Each match goes through the same trash/`recursive` rules as
+/// [`delete_folder`]; one failure doesn't stop the rest of the batch.
+///
+/// # Arguments
+/// * `pattern` - A `*`/`?` wildcard matched against direct folder names
+/// * `recursive` - If `false`, refuses to delete any matched non-empty folder
+///
+/// # Requirements
+/// Validates: Requirements 10.3
+#[tauri::command]
+pub async fn delete_folders(
+    pattern: String,
+    recursive: bool,
+    filesystem: State<'_, FileSystem>,
+) -> Result<BatchFolderResult, String> {
+    filesystem.delete_folders(&pattern, recursive)
+}
+
+/// Renames every top-level folder matching a `*`/`?` wildcard pattern.
+///
+/// `template` is the destination name, with every `{}` replaced by the
+/// matched folder's original name (e.g. `"Archived-{}"`).
+///
+/// # Arguments
+/// * `pattern` - A `*`/`?` wildcard matched against direct folder names
+/// * `template` - The destination name template, `{}` standing in for the original name
+///
+/// # Requirements
+/// Validates: Requirements 10.4
+#[tauri::command]
+pub async fn rename_folders(
+    pattern: String,
+    template: String,
+    filesystem: State<'_, FileSystem>,
+) -> Result<BatchFolderResult, String> {
+    filesystem.rename_folders(&pattern, &template)
+}
+
+/// Renames (or moves) a folder in the notes directory.
+///
+/// `old_name`/`new_name` may be `/`-separated nested paths, so this also
+/// moves a folder to a different parent.
+///
+/// # Arguments
+/// * `old_name` - The current name (or nested path) of the folder
+/// * `new_name` - The new name (or nested path) for the folder
+/// * `overwrite` - If `true` and `new_name` exists, replace it
+/// * `merge` - If `true` and `new_name` exists, merge `old_name`'s notes into it
+///
 /// # Requirements
 /// Validates: Requirements 10.4
 #[tauri::command]
 pub async fn rename_folder(
     old_name: String,
     new_name: String,
+    overwrite: bool,
+    merge: bool,
     filesystem: State<'_, FileSystem>,
 ) -> Result<ApiResult, String> {
     if old_name.trim().is_empty() || new_name.trim().is_empty() {
         return Ok(ApiResult::error("Folder names cannot be empty or whitespace only".to_string()));
     }
 
-    if old_name.contains("..") || old_name.contains('/') || old_name.contains('\\') ||
-       new_name.contains("..") || new_name.contains('/') || new_name.contains('\\') {
+    if old_name.contains("..") || old_name.contains('\\') ||
+       new_name.contains("..") || new_name.contains('\\') {
         return Ok(ApiResult::error("Folder names cannot contain path traversal or separators".to_string()));
     }
 
     if filesystem.is_protected_name(&old_name) {
         return Ok(ApiResult::error(format!("Cannot rename protected folder '{}'", old_name)));
     }
-    
+
     if filesystem.is_protected_name(&new_name) {
         return Ok(ApiResult::error(format!("Cannot rename to protected name '{}'", new_name)));
     }
-    
-    // Check specific conflict for new_name
-    match filesystem.validate_notes_path(&new_name) {
-        Ok(path) => {
-            if path.exists() {
-                return Ok(ApiResult::error(format!("Folder '{}' already exists", new_name)));
-            }
-        },
-        Err(e) => return Ok(ApiResult::error(e)),
-    }
 
-    match filesystem.rename_folder(&old_name, &new_name) {
+    match filesystem.rename_folder(&old_name, &new_name, overwrite, merge) {
         Ok(()) => Ok(ApiResult::success()),
         Err(e) => Ok(ApiResult::error(e)),
     }
 }
+
+/// Copies a folder and everything under it to a new location.
+///
+/// # Arguments
+/// * `from` - The folder (or nested path) to copy
+/// * `to` - The destination folder (or nested path)
+/// * `overwrite` - If `true`, replaces a colliding destination file
+///
+/// # Requirements
+/// Validates: Requirements 10.4
+#[tauri::command]
+pub async fn copy_folder(
+    from: String,
+    to: String,
+    overwrite: bool,
+    filesystem: State<'_, FileSystem>,
+) -> Result<ApiResult, String> {
+    match filesystem.copy_folder(&from, &to, overwrite) {
+        Ok(count) => Ok(ApiResult::with_moved_count(count)),
+        Err(e) => Ok(ApiResult::error(e)),
+    }
+}
+
+/// Moves a folder and everything under it to a new location.
+///
+/// # Arguments
+/// * `from` - The folder (or nested path) to move
+/// * `to` - The destination folder (or nested path)
+/// * `overwrite` - If falling back to a recursive copy, replaces a colliding destination file
+///
+/// # Requirements
+/// Validates: Requirements 10.4
+#[tauri::command]
+pub async fn move_folder(
+    from: String,
+    to: String,
+    overwrite: bool,
+    filesystem: State<'_, FileSystem>,
+) -> Result<ApiResult, String> {
+    match filesystem.move_folder(&from, &to, overwrite) {
+        Ok(count) => Ok(ApiResult::with_moved_count(count)),
+        Err(e) => Ok(ApiResult::error(e)),
+    }
+}
@@ -1,6 +1,7 @@
 use tauri::State;
-use crate::filesystem::FileSystem;
-use crate::models::{ApiResult, NoteInfo};
+use crate::config::ConfigManager;
+use crate::filesystem::{self, FileSystem};
+use crate::models::{ApiResult, NoteInfo, RecentFile, SearchHit, VersionInfo};
 
 /// Lists all notes, optionally filtered by folder.
 /// 
@@ -20,6 +21,85 @@ pub async fn list_notes(
     filesystem.list_notes(folder.as_deref())
 }
 
+/// Searches note content for `query`, optionally restricted to one folder.
+///
+/// # Arguments
+/// * `query` - The text to search for
+/// * `folder` - Optional folder name to restrict the search to
+/// * `whole_word` - If `true`, only counts matches not adjacent to a word character
+///
+/// # Requirements
+/// Validates: Requirements 11.1, 11.2
+#[tauri::command]
+pub async fn search_notes(
+    query: String,
+    folder: Option<String>,
+    whole_word: bool,
+    filesystem: State<'_, FileSystem>,
+) -> Result<Vec<SearchHit>, String> {
+    filesystem.search_notes(&query, folder.as_deref(), whole_word)
+}
+
+/// Searches note content for `query` with BM25 relevance ranking, optionally
+/// restricted to one folder.
+///
+/// Unlike [`search_notes`]'s match-count ranking, each hit's `score` reflects
+/// how relevant the note is to the query's terms as a whole, via an
+/// incrementally-maintained full-text index rather than a per-call scan.
+///
+/// # Arguments
+/// * `query` - The text to search for
+/// * `folder` - Optional folder name to restrict the search to
+#[tauri::command]
+pub async fn search_notes_ranked(
+    query: String,
+    folder: Option<String>,
+    filesystem: State<'_, FileSystem>,
+) -> Result<Vec<SearchHit>, String> {
+    filesystem.search_notes_ranked(&query, folder.as_deref())
+}
+
+/// Lists every tag found in frontmatter across the vault, with how many
+/// notes carry each one, ranked by descending count.
+#[tauri::command]
+pub async fn list_tags(filesystem: State<'_, FileSystem>) -> Result<Vec<(String, usize)>, String> {
+    filesystem.list_tags()
+}
+
+/// Lists every note whose frontmatter carries `tag`.
+///
+/// # Arguments
+/// * `tag` - The tag to filter by
+#[tauri::command]
+pub async fn list_notes_by_tag(
+    tag: String,
+    filesystem: State<'_, FileSystem>,
+) -> Result<Vec<NoteInfo>, String> {
+    filesystem.list_notes_by_tag(&tag)
+}
+
+/// Replaces a note's frontmatter tag list.
+///
+/// # Arguments
+/// * `note_id` - The ID of the note to tag
+/// * `tags` - The note's new, complete tag list
+/// * `folder` - Optional folder containing the note
+#[tauri::command]
+pub async fn set_note_tags(
+    note_id: String,
+    tags: Vec<String>,
+    folder: Option<String>,
+    filesystem: State<'_, FileSystem>,
+) -> Result<ApiResult, String> {
+    match filesystem.set_note_tags(&note_id, tags, folder.as_deref()) {
+        Ok(()) => Ok(ApiResult::success()),
+        Err(e) => {
+            log::error!("Failed to set tags on note '{}' (folder: {:?}): {}", note_id, folder, e);
+            Ok(ApiResult::error(e))
+        }
+    }
+}
+
 /// Reads the content of a note.
 /// 
 /// # Arguments
@@ -33,10 +113,25 @@ pub async fn read_note(
     note_id: String,
     folder: Option<String>,
     filesystem: State<'_, FileSystem>,
+    config: State<'_, ConfigManager>,
+    app: tauri::AppHandle,
 ) -> Result<ApiResult, String> {
     match filesystem.read_note(&note_id, folder.as_deref()) {
-        Ok(content) => Ok(ApiResult::with_content(content)),
-        Err(e) => Ok(ApiResult::error(e)),
+        Ok(content) => {
+            let title = filesystem::title_from_content(&content).unwrap_or_else(|| note_id.clone());
+            config.add_recent_file(RecentFile {
+                note_id: note_id.clone(),
+                folder: folder.clone(),
+                title,
+            });
+            config.schedule_save().await;
+            crate::tray::refresh_recent_menu(&app);
+            Ok(ApiResult::with_content(content))
+        }
+        Err(e) => {
+            log::error!("Failed to read note '{}' (folder: {:?}): {}", note_id, folder, e);
+            Ok(ApiResult::error(e))
+        }
     }
 }
 
@@ -58,7 +153,76 @@ pub async fn save_note(
 ) -> Result<ApiResult, String> {
     match filesystem.save_note(&note_id, &content, folder.as_deref()) {
         Ok(()) => Ok(ApiResult::success()),
-        Err(e) => Ok(ApiResult::error(e)),
+        Err(e) => {
+            log::error!("Failed to save note '{}' (folder: {:?}): {}", note_id, folder, e);
+            Ok(ApiResult::error(e))
+        }
+    }
+}
+
+/// Lists a note's version history, newest first.
+///
+/// Every [`save_note`] snapshots the content it's about to overwrite, so
+/// this doubles as a recovery path for destructive saves.
+///
+/// # Arguments
+/// * `note_id` - The note to list versions for
+/// * `folder` - Optional folder containing the note
+#[tauri::command]
+pub async fn list_note_versions(
+    note_id: String,
+    folder: Option<String>,
+    filesystem: State<'_, FileSystem>,
+) -> Result<Vec<VersionInfo>, String> {
+    filesystem.list_note_versions(&note_id, folder.as_deref())
+}
+
+/// Reads one past revision of a note's content, to preview it before
+/// deciding whether to restore it.
+///
+/// # Arguments
+/// * `note_id` - The note the version belongs to
+/// * `version_id` - A version id from [`list_note_versions`]
+#[tauri::command]
+pub async fn read_note_version(
+    note_id: String,
+    version_id: String,
+    filesystem: State<'_, FileSystem>,
+) -> Result<ApiResult, String> {
+    match filesystem.read_note_version(&note_id, &version_id) {
+        Ok(content) => Ok(ApiResult::with_content(content)),
+        Err(e) => {
+            log::error!("Failed to read version '{}' of note '{}': {}", version_id, note_id, e);
+            Ok(ApiResult::error(e))
+        }
+    }
+}
+
+/// Rolls a note back to an earlier version.
+///
+/// The current content is snapshotted first, so rolling back is itself
+/// undoable via the same version history.
+///
+/// # Arguments
+/// * `note_id` - The note to restore
+/// * `version_id` - A version id from [`list_note_versions`]
+/// * `folder` - Optional folder containing the note
+#[tauri::command]
+pub async fn restore_note_version(
+    note_id: String,
+    version_id: String,
+    folder: Option<String>,
+    filesystem: State<'_, FileSystem>,
+) -> Result<ApiResult, String> {
+    match filesystem.restore_note_version(&note_id, &version_id, folder.as_deref()) {
+        Ok(()) => Ok(ApiResult::success()),
+        Err(e) => {
+            log::error!(
+                "Failed to restore note '{}' (folder: {:?}) to version '{}': {}",
+                note_id, folder, version_id, e
+            );
+            Ok(ApiResult::error(e))
+        }
     }
 }
 
@@ -76,27 +240,59 @@ pub async fn create_note(
 ) -> Result<ApiResult, String> {
     match filesystem.create_note(folder.as_deref()) {
         Ok((note_id, _path)) => Ok(ApiResult::with_note_id_and_folder(note_id, folder)),
-        Err(e) => Ok(ApiResult::error(e)),
+        Err(e) => {
+            log::error!("Failed to create note (folder: {:?}): {}", folder, e);
+            Ok(ApiResult::error(e))
+        }
     }
 }
 
 /// Deletes a note.
-/// 
+///
+/// By default the note is moved to the trash and can be restored with
+/// [`restore_note`]. Pass `permanent: true` to delete it immediately instead.
+///
 /// # Arguments
 /// * `note_id` - The ID of the note to delete
 /// * `folder` - Optional folder containing the note
-/// 
+/// * `permanent` - If `true`, skips the trash and deletes irreversibly
+///
 /// # Requirements
 /// Validates: Requirements 11.6
 #[tauri::command]
 pub async fn delete_note(
     note_id: String,
     folder: Option<String>,
+    permanent: bool,
     filesystem: State<'_, FileSystem>,
 ) -> Result<ApiResult, String> {
-    match filesystem.delete_note(&note_id, folder.as_deref()) {
+    match filesystem.delete_note(&note_id, folder.as_deref(), permanent) {
         Ok(()) => Ok(ApiResult::success()),
-        Err(e) => Ok(ApiResult::error(e)),
+        Err(e) => {
+            log::error!(
+                "Failed to delete note '{}' (folder: {:?}, permanent: {}): {}",
+                note_id, folder, permanent, e
+            );
+            Ok(ApiResult::error(e))
+        }
+    }
+}
+
+/// Restores a trashed note to its original folder.
+///
+/// # Arguments
+/// * `trash_id` - The id of the trash entry to restore, from `list_trash`
+///
+/// # Requirements
+/// Validates: Requirements 11.6
+#[tauri::command]
+pub async fn restore_note(trash_id: String, filesystem: State<'_, FileSystem>) -> Result<ApiResult, String> {
+    match filesystem.restore_note(&trash_id) {
+        Ok(_) => Ok(ApiResult::success()),
+        Err(e) => {
+            log::error!("Failed to restore trashed note '{}': {}", trash_id, e);
+            Ok(ApiResult::error(e))
+        }
     }
 }
 
@@ -118,7 +314,13 @@ pub async fn rename_note(
 ) -> Result<ApiResult, String> {
     match filesystem.rename_note(&note_id, &new_name, folder.as_deref()) {
         Ok(new_id) => Ok(ApiResult::with_note_id(new_id)),
-        Err(e) => Ok(ApiResult::error(e)),
+        Err(e) => {
+            log::error!(
+                "Failed to rename note '{}' to '{}' (folder: {:?}): {}",
+                note_id, new_name, folder, e
+            );
+            Ok(ApiResult::error(e))
+        }
     }
 }
 
@@ -140,7 +342,13 @@ pub async fn move_note(
 ) -> Result<ApiResult, String> {
     match filesystem.move_note(&note_id, &from_folder, &to_folder) {
         Ok(()) => Ok(ApiResult::success()),
-        Err(e) => Ok(ApiResult::error(e)),
+        Err(e) => {
+            log::error!(
+                "Failed to move note '{}' from '{}' to '{}': {}",
+                note_id, from_folder, to_folder, e
+            );
+            Ok(ApiResult::error(e))
+        }
     }
 }
 
@@ -165,7 +373,10 @@ pub async fn toggle_pin_note(
             pinned: Some(pinned),
             ..Default::default()
         }),
-        Err(e) => Ok(ApiResult::error(e)),
+        Err(e) => {
+            log::error!("Failed to toggle pin on note '{}': {}", note_id, e);
+            Ok(ApiResult::error(e))
+        }
     }
 }
 
@@ -197,7 +408,10 @@ pub async fn save_note_order(
 ) -> Result<ApiResult, String> {
     match filesystem.save_note_order(order) {
         Ok(()) => Ok(ApiResult::success()),
-        Err(e) => Ok(ApiResult::error(e)),
+        Err(e) => {
+            log::error!("Failed to save note order: {}", e);
+            Ok(ApiResult::error(e))
+        }
     }
 }
 
@@ -238,22 +452,65 @@ pub async fn save_quick_note(
     match filesystem.save_note(&note_id, &formatted_content, None) {
         Ok(()) => {
             // Show notification
-            let _ = app.notification()
+            if let Err(e) = app.notification()
                 .builder()
                 .title("Quick Note Saved")
                 .body("Your quick note has been saved")
-                .show();
+                .show()
+            {
+                log::warn!("Failed to show quick note notification: {}", e);
+            }
             
             // Emit refresh-notes event to update the UI
-            let _ = app.emit("refresh-notes", note_id.clone());
+            if let Err(e) = app.emit("refresh-notes", note_id.clone()) {
+                log::warn!("Failed to emit refresh-notes for '{}': {}", note_id, e);
+            }
             
             // Hide the quick note window
             if let Some(window) = app.get_webview_window("quick-note") {
-                let _ = window.hide();
+                if let Err(e) = window.hide() {
+                    log::warn!("Failed to hide quick note window: {}", e);
+                }
             }
             
             Ok(ApiResult::with_note_id(note_id))
         }
-        Err(e) => Ok(ApiResult::error(e)),
+        Err(e) => {
+            log::error!("Failed to save quick note: {}", e);
+            Ok(ApiResult::error(e))
+        }
+    }
+}
+
+/// Starts the live notes-directory watcher, so changes made outside the app
+/// emit `note-created`/`note-modified`/`note-deleted`/`note-renamed` events
+/// to this window's frontend. A no-op if already running.
+#[tauri::command]
+pub async fn start_notes_watcher(
+    filesystem: State<'_, FileSystem>,
+    app: tauri::AppHandle,
+) -> Result<ApiResult, String> {
+    match filesystem.start_notes_watcher(app) {
+        Ok(()) => Ok(ApiResult::success()),
+        Err(e) => {
+            log::error!("Failed to start notes watcher: {}", e);
+            Ok(ApiResult::error(e))
+        }
+    }
+}
+
+/// Stops the watcher started by [`start_notes_watcher`].
+///
+/// The frontend should pair this with its own save/create/delete/rename
+/// calls, so the app's own writes don't feed back as external-change
+/// events.
+#[tauri::command]
+pub async fn stop_notes_watcher(filesystem: State<'_, FileSystem>) -> Result<ApiResult, String> {
+    match filesystem.stop_notes_watcher() {
+        Ok(()) => Ok(ApiResult::success()),
+        Err(e) => {
+            log::error!("Failed to stop notes watcher: {}", e);
+            Ok(ApiResult::error(e))
+        }
     }
 }
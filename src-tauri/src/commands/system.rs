@@ -1,6 +1,14 @@
 use tauri::State;
-use crate::filesystem::FileSystem;
-use crate::models::ApiResult;
+use crate::filesystem::{ChecksumAlgorithm, FileSystem, SymlinkPolicy};
+use crate::models::{ApiResult, ImportedNote};
+
+/// Directory the file-backed logger (see [`crate::logging`]) writes into,
+/// relative to the data directory - kept alongside notes/assets/.versions
+/// rather than a separate OS log location, so `MDED_DATA_DIR` relocates logs
+/// along with everything else.
+fn log_dir(filesystem: &FileSystem) -> std::path::PathBuf {
+    filesystem.base_dir.join("logs")
+}
 
 /// Saves a screenshot from base64 PNG data.
 /// 
@@ -27,7 +35,61 @@ pub async fn save_screenshot(
             image_path: Some(image_path),
             ..Default::default()
         }),
-        Err(e) => Ok(ApiResult::error(e)),
+        Err(e) => {
+            log::error!("Failed to save screenshot: {}", e);
+            Ok(ApiResult::error(e))
+        }
+    }
+}
+
+/// Saves a screenshot from base64 PNG data, deduplicated by content hash.
+///
+/// Unlike [`save_screenshot`], pasting the same image twice reuses the same
+/// asset file instead of writing a duplicate.
+///
+/// # Arguments
+/// * `base64_data` - The base64-encoded PNG image data (may include data URL prefix)
+///
+/// # Returns
+/// ApiResult with image_path, image_id, and `was_new` on success
+///
+/// # Requirements
+/// Validates: Requirements 14.1, 14.2
+#[tauri::command]
+pub async fn save_screenshot_dedup(
+    base64_data: String,
+    filesystem: State<'_, FileSystem>,
+) -> Result<ApiResult, String> {
+    match filesystem.save_screenshot_dedup(&base64_data) {
+        Ok((image_id, image_path, was_new)) => Ok(ApiResult {
+            success: true,
+            image_id: Some(image_id),
+            image_path: Some(image_path),
+            was_new: Some(was_new),
+            ..Default::default()
+        }),
+        Err(e) => {
+            log::error!("Failed to save deduplicated screenshot: {}", e);
+            Ok(ApiResult::error(e))
+        }
+    }
+}
+
+/// Removes every asset file not referenced by any note's content.
+///
+/// # Returns
+/// The number of asset files removed
+///
+/// # Requirements
+/// Validates: Requirements 14.1, 14.2
+#[tauri::command]
+pub async fn gc_assets(filesystem: State<'_, FileSystem>) -> Result<usize, String> {
+    match filesystem.gc_assets() {
+        Ok(removed) => Ok(removed),
+        Err(e) => {
+            log::error!("Failed to garbage-collect assets: {}", e);
+            Err(e)
+        }
     }
 }
 
@@ -72,10 +134,132 @@ pub async fn read_external_file(
             file_path: Some(absolute_path),
             ..Default::default()
         }),
-        Err(e) => Ok(ApiResult::error(e)),
+        Err(e) => {
+            log::error!("Failed to read external file '{}': {}", file_path, e);
+            Ok(ApiResult::error(e))
+        }
+    }
+}
+
+/// Reads an external file, accepting Markdown by content when its extension
+/// doesn't already say so.
+///
+/// Like [`read_external_file`], but an extensionless or unconventionally
+/// named file (a `README`, a `NOTES` file) is still opened as long as its
+/// content looks like Markdown, rather than being rejected outright.
+///
+/// # Arguments
+/// * `file_path` - The absolute path to the file
+///
+/// # Returns
+/// ApiResult with content, file_name, and file_path on success
+#[tauri::command]
+pub async fn read_external_file_sniffed(
+    file_path: String,
+) -> Result<ApiResult, String> {
+    let filesystem = FileSystem::new()
+        .map_err(|e| format!("Failed to initialize filesystem: {}", e))?;
+
+    match filesystem.read_external_file_sniffed(&file_path) {
+        Ok((content, file_name, absolute_path)) => Ok(ApiResult {
+            success: true,
+            content: Some(content),
+            file_name: Some(file_name),
+            file_path: Some(absolute_path),
+            ..Default::default()
+        }),
+        Err(e) => {
+            log::error!("Failed to read external file '{}': {}", file_path, e);
+            Ok(ApiResult::error(e))
+        }
     }
 }
 
+/// Reads an external markdown file along with a checksum of its bytes, so the
+/// caller can later re-check the file hasn't changed on disk before an
+/// overwrite.
+///
+/// # Arguments
+/// * `file_path` - The absolute path to the file
+/// * `algorithm` - Which digest to compute
+///
+/// # Returns
+/// ApiResult with content, file_name, file_path, checksum, and checksum_algorithm on success
+#[tauri::command]
+pub async fn read_external_file_with_checksum(
+    file_path: String,
+    algorithm: ChecksumAlgorithm,
+) -> Result<ApiResult, String> {
+    let filesystem = FileSystem::new()
+        .map_err(|e| format!("Failed to initialize filesystem: {}", e))?;
+
+    match filesystem.read_external_file_with_checksum(&file_path, algorithm) {
+        Ok((content, file_name, absolute_path, checksum)) => Ok(ApiResult {
+            success: true,
+            content: Some(content),
+            file_name: Some(file_name),
+            file_path: Some(absolute_path),
+            checksum: Some(checksum.digest),
+            checksum_algorithm: Some(checksum.algorithm.as_str().to_string()),
+            ..Default::default()
+        }),
+        Err(e) => {
+            log::error!("Failed to read external file '{}' with checksum: {}", file_path, e);
+            Ok(ApiResult::error(e))
+        }
+    }
+}
+
+/// Reads an external markdown file, applying an explicit policy to a path
+/// that resolves through a symbolic link.
+///
+/// # Arguments
+/// * `file_path` - The absolute path to the file
+/// * `policy` - How to treat a path that resolves through a symlink
+///
+/// # Returns
+/// ApiResult with content, file_name, and file_path on success
+#[tauri::command]
+pub async fn read_external_file_with_symlink_policy(
+    file_path: String,
+    policy: SymlinkPolicy,
+) -> Result<ApiResult, String> {
+    let filesystem = FileSystem::new()
+        .map_err(|e| format!("Failed to initialize filesystem: {}", e))?;
+
+    match filesystem.read_external_file_with_symlink_policy(&file_path, &policy) {
+        Ok((content, file_name, absolute_path)) => Ok(ApiResult {
+            success: true,
+            content: Some(content),
+            file_name: Some(file_name),
+            file_path: Some(absolute_path),
+            ..Default::default()
+        }),
+        Err(e) => {
+            log::error!("Failed to read external file '{}' with symlink policy: {}", file_path, e);
+            Ok(ApiResult::error(e))
+        }
+    }
+}
+
+/// Recursively imports every Markdown file found under a directory.
+///
+/// See [`FileSystem::import_directory`] for which files are recognized as
+/// Markdown and the order notes are returned in.
+///
+/// # Arguments
+/// * `root` - The absolute path to the directory to import
+#[tauri::command]
+pub async fn import_directory(root: String) -> Result<Vec<ImportedNote>, String> {
+    let filesystem = FileSystem::new()
+        .map_err(|e| format!("Failed to initialize filesystem: {}", e))?;
+
+    filesystem.import_directory(&root).map_err(|e| {
+        log::error!("Failed to import directory '{}': {}", root, e);
+        e
+    })
+}
+
 /// Gets the current auto-start status.
 /// 
 /// # Returns
@@ -89,9 +273,10 @@ pub async fn get_auto_start(
 ) -> Result<bool, String> {
     use tauri_plugin_autostart::ManagerExt;
     
-    app.autolaunch()
-        .is_enabled()
-        .map_err(|e| format!("Failed to get auto-start status: {}", e))
+    app.autolaunch().is_enabled().map_err(|e| {
+        log::error!("Failed to get auto-start status: {}", e);
+        format!("Failed to get auto-start status: {}", e)
+    })
 }
 
 /// Sets the auto-start status.
@@ -121,6 +306,112 @@ pub async fn set_auto_start(
     
     match result {
         Ok(()) => Ok(ApiResult::success()),
-        Err(e) => Ok(ApiResult::error(format!("Failed to set auto-start: {}", e))),
+        Err(e) => {
+            log::error!("Failed to set auto-start to {}: {}", enabled, e);
+            Ok(ApiResult::error(format!("Failed to set auto-start: {}", e)))
+        }
+    }
+}
+
+/// Packages the whole vault into a single portable archive file.
+///
+/// See [`FileSystem::export_vault`] for the archive format and what is
+/// included. Emits `export-progress` events as the archive is written.
+///
+/// # Arguments
+/// * `dest_path` - Where to write the archive file
+/// * `include_assets` - If `true`, also packages the screenshot assets
+#[tauri::command]
+pub async fn export_vault(
+    dest_path: String,
+    include_assets: bool,
+    filesystem: State<'_, FileSystem>,
+    app: tauri::AppHandle,
+) -> Result<ApiResult, String> {
+    match filesystem.export_vault(&dest_path, include_assets, app) {
+        Ok(()) => Ok(ApiResult::success()),
+        Err(e) => {
+            log::error!("Failed to export vault to '{}': {}", dest_path, e);
+            Ok(ApiResult::error(e))
+        }
+    }
+}
+
+/// Imports a vault archive written by [`export_vault`] into the current vault.
+///
+/// See [`FileSystem::import_vault`] for how `merge` affects colliding notes
+/// and assets. Emits `import-progress` events as the archive is read.
+///
+/// # Arguments
+/// * `archive_path` - Path to the archive file
+/// * `merge` - If `false`, colliding notes/assets are overwritten; if `true`, they're kept alongside the existing ones
+#[tauri::command]
+pub async fn import_vault(
+    archive_path: String,
+    merge: bool,
+    filesystem: State<'_, FileSystem>,
+    app: tauri::AppHandle,
+) -> Result<ApiResult, String> {
+    match filesystem.import_vault(&archive_path, merge, app) {
+        Ok(()) => Ok(ApiResult::success()),
+        Err(e) => {
+            log::error!("Failed to import vault from '{}' (merge: {}): {}", archive_path, merge, e);
+            Ok(ApiResult::error(e))
+        }
+    }
+}
+
+/// Returns up to the last `max_lines` lines logged by the running app, oldest
+/// first, so users can preview what they're about to attach to a bug report.
+///
+/// # Arguments
+/// * `max_lines` - The maximum number of trailing lines to return
+#[tauri::command]
+pub async fn get_recent_logs(
+    max_lines: usize,
+    filesystem: State<'_, FileSystem>,
+) -> Result<Vec<String>, String> {
+    Ok(crate::logging::recent_lines(&log_dir(&filesystem), max_lines))
+}
+
+/// Opens the log directory in the system file manager, so a user can attach
+/// the log files to a bug report without hunting for the data directory.
+#[tauri::command]
+pub async fn open_log_directory(
+    filesystem: State<'_, FileSystem>,
+    app: tauri::AppHandle,
+) -> Result<ApiResult, String> {
+    use tauri_plugin_opener::OpenerExt;
+
+    match app.opener().open_path(log_dir(&filesystem).to_string_lossy(), None::<&str>) {
+        Ok(()) => Ok(ApiResult::success()),
+        Err(e) => {
+            log::warn!("Failed to open log directory: {}", e);
+            Ok(ApiResult::error(format!("Failed to open log directory: {}", e)))
+        }
+    }
+}
+
+/// Updates the tray icon and tooltip to reflect the current document's save state.
+///
+/// Swaps to a badged icon and appends an "unsaved" marker to the tooltip while
+/// `dirty` is true, so the tray communicates save state while the window is
+/// hidden.
+///
+/// # Arguments
+/// * `title` - The current document's display title, if any
+/// * `dirty` - Whether the document has unsaved changes
+#[tauri::command]
+pub async fn update_tray_status(
+    title: Option<String>,
+    dirty: bool,
+    app: tauri::AppHandle,
+) -> Result<ApiResult, String> {
+    match crate::tray::update_tray_status(&app, title.as_deref(), dirty) {
+        Ok(()) => Ok(ApiResult::success()),
+        Err(e) => {
+            log::warn!("Failed to update tray status: {}", e);
+            Ok(ApiResult::error(e))
+        }
     }
 }
@@ -1,7 +1,8 @@
-use tauri::{State, Window};
+use tauri::{AppHandle, Emitter, Manager, State, Window};
 use crate::config::ConfigManager;
-use crate::models::{ApiResult, DisplayInfo, WindowBounds};
+use crate::models::{ApiResult, DisplayInfo, NoteWindowInfo, WindowBounds};
 use crate::window::WindowManager;
+use crate::window_state::WindowState;
 
 /// Minimizes the window.
 /// 
@@ -31,15 +32,116 @@ pub async fn maximize_window(window: Window) -> Result<(), String> {
     }
 }
 
-/// Closes (hides) the window instead of terminating the application.
-/// 
-/// The window is hidden to the system tray rather than being destroyed.
-/// 
-/// # Requirements
-/// Validates: Requirements 2.5
+/// Closes the window.
+///
+/// The main window is hidden to the system tray rather than being
+/// destroyed. Detached note windows (opened via [`open_note_window`]) have
+/// no tray presence, so they're genuinely closed and untracked instead.
+#[tauri::command]
+pub async fn close_window(window: Window, window_manager: State<'_, WindowManager>) -> Result<(), String> {
+    if window.label() == "main" {
+        window.hide().map_err(|e| format!("Failed to hide window: {}", e))
+    } else {
+        window_manager.untrack_note_window(window.label());
+        window.close().map_err(|e| format!("Failed to close window: {}", e))
+    }
+}
+
+/// Turns a note id into a valid, stable window label by replacing every
+/// non-alphanumeric character - window labels only allow a narrow character
+/// set, and note ids are free-form.
+fn note_window_label(note_id: &str) -> String {
+    let sanitized: String =
+        note_id.chars().map(|c| if c.is_ascii_alphanumeric() { c } else { '-' }).collect();
+    format!("note-{}", sanitized)
+}
+
+/// Opens a note in its own detached window, tracked by label in
+/// [`WindowManager`] so the backend can target it directly (see
+/// [`crate::window::WindowManager::find_note_window`]) instead of
+/// broadcasting to every window. If the note is already open in a window,
+/// that window is focused instead of creating a duplicate.
+///
+/// # Arguments
+/// * `note_id` - The note to open
+/// * `folder` - The note's folder, if any
+#[tauri::command]
+pub async fn open_note_window(
+    note_id: String,
+    folder: Option<String>,
+    app: AppHandle,
+    window_manager: State<'_, WindowManager>,
+) -> Result<ApiResult, String> {
+    if let Some(label) = window_manager.find_note_window(&note_id, folder.as_deref()) {
+        if let Some(window) = app.get_webview_window(&label) {
+            if let Err(e) = window.show() {
+                log::warn!("Failed to show note window '{}': {}", label, e);
+            }
+            if let Err(e) = window.set_focus() {
+                log::warn!("Failed to focus note window '{}': {}", label, e);
+            }
+            return Ok(ApiResult::with_note_id_and_folder(note_id, folder));
+        }
+        // The window was closed without going through close_window (e.g. it
+        // crashed), leaving a stale entry - drop it and open a fresh window.
+        window_manager.untrack_note_window(&label);
+    }
+
+    let label = note_window_label(&note_id);
+    let mut url = format!("index.html?note={}", note_id);
+    if let Some(folder) = &folder {
+        url.push_str(&format!("&folder={}", folder));
+    }
+
+    let window = tauri::WebviewWindowBuilder::new(&app, &label, tauri::WebviewUrl::App(url.into()))
+        .title("Note")
+        .inner_size(900.0, 700.0)
+        .build()
+        .map_err(|e| format!("Failed to create note window: {}", e))?;
+
+    window_manager.track_note_window(&label, note_id.clone(), folder.clone());
+    if let Err(e) = window.set_focus() {
+        log::warn!("Failed to focus newly opened note window '{}': {}", label, e);
+    }
+
+    Ok(ApiResult::with_note_id_and_folder(note_id, folder))
+}
+
+/// Lists every currently open detached note window.
+#[tauri::command]
+pub async fn list_note_windows(window_manager: State<'_, WindowManager>) -> Result<Vec<NoteWindowInfo>, String> {
+    Ok(window_manager
+        .list_note_windows()
+        .into_iter()
+        .map(|(label, note_id, folder)| NoteWindowInfo { label, note_id, folder })
+        .collect())
+}
+
+/// Brings the window labeled `label` to the front.
+///
+/// # Arguments
+/// * `label` - The label of the window to focus
 #[tauri::command]
-pub async fn close_window(window: Window) -> Result<(), String> {
-    window.hide().map_err(|e| format!("Failed to hide window: {}", e))
+pub async fn focus_note_window(label: String, app: AppHandle) -> Result<ApiResult, String> {
+    let window = app
+        .get_webview_window(&label)
+        .ok_or_else(|| format!("No window labeled '{}'", label))?;
+    window.show().map_err(|e| format!("Failed to show window: {}", e))?;
+    window.set_focus().map_err(|e| format!("Failed to focus window: {}", e))?;
+    Ok(ApiResult::success())
+}
+
+/// Hides the quick-capture overlay window.
+///
+/// Called when the frontend detects Escape while the overlay is focused,
+/// mirroring the auto-hide-on-blur behavior set up when the window is
+/// created (see `create_quick_note_window`).
+#[tauri::command]
+pub async fn hide_quick_note_window(app: AppHandle) -> Result<(), String> {
+    if let Some(window) = app.get_webview_window("quick-note") {
+        window.hide().map_err(|e| format!("Failed to hide quick note window: {}", e))?;
+    }
+    Ok(())
 }
 
 /// Sets the window always-on-top flag.
@@ -56,11 +158,33 @@ pub async fn set_always_on_top(flag: bool, window: Window) -> Result<ApiResult,
     Ok(ApiResult::success())
 }
 
+/// Sets whether the window stays visible across all virtual
+/// desktops/workspaces (macOS Spaces), and persists the preference.
+///
+/// # Arguments
+/// * `flag` - Whether the window should follow the user across workspaces
+#[tauri::command]
+pub async fn set_visible_on_all_workspaces(
+    flag: bool,
+    window: Window,
+    config: State<'_, ConfigManager>,
+) -> Result<ApiResult, String> {
+    window.set_visible_on_all_workspaces(flag)
+        .map_err(|e| format!("Failed to set visible-on-all-workspaces: {}", e))?;
+
+    config.set_visible_on_all_workspaces(flag);
+    config.schedule_save().await;
+
+    Ok(ApiResult::success())
+}
+
 /// Enters minimal mode.
-/// 
-/// Saves the current window bounds, sets always-on-top, and resizes
-/// to the saved minimal mode bounds.
-/// 
+///
+/// Saves the current window bounds, sets always-on-top, resizes to the
+/// saved minimal mode bounds, and force-enables visible-on-all-workspaces
+/// so the floating note follows the user across Spaces/desktops
+/// (remembering the prior value so [`exit_minimal_mode`] can restore it).
+///
 /// # Requirements
 /// Validates: Requirements 5.1
 #[tauri::command]
@@ -74,25 +198,31 @@ pub async fn enter_minimal_mode(
         .map_err(|e| format!("Failed to get window position: {}", e))?;
     let size = window.outer_size()
         .map_err(|e| format!("Failed to get window size: {}", e))?;
-    
+
     let current_bounds = WindowBounds {
         width: size.width,
         height: size.height,
         x: Some(position.x),
         y: Some(position.y),
+        scale_factor: window.scale_factor().unwrap_or(1.0),
     };
-    
+
     // Save normal bounds
     window_manager.save_normal_bounds(current_bounds);
-    
+
     // Get minimal mode bounds from config
     let cfg = config.get();
     let minimal_bounds = cfg.minimal_mode_bounds;
-    
+
     // Set always on top
     window.set_always_on_top(true)
         .map_err(|e| format!("Failed to set always on top: {}", e))?;
-    
+
+    // Force-enable visible-on-all-workspaces, remembering the prior value.
+    window_manager.save_previous_all_workspaces(cfg.visible_on_all_workspaces);
+    window.set_visible_on_all_workspaces(true)
+        .map_err(|e| format!("Failed to enable visible-on-all-workspaces: {}", e))?;
+
     // Resize to minimal bounds
     window.set_size(tauri::Size::Physical(tauri::PhysicalSize {
         width: minimal_bounds.width,
@@ -135,8 +265,9 @@ pub async fn exit_minimal_mode(
         height: size.height,
         x: Some(position.x),
         y: Some(position.y),
+        scale_factor: window.scale_factor().unwrap_or(1.0),
     };
-    
+
     // Save current bounds as minimal mode bounds
     config.update(|cfg| {
         cfg.minimal_mode_bounds = current_bounds;
@@ -147,8 +278,11 @@ pub async fn exit_minimal_mode(
     window.set_always_on_top(false)
         .map_err(|e| format!("Failed to disable always on top: {}", e))?;
     
-    // Restore normal bounds if available
+    // Restore normal bounds if available, repositioning onto a connected
+    // display first in case the one it was saved on is gone or resized.
     if let Some(normal_bounds) = window_manager.get_normal_bounds() {
+        let normal_bounds = window_manager.ensure_visible(normal_bounds, &list_displays(&window));
+
         window.set_size(tauri::Size::Physical(tauri::PhysicalSize {
             width: normal_bounds.width,
             height: normal_bounds.height,
@@ -160,10 +294,17 @@ pub async fn exit_minimal_mode(
         }
     }
     
+    // Restore the visible-on-all-workspaces value that was in effect before
+    // minimal mode force-enabled it.
+    if let Some(previous) = window_manager.take_previous_all_workspaces() {
+        window.set_visible_on_all_workspaces(previous)
+            .map_err(|e| format!("Failed to restore visible-on-all-workspaces: {}", e))?;
+    }
+
     // Clear saved normal bounds and set minimal mode state
     window_manager.clear_normal_bounds();
     window_manager.set_minimal_mode(false);
-    
+
     Ok(ApiResult::success())
 }
 
@@ -186,8 +327,9 @@ pub async fn save_minimal_bounds(
         height: size.height,
         x: Some(position.x),
         y: Some(position.y),
+        scale_factor: window.scale_factor().unwrap_or(1.0),
     };
-    
+
     config.update(|cfg| {
         cfg.minimal_mode_bounds = bounds;
     });
@@ -205,25 +347,62 @@ pub async fn get_window_opacity(config: State<'_, ConfigManager>) -> Result<f64,
     Ok(config.get_window_opacity())
 }
 
+/// Number of increments a `set_window_opacity` fade is broken into.
+const OPACITY_FADE_STEPS: u32 = 10;
+/// Delay between fade increments, totaling ~150ms across [`OPACITY_FADE_STEPS`].
+const OPACITY_FADE_STEP_DELAY: std::time::Duration = std::time::Duration::from_millis(15);
+
 /// Sets the window opacity.
-/// 
-/// The opacity is clamped between 0.3 and 1.0.
-/// 
+///
+/// The opacity is clamped between 0.3 and 1.0, persisted, and applied to the
+/// live window. When `animate` is true, the live opacity is stepped from its
+/// current value to the target over ~150ms on a background task instead of
+/// jumping straight there, emitting `opacity-fade-complete` once it lands on
+/// the target so the UI can sync its slider.
+///
 /// # Arguments
 /// * `opacity` - The desired opacity value
-/// 
+/// * `animate` - Whether to fade to the target instead of applying it immediately
+///
 /// # Requirements
 /// Validates: Requirements 6.1, 6.2, 6.3
 #[tauri::command]
 pub async fn set_window_opacity(
     opacity: f64,
+    animate: Option<bool>,
+    window: tauri::WebviewWindow,
+    app: tauri::AppHandle,
+    window_manager: State<'_, WindowManager>,
     config: State<'_, ConfigManager>,
 ) -> Result<ApiResult, String> {
     let clamped = WindowManager::clamp_opacity(opacity);
-    
+
     config.set_window_opacity(clamped);
     config.schedule_save().await;
-    
+
+    if animate.unwrap_or(false) {
+        let start = window_manager.get_live_opacity();
+        tauri::async_runtime::spawn(async move {
+            for step in 1..=OPACITY_FADE_STEPS {
+                let stepped = start + (clamped - start) * (step as f64 / OPACITY_FADE_STEPS as f64);
+                if let Err(e) = crate::window::apply_window_opacity(&window, stepped) {
+                    log::warn!("Failed to step window opacity to {}: {}", stepped, e);
+                }
+                app.state::<WindowManager>().set_live_opacity(stepped);
+                tokio::time::sleep(OPACITY_FADE_STEP_DELAY).await;
+            }
+            if let Err(e) = app.emit("opacity-fade-complete", clamped) {
+                log::warn!("Failed to emit opacity-fade-complete: {}", e);
+            }
+        });
+    } else {
+        crate::window::apply_window_opacity(&window, clamped)?;
+        window_manager.set_live_opacity(clamped);
+        if let Err(e) = app.emit("opacity-fade-complete", clamped) {
+            log::warn!("Failed to emit opacity-fade-complete: {}", e);
+        }
+    }
+
     Ok(ApiResult {
         success: true,
         opacity: Some(clamped),
@@ -231,10 +410,26 @@ pub async fn set_window_opacity(
     })
 }
 
+/// Lists every display currently connected, for use with
+/// [`crate::window::WindowManager::ensure_visible`]. If the monitors can't be
+/// enumerated, returns an empty list rather than failing the whole call.
+fn list_displays(window: &Window) -> Vec<DisplayInfo> {
+    window
+        .available_monitors()
+        .unwrap_or_default()
+        .into_iter()
+        .map(|monitor| {
+            let position = monitor.position();
+            let size = monitor.size();
+            DisplayInfo { x: position.x, y: position.y, width: size.width, height: size.height }
+        })
+        .collect()
+}
+
 /// Gets display information for the primary monitor.
-/// 
+///
 /// Returns the work area dimensions and position.
-/// 
+///
 /// # Requirements
 /// Validates: Requirements 18.1
 #[tauri::command]
@@ -254,10 +449,50 @@ pub async fn get_display_info(window: Window) -> Result<DisplayInfo, String> {
     })
 }
 
+/// Captures and persists the window's full state: position, size,
+/// maximized, fullscreen, and visibility.
+///
+/// While maximized, the previously recorded (non-maximized) position and
+/// size are kept as the stored state instead of the live maximized bounds,
+/// so restoring later un-maximizes to sane dimensions rather than filling
+/// the screen. See [`crate::window_state::WindowState::update_from_live_snapshot`].
+#[tauri::command]
+pub async fn save_full_window_state(
+    window: Window,
+    window_manager: State<'_, WindowManager>,
+    config: State<'_, ConfigManager>,
+) -> Result<ApiResult, String> {
+    let label = window.label().to_string();
+
+    let position = window.outer_position().map_err(|e| format!("Failed to get window position: {}", e))?;
+    let size = window.outer_size().map_err(|e| format!("Failed to get window size: {}", e))?;
+    let maximized = window.is_maximized().map_err(|e| format!("Failed to check maximized state: {}", e))?;
+    let fullscreen = window.is_fullscreen().map_err(|e| format!("Failed to check fullscreen state: {}", e))?;
+    let visible = window.is_visible().map_err(|e| format!("Failed to check visibility: {}", e))?;
+
+    let live = WindowState {
+        x: Some(position.x),
+        y: Some(position.y),
+        width: Some(size.width),
+        height: Some(size.height),
+        maximized: Some(maximized),
+        fullscreen: Some(fullscreen),
+        visible: Some(visible),
+        always_on_top: window_manager.get_window_state(&label).and_then(|s| s.always_on_top),
+    };
+
+    let merged = WindowState::update_from_live_snapshot(window_manager.get_window_state(&label).as_ref(), live);
+    window_manager.set_window_state(&label, merged.clone());
+    config.set_window_state(&label, merged);
+    config.schedule_save().await;
+
+    Ok(ApiResult::success())
+}
+
 /// Saves the current window bounds to configuration.
-/// 
+///
 /// This is called when the window is moved or resized.
-/// 
+///
 /// # Requirements
 /// Validates: Requirements 2.3, 2.4
 #[tauri::command]
@@ -281,10 +516,11 @@ pub async fn save_window_bounds(
         height: size.height,
         x: Some(position.x),
         y: Some(position.y),
+        scale_factor: window.scale_factor().unwrap_or(1.0),
     };
-    
-    // Clamp bounds to minimum dimensions
-    let clamped_bounds = WindowManager::clamp_bounds(bounds);
+
+    // Clamp bounds to the configured min/max dimensions
+    let clamped_bounds = window_manager.clamp_bounds(bounds);
     
     config.update(|cfg| {
         cfg.window_bounds = clamped_bounds;
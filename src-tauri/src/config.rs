@@ -1,16 +1,101 @@
 use std::fs;
-use std::path::PathBuf;
-use std::sync::{Arc, RwLock};
-use std::time::Duration;
+use std::io::Write;
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, Mutex as StdMutex, RwLock};
+use std::time::{Duration, SystemTime};
+use fs2::FileExt;
+use serde::{Deserialize, Serialize};
 use tokio::sync::Mutex;
 use tokio::task::JoinHandle;
 use tokio::time::sleep;
 
-use crate::models::{Config, LastNote};
+use crate::models::{Config, LastNote, RecentFile};
+use crate::shortcuts::{ShortcutManager, ShortcutParseError};
+use tauri_plugin_global_shortcut::Shortcut;
 
 /// Debounce delay for config saves (1 second)
 const SAVE_DEBOUNCE_MS: u64 = 1000;
 
+/// Maximum number of entries kept in the recent files list.
+const MAX_RECENT_FILES: usize = 10;
+
+/// Config fields that can be overridden by the `Env` and `CommandArg` layers,
+/// in addition to `config.json`. Kept to simple scalars - structured fields
+/// like `window_bounds` or `window_states` stay file-only.
+const OVERRIDABLE_FIELDS: &[&str] = &[
+    "global_shortcut",
+    "clipboard_shortcut",
+    "quick_note_shortcut",
+    "window_opacity",
+    "normal_opacity",
+    "minimal_opacity",
+    "auto_start_on_boot",
+    "menubar_mode",
+    "visible_on_all_workspaces",
+];
+
+/// A layer a [`Config`] field's value may be resolved from, in ascending
+/// precedence order - a field set in a later layer shadows the same field in
+/// an earlier one. Modeled after jj's config layering.
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum ConfigSource {
+    /// [`Config::default`] - no layer above it set this field.
+    Default,
+    /// The on-disk `config.json`.
+    User,
+    /// An `MDED_*` environment variable - shadows `config.json` without
+    /// editing it, e.g. to override a shortcut for one launch.
+    Env,
+    /// A launch-time `--set key=value` override, the highest precedence.
+    CommandArg,
+}
+
+/// A single resolved config field, tagged with the layer that supplied its
+/// value. Produced by [`ConfigManager::get_annotated`] so callers/tests can
+/// see *why* a field has the value it does, instead of only the final
+/// merged [`Config`].
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+pub struct AnnotatedValue {
+    pub path: Vec<String>,
+    pub source: ConfigSource,
+    pub value: serde_json::Value,
+}
+
+/// An error constructing a [`ConfigManager`]. Kept as a distinct type (most
+/// of this module's I/O just uses `String`, see e.g. [`ConfigManager::save_sync`])
+/// because [`ConfigManager::new`]'s caller needs to match on `AlreadyLocked`
+/// specifically - a second instance should refuse to start up and race
+/// another one over the same `config.json`, rather than just logging and
+/// giving up like an ordinary read/parse failure.
+#[derive(Debug)]
+pub enum ConfigError {
+    /// Another process already holds an exclusive lock on `config.json`.
+    AlreadyLocked,
+    /// Any other failure reading, parsing, or locking the config file.
+    Other(String),
+}
+
+impl std::fmt::Display for ConfigError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ConfigError::AlreadyLocked => {
+                write!(f, "config file is locked by another running instance")
+            }
+            ConfigError::Other(message) => write!(f, "{}", message),
+        }
+    }
+}
+
+impl std::error::Error for ConfigError {}
+
+impl From<String> for ConfigError {
+    fn from(message: String) -> Self {
+        ConfigError::Other(message)
+    }
+}
+
 /// ConfigManager handles loading, saving, and updating application configuration.
 /// 
 /// Features:
@@ -31,6 +116,46 @@ pub struct ConfigManager {
     config_for_save: Arc<RwLock<Config>>,
     /// Shared path for async operations
     config_path_for_save: Arc<PathBuf>,
+    /// The last-known raw JSON object backing `config.json`, including any
+    /// top-level keys this binary doesn't recognize (written by a newer
+    /// version, or a plugin). Serves two purposes: answering
+    /// [`ConfigManager::get_annotated`]'s "did the user's file set this
+    /// field?" question, and letting a save reshape this object - only
+    /// overwriting the keys [`Config`] knows about - so unrecognized keys
+    /// round-trip instead of being dropped.
+    user_layer: Arc<StdMutex<serde_json::Value>>,
+    /// Field overrides sourced from `MDED_*` environment variables at
+    /// construction time, applied between the `User` and `CommandArg`
+    /// layers.
+    env_layer: serde_json::Value,
+    /// Field overrides passed in as `key=value` launch arguments, the
+    /// highest-precedence layer.
+    command_arg_layer: serde_json::Value,
+    /// Bumped on every `update()`. A debounced save captures this at
+    /// schedule time and re-checks it just before writing, so an update (or
+    /// an external reload) that lands while the save is still debouncing
+    /// makes it stale and it's discarded instead of clobbering newer state.
+    generation: Arc<AtomicU64>,
+    /// mtime of the last write this manager performed itself (via
+    /// `save_sync` or a debounced save), so [`ConfigManager::reload_from_disk`]
+    /// can recognize and ignore its own writes instead of treating them as
+    /// an external edit.
+    self_write: Arc<StdMutex<Option<SystemTime>>>,
+    /// The `Default`+`User` config as of the last time memory and disk were
+    /// known to agree (construction, a completed save, or a previous
+    /// reload). Diffing the live config against this lets
+    /// `reload_from_disk` tell which fields are unsaved local edits worth
+    /// preserving over the freshly-read file.
+    last_synced: Arc<StdMutex<Config>>,
+    /// Notifies subscribers (e.g. the frontend) with the new generation
+    /// number whenever `reload_from_disk` applies an externally-detected
+    /// edit.
+    reload_tx: tokio::sync::watch::Sender<u64>,
+    reload_rx: tokio::sync::watch::Receiver<u64>,
+    /// An advisory exclusive lock on `config.json`, held only so its `Drop`
+    /// releases the OS-level lock when this manager is dropped - see
+    /// [`acquire_config_lock`]. Never read directly.
+    _config_lock: fs::File,
 }
 
 impl ConfigManager {
@@ -45,91 +170,138 @@ impl ConfigManager {
     /// # Returns
     /// * `Ok(ConfigManager)` - A new ConfigManager instance
     /// * `Err(String)` - If loading fails
-    pub fn new(config_path: PathBuf) -> Result<Self, String> {
-        let config = Self::load_from_file(&config_path)?;
+    pub fn new(config_path: PathBuf) -> Result<Self, ConfigError> {
+        Self::new_with_args(config_path, Vec::new())
+    }
+
+    /// Creates a new ConfigManager, additionally applying a `CommandArg`
+    /// layer of `key=value` overrides (e.g. parsed from launch flags) on top
+    /// of `config.json` and the `MDED_*` environment.
+    ///
+    /// Layers are resolved in ascending precedence - `Default`, then `User`
+    /// (`config.json`), then `Env` (`MDED_*` variables), then `CommandArg` -
+    /// so a field set in a later layer shadows the same field set in an
+    /// earlier one. This lets a shortcut or opacity be overridden temporarily
+    /// without rewriting the saved file. See [`ConfigManager::get_annotated`]
+    /// to inspect which layer supplied a given field.
+    ///
+    /// # Arguments
+    /// * `config_path` - Path to the config.json file
+    /// * `command_args` - `(field, value)` overrides from the command line
+    ///
+    /// # Returns
+    /// * `Ok(ConfigManager)` - A new ConfigManager instance
+    /// * `Err(ConfigError::AlreadyLocked)` - If another running instance
+    ///   already holds the config file's lock
+    /// * `Err(ConfigError::Other)` - If loading otherwise fails
+    pub fn new_with_args(config_path: PathBuf, command_args: Vec<(String, String)>) -> Result<Self, ConfigError> {
+        let config_lock = acquire_config_lock(&config_path)?;
+        let user_layer = Self::read_user_layer(&config_path)?;
+        let env_layer = read_env_layer();
+        let command_arg_layer = build_command_arg_layer(&command_args);
+
+        // load_from_file is the Default+User case of this layering (already
+        // upgraded to the current schema version via Config::migrate); Env
+        // and CommandArg are applied on top.
+        let (disk_config, was_migrated) = Self::load_from_file(&config_path)?;
+        let mut config = disk_config.clone();
+        apply_layer(&mut config, &env_layer);
+        apply_layer(&mut config, &command_arg_layer);
+
         let config_for_save = Arc::new(RwLock::new(config.clone()));
         let config_path_for_save = Arc::new(config_path.clone());
-        
-        Ok(Self {
+        let self_write = Arc::new(StdMutex::new(
+            fs::metadata(&config_path).ok().and_then(|m| m.modified().ok()),
+        ));
+        let (reload_tx, reload_rx) = tokio::sync::watch::channel(0);
+
+        let manager = Self {
             config: RwLock::new(config),
             config_path,
             save_handle: Mutex::new(None),
             config_for_save,
             config_path_for_save,
-        })
+            user_layer: Arc::new(StdMutex::new(user_layer)),
+            env_layer,
+            command_arg_layer,
+            generation: Arc::new(AtomicU64::new(0)),
+            self_write,
+            last_synced: Arc::new(StdMutex::new(disk_config)),
+            reload_tx,
+            reload_rx,
+            _config_lock: config_lock,
+        };
+
+        // A config loaded from an older schema version was upgraded in
+        // memory above - write it back now so the migration runs at most
+        // once per file instead of on every launch.
+        if was_migrated {
+            manager.save_sync()?;
+        }
+
+        Ok(manager)
     }
 
-    /// Loads configuration from file, merging with defaults.
-    /// 
-    /// If the file doesn't exist, returns default configuration.
-    /// If the file exists but has missing fields, those fields get default values.
-    /// 
+    /// Reads `config.json` as a raw JSON value, without merging onto
+    /// defaults. Used only to track which fields the `User` layer set, for
+    /// [`ConfigManager::get_annotated`].
+    fn read_user_layer(path: &PathBuf) -> Result<serde_json::Value, String> {
+        if !path.exists() {
+            return Ok(serde_json::Value::Object(serde_json::Map::new()));
+        }
+
+        let content = fs::read_to_string(path)
+            .map_err(|e| format!("Failed to read config file: {}", e))?;
+
+        // `acquire_config_lock` leaves an empty file behind when none existed.
+        if content.trim().is_empty() {
+            return Ok(serde_json::Value::Object(serde_json::Map::new()));
+        }
+
+        serde_json::from_str(&content)
+            .map_err(|e| format!("Failed to parse config file: {}", e))
+    }
+
+    /// Loads configuration from file, running it through [`Config::migrate`]
+    /// so a config written by an older schema version is upgraded
+    /// deterministically rather than silently losing fields a rename or
+    /// restructure moved.
+    ///
+    /// If the file doesn't exist or is empty, returns default configuration.
+    ///
     /// # Arguments
     /// * `path` - Path to the config file
-    /// 
+    ///
     /// # Returns
-    /// * `Ok(Config)` - The loaded configuration
+    /// * `Ok((Config, was_migrated))` - The loaded (and possibly upgraded)
+    ///   configuration, and whether it was stored at an older schema version
+    ///   - the caller writes the upgraded version back so the migration
+    ///     only has to run once.
     /// * `Err(String)` - If reading or parsing fails
-    /// 
+    ///
     /// # Requirements
     /// Validates: Requirements 17.2
-    fn load_from_file(path: &PathBuf) -> Result<Config, String> {
+    fn load_from_file(path: &PathBuf) -> Result<(Config, bool), String> {
         if !path.exists() {
-            return Ok(Config::default());
+            return Ok((Config::default(), false));
         }
 
         let content = fs::read_to_string(path)
             .map_err(|e| format!("Failed to read config file: {}", e))?;
 
+        // `acquire_config_lock` leaves an empty file behind when none existed.
+        if content.trim().is_empty() {
+            return Ok((Config::default(), false));
+        }
+
         // Parse as JSON Value first to handle partial configs
         let json_value: serde_json::Value = serde_json::from_str(&content)
             .map_err(|e| format!("Failed to parse config file: {}", e))?;
 
-        // Start with defaults
-        let mut config = Config::default();
-
-        // Merge saved values into defaults
-        if let Some(obj) = json_value.as_object() {
-            if let Some(v) = obj.get("global_shortcut").and_then(|v| v.as_str()) {
-                config.global_shortcut = v.to_string();
-            }
-            if let Some(v) = obj.get("clipboard_shortcut").and_then(|v| v.as_str()) {
-                config.clipboard_shortcut = v.to_string();
-            }
-            if let Some(v) = obj.get("quick_note_shortcut").and_then(|v| v.as_str()) {
-                config.quick_note_shortcut = v.to_string();
-            }
-            if let Some(v) = obj.get("window_bounds") {
-                if let Ok(bounds) = serde_json::from_value(v.clone()) {
-                    config.window_bounds = bounds;
-                }
-            }
-            if let Some(v) = obj.get("last_note_id") {
-                config.last_note_id = v.as_str().map(|s| s.to_string());
-            }
-            if let Some(v) = obj.get("last_folder") {
-                config.last_folder = v.as_str().map(|s| s.to_string());
-            }
-            if let Some(v) = obj.get("pinned_notes").and_then(|v| v.as_array()) {
-                config.pinned_notes = v
-                    .iter()
-                    .filter_map(|item| item.as_str().map(|s| s.to_string()))
-                    .collect();
-            }
-            if let Some(v) = obj.get("minimal_mode_bounds") {
-                if let Ok(bounds) = serde_json::from_value(v.clone()) {
-                    config.minimal_mode_bounds = bounds;
-                }
-            }
-            if let Some(v) = obj.get("window_opacity").and_then(|v| v.as_f64()) {
-                config.window_opacity = v;
-            }
-            if let Some(v) = obj.get("auto_start_on_boot").and_then(|v| v.as_bool()) {
-                config.auto_start_on_boot = v;
-            }
-        }
+        let on_disk_version = json_value.get("version").and_then(|v| v.as_u64()).unwrap_or(0) as u32;
+        let was_migrated = on_disk_version < crate::models::CONFIG_VERSION;
 
-        Ok(config)
+        Ok((Config::migrate(json_value), was_migrated))
     }
 
     /// Gets a clone of the current configuration.
@@ -151,11 +323,15 @@ impl ConfigManager {
         {
             let mut config = self.config.write().unwrap();
             f(&mut config);
-            
+
             // Also update the shared config for async save
             let mut shared = self.config_for_save.write().unwrap();
             *shared = config.clone();
         }
+
+        // Makes any save already debouncing for an earlier generation stale,
+        // see `schedule_save`.
+        self.generation.fetch_add(1, Ordering::SeqCst);
     }
 
     /// Schedules a debounced save operation.
@@ -167,28 +343,49 @@ impl ConfigManager {
     /// Validates: Requirements 17.1
     pub async fn schedule_save(&self) {
         let mut handle_guard = self.save_handle.lock().await;
-        
+
         // Cancel any existing save task
         if let Some(handle) = handle_guard.take() {
             handle.abort();
         }
-        
+
         // Clone the Arc references for the async task
         let config_ref = Arc::clone(&self.config_for_save);
         let path_ref = Arc::clone(&self.config_path_for_save);
-        
+        let generation = Arc::clone(&self.generation);
+        let self_write = Arc::clone(&self.self_write);
+        let last_synced = Arc::clone(&self.last_synced);
+        let user_layer = Arc::clone(&self.user_layer);
+        let scheduled_generation = generation.load(Ordering::SeqCst);
+
         // Schedule a new save task
         let handle = tokio::spawn(async move {
             // Wait for the debounce period
             sleep(Duration::from_millis(SAVE_DEBOUNCE_MS)).await;
-            
-            // Perform the save
+
+            // A later `update()` (a local edit, or a `reload_from_disk`
+            // merge) landed while this save was debouncing - let that
+            // generation's own save, or the reload itself, be authoritative
+            // instead of writing this now-stale snapshot over it.
+            if generation.load(Ordering::SeqCst) != scheduled_generation {
+                return;
+            }
+
+            // Perform the save, reshaping the last-known on-disk object so
+            // any top-level keys this binary doesn't recognize round-trip.
             let config = config_ref.read().unwrap().clone();
-            if let Ok(content) = serde_json::to_string_pretty(&config) {
-                let _ = fs::write(path_ref.as_ref(), content);
+            let reshaped = reshape_for_save(&user_layer.lock().unwrap(), &config);
+            if let Ok(content) = serde_json::to_string_pretty(&reshaped) {
+                if atomic_write(path_ref.as_ref(), &content).is_ok() {
+                    if let Ok(mtime) = fs::metadata(path_ref.as_ref()).and_then(|m| m.modified()) {
+                        *self_write.lock().unwrap() = Some(mtime);
+                    }
+                    *last_synced.lock().unwrap() = config;
+                    *user_layer.lock().unwrap() = reshaped;
+                }
             }
         });
-        
+
         *handle_guard = Some(handle);
     }
 
@@ -214,12 +411,24 @@ impl ConfigManager {
     /// * `Err(String)` - If saving fails
     pub fn save_sync(&self) -> Result<(), String> {
         let config = self.config.read().unwrap().clone();
-        
-        let content = serde_json::to_string_pretty(&config)
+
+        let reshaped = {
+            let raw = self.user_layer.lock().unwrap();
+            reshape_for_save(&raw, &config)
+        };
+
+        let content = serde_json::to_string_pretty(&reshaped)
             .map_err(|e| format!("Failed to serialize config: {}", e))?;
 
-        fs::write(&self.config_path, content)
-            .map_err(|e| format!("Failed to write config file: {}", e))
+        atomic_write(&self.config_path, &content)?;
+
+        if let Ok(mtime) = fs::metadata(&self.config_path).and_then(|m| m.modified()) {
+            *self.self_write.lock().unwrap() = Some(mtime);
+        }
+        *self.last_synced.lock().unwrap() = config;
+        *self.user_layer.lock().unwrap() = reshaped;
+
+        Ok(())
     }
 
     /// Gets the last opened note information.
@@ -261,16 +470,46 @@ impl ConfigManager {
     }
 
     /// Sets the global shortcut configuration.
-    /// 
+    ///
+    /// Validates `shortcut` as a parseable accelerator (see
+    /// [`ShortcutManager::parse_shortcut_detailed`]) before persisting it, so
+    /// `config.json` can never end up holding a string the OS hotkey manager
+    /// would then fail to register.
+    ///
     /// # Arguments
     /// * `shortcut` - The new shortcut string
-    /// 
+    ///
+    /// # Returns
+    /// * `Ok(())` - `shortcut` parsed as a valid accelerator and was stored
+    /// * `Err(ShortcutParseError)` - `shortcut` didn't parse; the config is left unchanged
+    ///
     /// # Requirements
-    /// Validates: Requirements 7.4
-    pub fn set_global_shortcut(&self, shortcut: String) {
+    /// Validates: Requirements 7.4, 7.5
+    pub fn set_global_shortcut(&self, shortcut: String) -> Result<(), ShortcutParseError> {
+        ShortcutManager::parse_shortcut_detailed(&shortcut)?;
         self.update(|config| {
             config.global_shortcut = shortcut;
         });
+        Ok(())
+    }
+
+    /// Gets the global shortcut configuration, parsed into a structured
+    /// accelerator.
+    ///
+    /// # Returns
+    /// * `Ok(Shortcut)` - The parsed accelerator
+    /// * `Err(ShortcutParseError)` - The stored string no longer parses (e.g.
+    ///   a hand-edited config.json)
+    pub fn get_parsed_shortcut(&self) -> Result<Shortcut, ShortcutParseError> {
+        ShortcutManager::parse_shortcut_detailed(&self.get_global_shortcut())
+    }
+
+    /// Gets the quick-capture overlay shortcut configuration.
+    ///
+    /// # Returns
+    /// The current quick-capture shortcut string
+    pub fn get_quick_note_shortcut(&self) -> String {
+        self.config.read().unwrap().quick_note_shortcut.clone()
     }
 
     /// Gets the pinned notes list.
@@ -285,6 +524,25 @@ impl ConfigManager {
         });
     }
 
+    /// Gets the recent files list, most recently opened first.
+    pub fn get_recent_files(&self) -> Vec<RecentFile> {
+        self.config.read().unwrap().recent_files.clone()
+    }
+
+    /// Records a note as recently opened.
+    ///
+    /// Moves the entry to the front of the list (removing any prior entry
+    /// for the same note) and trims the list to `MAX_RECENT_FILES`.
+    pub fn add_recent_file(&self, entry: RecentFile) {
+        self.update(|config| {
+            config
+                .recent_files
+                .retain(|f| !(f.note_id == entry.note_id && f.folder == entry.folder));
+            config.recent_files.insert(0, entry);
+            config.recent_files.truncate(MAX_RECENT_FILES);
+        });
+    }
+
     /// Gets the window opacity.
     pub fn get_window_opacity(&self) -> f64 {
         self.config.read().unwrap().window_opacity
@@ -297,10 +555,437 @@ impl ConfigManager {
         });
     }
 
+    /// Gets the opacity configured for normal (non-minimal) mode.
+    pub fn get_normal_opacity(&self) -> f64 {
+        self.config.read().unwrap().normal_opacity
+    }
+
+    /// Sets the opacity configured for normal (non-minimal) mode.
+    pub fn set_normal_opacity(&self, opacity: f64) {
+        self.update(|config| {
+            config.normal_opacity = opacity;
+        });
+    }
+
+    /// Gets the opacity configured for minimal mode.
+    pub fn get_minimal_opacity(&self) -> f64 {
+        self.config.read().unwrap().minimal_opacity
+    }
+
+    /// Sets the opacity configured for minimal mode.
+    pub fn set_minimal_opacity(&self, opacity: f64) {
+        self.update(|config| {
+            config.minimal_opacity = opacity;
+        });
+    }
+
     /// Returns the config file path.
     pub fn config_path(&self) -> &PathBuf {
         &self.config_path
     }
+
+    /// Gets the persisted state for the window labeled `label`, if any.
+    pub fn get_window_state(&self, label: &str) -> Option<crate::window_state::WindowState> {
+        self.config.read().unwrap().window_states.get(label).cloned()
+    }
+
+    /// Persists `state` as the window state for the window labeled `label`.
+    pub fn set_window_state(&self, label: &str, state: crate::window_state::WindowState) {
+        self.update(|config| {
+            config.window_states.insert(label.to_string(), state);
+        });
+    }
+
+    /// Gets the visible-on-all-workspaces preference.
+    pub fn get_visible_on_all_workspaces(&self) -> bool {
+        self.config.read().unwrap().visible_on_all_workspaces
+    }
+
+    /// Sets the visible-on-all-workspaces preference.
+    pub fn set_visible_on_all_workspaces(&self, value: bool) {
+        self.update(|config| {
+            config.visible_on_all_workspaces = value;
+        });
+    }
+
+    /// Serializes the fully-populated default configuration - every field at
+    /// its [`Config::default`] value, including `version`. Used by `mded
+    /// --dump-default-config` to write out a canonical starting-point
+    /// `config.json`, e.g. after an upgrade changed the shape of the file.
+    pub fn default_config_json() -> serde_json::Value {
+        serde_json::to_value(Config::default()).expect("Config::default always serializes")
+    }
+
+    /// Produces a JSON Schema describing `config.json`'s shape: every
+    /// field's type, whether it's required, and a short description -
+    /// including the accelerator string format shared by the shortcut
+    /// fields - so an editor or external tool can validate or autocomplete a
+    /// hand-edited file.
+    pub fn config_schema() -> serde_json::Value {
+        let shortcut_description =
+            "A `+`-separated accelerator, e.g. \"CommandOrControl+Shift+N\" - one key token \
+             preceded by zero or more modifier tokens (Ctrl, Alt, Shift, Super, CommandOrControl).";
+
+        serde_json::json!({
+            "$schema": "http://json-schema.org/draft-07/schema#",
+            "title": "mded config.json",
+            "type": "object",
+            "properties": {
+                "global_shortcut": { "type": "string", "description": shortcut_description },
+                "clipboard_shortcut": { "type": "string", "description": shortcut_description },
+                "quick_note_shortcut": { "type": "string", "description": shortcut_description },
+                "window_bounds": { "$ref": "#/definitions/window_bounds", "description": "Main window's last saved position and size." },
+                "last_note_id": { "type": ["string", "null"], "description": "Id of the last-opened note, restored on launch." },
+                "last_folder": { "type": ["string", "null"], "description": "Folder of the last-opened note." },
+                "pinned_notes": { "type": "array", "items": { "type": "string" }, "description": "Note ids pinned to the top of the list." },
+                "minimal_mode_bounds": { "$ref": "#/definitions/window_bounds", "description": "Window position and size while in minimal mode." },
+                "window_opacity": { "type": "number", "minimum": 0.0, "maximum": 1.0, "description": "Legacy single opacity value, superseded by normal_opacity/minimal_opacity." },
+                "normal_opacity": { "type": "number", "minimum": 0.0, "maximum": 1.0, "description": "Window opacity while in normal (non-minimal) mode." },
+                "minimal_opacity": { "type": "number", "minimum": 0.0, "maximum": 1.0, "description": "Window opacity while in minimal mode." },
+                "auto_start_on_boot": { "type": "boolean", "description": "Whether the app launches on system login." },
+                "recent_files": { "type": "array", "description": "Recently opened notes, most recent first." },
+                "menubar_mode": { "type": "boolean", "description": "Whether the tray left-click toggle repositions the window under the tray icon." },
+                "window_states": { "type": "object", "description": "Persisted position/size/mode per window label." },
+                "visible_on_all_workspaces": { "type": "boolean", "description": "Whether the main window stays visible across all virtual desktops/Spaces." },
+                "startup_mode": { "type": "string", "enum": ["windowed", "maximized", "minimal"], "description": "How the main window presents itself on launch." },
+                "version": { "type": "integer", "minimum": 0, "description": "Schema version, used by Config::migrate to decide which upgrades still apply." }
+            },
+            "required": [
+                "global_shortcut", "clipboard_shortcut", "quick_note_shortcut", "window_bounds",
+                "pinned_notes", "minimal_mode_bounds", "window_opacity", "auto_start_on_boot",
+                "recent_files", "menubar_mode", "window_states", "visible_on_all_workspaces"
+            ],
+            "definitions": {
+                "window_bounds": {
+                    "type": "object",
+                    "properties": {
+                        "width": { "type": "integer", "minimum": 0 },
+                        "height": { "type": "integer", "minimum": 0 },
+                        "x": { "type": ["integer", "null"] },
+                        "y": { "type": ["integer", "null"] },
+                        "scale_factor": { "type": "number" }
+                    },
+                    "required": ["width", "height"]
+                }
+            }
+        })
+    }
+
+    /// Returns every layer-resolvable field of the current config, each
+    /// tagged with the layer ([`ConfigSource`]) that actually supplied its
+    /// value. Lets callers/tests answer "why is this set?" without
+    /// re-deriving the merge by hand.
+    pub fn get_annotated(&self) -> Vec<AnnotatedValue> {
+        let config_value = serde_json::to_value(self.get()).expect("Config always serializes");
+
+        OVERRIDABLE_FIELDS
+            .iter()
+            .map(|field| {
+                let source = if field_set_in(&self.command_arg_layer, field) {
+                    ConfigSource::CommandArg
+                } else if field_set_in(&self.env_layer, field) {
+                    ConfigSource::Env
+                } else if field_set_in(&self.user_layer.lock().unwrap(), field) {
+                    ConfigSource::User
+                } else {
+                    ConfigSource::Default
+                };
+
+                AnnotatedValue {
+                    path: vec![(*field).to_string()],
+                    source,
+                    value: config_value.get(*field).cloned().unwrap_or(serde_json::Value::Null),
+                }
+            })
+            .collect()
+    }
+
+    /// Detects and applies an external edit to `config.json` - made by hand,
+    /// or by another running instance - merging it into the in-memory
+    /// config so the app picks it up without a restart. Called by the
+    /// filesystem watcher spawned in `lib.rs`'s `setup` hook whenever the
+    /// file changes.
+    ///
+    /// An event whose file mtime matches the last write this manager
+    /// performed itself is ignored, so a save this instance just made isn't
+    /// mistaken for an external edit and reloaded right back on top of
+    /// itself. Any field this manager changed since the last time memory
+    /// and disk were known to agree (an unsaved local edit, including one a
+    /// pending debounced save hasn't written out yet) is re-applied on top
+    /// of the freshly-read file rather than being silently lost - this
+    /// covers the `OVERRIDABLE_FIELDS` set; other fields follow the
+    /// external file as-is, same as a normal load. The generation bump
+    /// ensures any save that was still debouncing for the prior state is
+    /// now stale and discards itself (see `schedule_save`).
+    pub fn reload_from_disk(&self) {
+        let mtime = fs::metadata(&self.config_path).and_then(|m| m.modified()).ok();
+
+        {
+            let mut self_write = self.self_write.lock().unwrap();
+            if let (Some(mtime), Some(last_self_write)) = (mtime, *self_write) {
+                if mtime == last_self_write {
+                    return;
+                }
+            }
+            *self_write = mtime;
+        }
+
+        let (from_disk, was_migrated) = match Self::load_from_file(&self.config_path) {
+            Ok(result) => result,
+            Err(_) => return,
+        };
+        let raw_from_disk = Self::read_user_layer(&self.config_path)
+            .unwrap_or_else(|_| serde_json::Value::Object(serde_json::Map::new()));
+
+        let local_changes = {
+            let current = self.config.read().unwrap();
+            let baseline = self.last_synced.lock().unwrap();
+            local_overrides_since(&current, &baseline)
+        };
+
+        let mut merged = from_disk.clone();
+        apply_layer(&mut merged, &self.env_layer);
+        apply_layer(&mut merged, &self.command_arg_layer);
+        apply_layer(&mut merged, &local_changes);
+
+        {
+            let mut config = self.config.write().unwrap();
+            *config = merged.clone();
+            let mut shared = self.config_for_save.write().unwrap();
+            *shared = merged;
+        }
+        *self.last_synced.lock().unwrap() = from_disk;
+        *self.user_layer.lock().unwrap() = raw_from_disk;
+
+        self.generation.fetch_add(1, Ordering::SeqCst);
+        let _ = self.reload_tx.send(self.generation.load(Ordering::SeqCst));
+
+        // An externally-written file at an older schema version was
+        // upgraded in memory above - write the upgraded version back so it
+        // only has to be migrated once, same as a fresh load in `new_with_args`.
+        if was_migrated {
+            let _ = self.save_sync();
+        }
+    }
+
+    /// Subscribes to reload notifications - the channel carries the new
+    /// generation number each time `reload_from_disk` applies an
+    /// externally-detected edit, so e.g. the frontend can re-render.
+    pub fn subscribe_reload(&self) -> tokio::sync::watch::Receiver<u64> {
+        self.reload_rx.clone()
+    }
+}
+
+/// Opens `config.json` (creating an empty file if none exists yet) and takes
+/// an advisory exclusive lock on it via fs2's `try_lock_exclusive`, so a
+/// second instance launched against the same config file fails fast with
+/// [`ConfigError::AlreadyLocked`] instead of racing this one's writes. The
+/// returned `File` must be kept alive for as long as the lock should be held
+/// - [`ConfigManager`] stores it in `_config_lock` purely so its `Drop`
+/// releases the OS-level lock.
+fn acquire_config_lock(path: &Path) -> Result<fs::File, ConfigError> {
+    let file = fs::OpenOptions::new()
+        .read(true)
+        .write(true)
+        .create(true)
+        .open(path)
+        .map_err(|e| ConfigError::Other(format!("Failed to open config file: {}", e)))?;
+
+    match file.try_lock_exclusive() {
+        Ok(()) => Ok(file),
+        Err(e) if e.kind() == std::io::ErrorKind::WouldBlock => Err(ConfigError::AlreadyLocked),
+        Err(e) => Err(ConfigError::Other(format!("Failed to lock config file: {}", e))),
+    }
+}
+
+/// Writes `content` to `path` atomically: writes to a sibling `.tmp` file,
+/// `fsync`s it so the bytes are durable, then renames it over `path`. A
+/// reader always sees either the previous complete file or the fully-written
+/// new one - never a half-written `config.json` from a process killed
+/// mid-write, as a plain `fs::write` would risk.
+fn atomic_write(path: &Path, content: &str) -> Result<(), String> {
+    let tmp_path = PathBuf::from(format!("{}.tmp", path.display()));
+
+    let mut tmp_file = fs::File::create(&tmp_path)
+        .map_err(|e| format!("Failed to create temp config file: {}", e))?;
+    tmp_file
+        .write_all(content.as_bytes())
+        .map_err(|e| format!("Failed to write temp config file: {}", e))?;
+    tmp_file
+        .sync_all()
+        .map_err(|e| format!("Failed to sync temp config file: {}", e))?;
+    drop(tmp_file);
+
+    fs::rename(&tmp_path, path).map_err(|e| format!("Failed to replace config file: {}", e))
+}
+
+/// Reshapes `raw` (the last-known on-disk `config.json` object, including
+/// any keys this binary doesn't recognize) for writing, by overwriting only
+/// the keys [`Config`] knows about with `config`'s current values. Keys
+/// `raw` has that `config` doesn't - written by a newer binary version, or a
+/// plugin - are left untouched, so they round-trip through a save instead of
+/// being silently dropped. This is checked recursively
+/// ([`deep_merge_preserving_unknown_keys`]), not just at the top level, so an
+/// unrecognized field nested inside an otherwise-known object (e.g. a future
+/// field on one `window_states` entry) survives too.
+fn reshape_for_save(raw: &serde_json::Value, config: &Config) -> serde_json::Value {
+    let typed = serde_json::to_value(config).expect("Config always serializes");
+    deep_merge_preserving_unknown_keys(raw, &typed)
+}
+
+/// Recursively overlays `typed`'s values onto `raw`, keeping any key `raw`
+/// has at a given object node that `typed` doesn't. Arrays and scalars are
+/// taken from `typed` as-is - there's no unknown-key concept to preserve
+/// inside them - so only object nodes recurse.
+fn deep_merge_preserving_unknown_keys(raw: &serde_json::Value, typed: &serde_json::Value) -> serde_json::Value {
+    match (raw.as_object(), typed.as_object()) {
+        (Some(raw_obj), Some(typed_obj)) => {
+            let mut merged = raw_obj.clone();
+            for (key, typed_value) in typed_obj {
+                let merged_value = match raw_obj.get(key) {
+                    Some(raw_value) => deep_merge_preserving_unknown_keys(raw_value, typed_value),
+                    None => typed_value.clone(),
+                };
+                merged.insert(key.clone(), merged_value);
+            }
+            serde_json::Value::Object(merged)
+        }
+        // `raw` has nothing to preserve at this node (absent, or not an
+        // object) - `typed` is authoritative.
+        _ => typed.clone(),
+    }
+}
+
+/// Applies the known-field overrides in `layer` onto `config`, the same
+/// type-checked, field-by-field approach [`ConfigManager::load_from_file`]
+/// uses for the `User` layer - a malformed or absent value for a field is
+/// simply skipped rather than overwriting it with something unusable.
+fn apply_layer(config: &mut Config, layer: &serde_json::Value) {
+    let Some(obj) = layer.as_object() else { return };
+
+    if let Some(v) = obj.get("global_shortcut").and_then(|v| v.as_str()) {
+        config.global_shortcut = v.to_string();
+    }
+    if let Some(v) = obj.get("clipboard_shortcut").and_then(|v| v.as_str()) {
+        config.clipboard_shortcut = v.to_string();
+    }
+    if let Some(v) = obj.get("quick_note_shortcut").and_then(|v| v.as_str()) {
+        config.quick_note_shortcut = v.to_string();
+    }
+    if let Some(v) = obj.get("window_opacity").and_then(|v| v.as_f64()) {
+        config.window_opacity = v;
+    }
+    if let Some(v) = obj.get("normal_opacity").and_then(|v| v.as_f64()) {
+        config.normal_opacity = v;
+    }
+    if let Some(v) = obj.get("minimal_opacity").and_then(|v| v.as_f64()) {
+        config.minimal_opacity = v;
+    }
+    if let Some(v) = obj.get("auto_start_on_boot").and_then(|v| v.as_bool()) {
+        config.auto_start_on_boot = v;
+    }
+    if let Some(v) = obj.get("menubar_mode").and_then(|v| v.as_bool()) {
+        config.menubar_mode = v;
+    }
+    if let Some(v) = obj.get("visible_on_all_workspaces").and_then(|v| v.as_bool()) {
+        config.visible_on_all_workspaces = v;
+    }
+}
+
+/// Builds a layer of the `OVERRIDABLE_FIELDS` where `current` differs from
+/// `baseline`, i.e. local edits made since memory and disk were last known
+/// to agree. Used by [`ConfigManager::reload_from_disk`] to carry unsaved
+/// local changes forward onto a freshly-read external file.
+fn local_overrides_since(current: &Config, baseline: &Config) -> serde_json::Value {
+    let mut map = serde_json::Map::new();
+
+    if current.global_shortcut != baseline.global_shortcut {
+        map.insert("global_shortcut".to_string(), serde_json::json!(current.global_shortcut));
+    }
+    if current.clipboard_shortcut != baseline.clipboard_shortcut {
+        map.insert("clipboard_shortcut".to_string(), serde_json::json!(current.clipboard_shortcut));
+    }
+    if current.quick_note_shortcut != baseline.quick_note_shortcut {
+        map.insert("quick_note_shortcut".to_string(), serde_json::json!(current.quick_note_shortcut));
+    }
+    if current.window_opacity != baseline.window_opacity {
+        map.insert("window_opacity".to_string(), serde_json::json!(current.window_opacity));
+    }
+    if current.normal_opacity != baseline.normal_opacity {
+        map.insert("normal_opacity".to_string(), serde_json::json!(current.normal_opacity));
+    }
+    if current.minimal_opacity != baseline.minimal_opacity {
+        map.insert("minimal_opacity".to_string(), serde_json::json!(current.minimal_opacity));
+    }
+    if current.auto_start_on_boot != baseline.auto_start_on_boot {
+        map.insert("auto_start_on_boot".to_string(), serde_json::json!(current.auto_start_on_boot));
+    }
+    if current.menubar_mode != baseline.menubar_mode {
+        map.insert("menubar_mode".to_string(), serde_json::json!(current.menubar_mode));
+    }
+    if current.visible_on_all_workspaces != baseline.visible_on_all_workspaces {
+        map.insert(
+            "visible_on_all_workspaces".to_string(),
+            serde_json::json!(current.visible_on_all_workspaces),
+        );
+    }
+
+    serde_json::Value::Object(map)
+}
+
+/// Builds the `Env` layer by reading an `MDED_<FIELD>` variable for each of
+/// [`OVERRIDABLE_FIELDS`] (e.g. `MDED_GLOBAL_SHORTCUT`, `MDED_WINDOW_OPACITY`).
+fn read_env_layer() -> serde_json::Value {
+    let mut map = serde_json::Map::new();
+    for field in OVERRIDABLE_FIELDS {
+        let var_name = format!("MDED_{}", field.to_uppercase());
+        if let Ok(raw) = std::env::var(&var_name) {
+            if let Some(value) = parse_override_value(field, &raw) {
+                map.insert((*field).to_string(), value);
+            }
+        }
+    }
+    serde_json::Value::Object(map)
+}
+
+/// Builds the `CommandArg` layer from `(field, value)` pairs, ignoring any
+/// field not in [`OVERRIDABLE_FIELDS`].
+fn build_command_arg_layer(command_args: &[(String, String)]) -> serde_json::Value {
+    let mut map = serde_json::Map::new();
+    for (field, raw) in command_args {
+        if OVERRIDABLE_FIELDS.contains(&field.as_str()) {
+            if let Some(value) = parse_override_value(field, raw) {
+                map.insert(field.clone(), value);
+            }
+        }
+    }
+    serde_json::Value::Object(map)
+}
+
+/// Parses a raw string override (from an env var or a `key=value` launch
+/// arg) into the JSON shape `field` expects, based on its type in [`Config`].
+/// An override that fails to parse as its field's type is dropped rather
+/// than corrupting the field with a string where a number or bool belongs.
+fn parse_override_value(field: &str, raw: &str) -> Option<serde_json::Value> {
+    match field {
+        "window_opacity" | "normal_opacity" | "minimal_opacity" => {
+            raw.parse::<f64>().ok().map(|v| serde_json::json!(v))
+        }
+        "auto_start_on_boot" | "menubar_mode" | "visible_on_all_workspaces" => {
+            raw.parse::<bool>().ok().map(|v| serde_json::json!(v))
+        }
+        _ => Some(serde_json::json!(raw)),
+    }
+}
+
+/// Whether `layer` (a JSON object, as built by [`read_env_layer`] or
+/// [`build_command_arg_layer`], or parsed directly from `config.json`) set
+/// `field`.
+fn field_set_in(layer: &serde_json::Value, field: &str) -> bool {
+    layer
+        .as_object()
+        .map(|obj| obj.contains_key(field))
+        .unwrap_or(false)
 }
 
 /// Merges a partial config JSON with defaults.
@@ -358,9 +1043,28 @@ pub fn merge_config_with_defaults(partial_json: &str) -> Result<Config, String>
         if let Some(v) = obj.get("window_opacity").and_then(|v| v.as_f64()) {
             config.window_opacity = v;
         }
+        if let Some(v) = obj.get("normal_opacity").and_then(|v| v.as_f64()) {
+            config.normal_opacity = v;
+        }
+        if let Some(v) = obj.get("minimal_opacity").and_then(|v| v.as_f64()) {
+            config.minimal_opacity = v;
+        }
         if let Some(v) = obj.get("auto_start_on_boot").and_then(|v| v.as_bool()) {
             config.auto_start_on_boot = v;
         }
+        if let Some(v) = obj.get("recent_files") {
+            if let Ok(recent_files) = serde_json::from_value(v.clone()) {
+                config.recent_files = recent_files;
+            }
+        }
+        if let Some(v) = obj.get("menubar_mode").and_then(|v| v.as_bool()) {
+            config.menubar_mode = v;
+        }
+        if let Some(v) = obj.get("startup_mode") {
+            if let Ok(startup_mode) = serde_json::from_value(v.clone()) {
+                config.startup_mode = startup_mode;
+            }
+        }
     }
 
     Ok(config)
@@ -445,6 +1149,29 @@ mod tests {
         assert!(content.contains("Ctrl+Alt+X"));
     }
 
+    #[test]
+    fn test_config_manager_save_sync_leaves_no_tmp_file_behind() {
+        let temp_dir = tempdir().unwrap();
+        let config_path = temp_dir.path().join("config.json");
+        let tmp_path = temp_dir.path().join("config.json.tmp");
+
+        let manager = ConfigManager::new(config_path).unwrap();
+        manager.save_sync().unwrap();
+
+        assert!(!tmp_path.exists());
+    }
+
+    #[test]
+    fn test_second_config_manager_on_same_file_is_already_locked() {
+        let temp_dir = tempdir().unwrap();
+        let config_path = temp_dir.path().join("config.json");
+
+        let _first = ConfigManager::new(config_path.clone()).unwrap();
+        let second = ConfigManager::new(config_path);
+
+        assert!(matches!(second, Err(ConfigError::AlreadyLocked)));
+    }
+
     #[test]
     fn test_config_manager_last_note() {
         let temp_dir = tempdir().unwrap();
@@ -476,10 +1203,305 @@ mod tests {
         assert_eq!(manager.get_global_shortcut(), "CommandOrControl+Shift+N");
         
         // Update shortcut
-        manager.set_global_shortcut("Ctrl+Alt+N".to_string());
+        manager.set_global_shortcut("Ctrl+Alt+N".to_string()).unwrap();
         assert_eq!(manager.get_global_shortcut(), "Ctrl+Alt+N");
     }
 
+    #[test]
+    fn test_set_global_shortcut_rejects_unparseable_accelerator_and_leaves_config_unchanged() {
+        let temp_dir = tempdir().unwrap();
+        let config_path = temp_dir.path().join("config.json");
+
+        let manager = ConfigManager::new(config_path).unwrap();
+        let err = manager.set_global_shortcut("NotAShortcut".to_string()).unwrap_err();
+
+        assert_eq!(err.invalid_arg, "NotAShortcut");
+        assert_eq!(manager.get_global_shortcut(), "CommandOrControl+Shift+N");
+    }
+
+    #[test]
+    fn test_get_parsed_shortcut_round_trips_the_default_accelerator() {
+        let temp_dir = tempdir().unwrap();
+        let config_path = temp_dir.path().join("config.json");
+
+        let manager = ConfigManager::new(config_path).unwrap();
+        assert!(manager.get_parsed_shortcut().is_ok());
+    }
+
+    #[test]
+    fn test_config_manager_window_state_round_trip() {
+        use crate::window_state::WindowState;
+
+        let temp_dir = tempdir().unwrap();
+        let config_path = temp_dir.path().join("config.json");
+
+        let manager = ConfigManager::new(config_path.clone()).unwrap();
+        assert!(manager.get_window_state("main").is_none());
+
+        let state = WindowState { x: Some(10), y: Some(20), width: Some(800), height: Some(600), maximized: Some(false), ..Default::default() };
+        manager.set_window_state("main", state.clone());
+        assert_eq!(manager.get_window_state("main"), Some(state.clone()));
+
+        manager.save_sync().unwrap();
+        let reloaded = ConfigManager::new(config_path).unwrap();
+        assert_eq!(reloaded.get_window_state("main"), Some(state));
+    }
+
+    #[test]
+    fn test_config_manager_visible_on_all_workspaces() {
+        let temp_dir = tempdir().unwrap();
+        let config_path = temp_dir.path().join("config.json");
+
+        let manager = ConfigManager::new(config_path).unwrap();
+        assert!(!manager.get_visible_on_all_workspaces());
+
+        manager.set_visible_on_all_workspaces(true);
+        assert!(manager.get_visible_on_all_workspaces());
+    }
+
+    #[test]
+    fn test_config_manager_normal_and_minimal_opacity() {
+        let temp_dir = tempdir().unwrap();
+        let config_path = temp_dir.path().join("config.json");
+
+        let manager = ConfigManager::new(config_path).unwrap();
+        assert_eq!(manager.get_normal_opacity(), 1.0);
+        assert_eq!(manager.get_minimal_opacity(), 1.0);
+
+        manager.set_normal_opacity(0.9);
+        manager.set_minimal_opacity(0.5);
+        assert_eq!(manager.get_normal_opacity(), 0.9);
+        assert_eq!(manager.get_minimal_opacity(), 0.5);
+    }
+
+    #[test]
+    fn test_get_annotated_reports_default_when_no_layer_set_a_field() {
+        let temp_dir = tempdir().unwrap();
+        let config_path = temp_dir.path().join("config.json");
+
+        let manager = ConfigManager::new(config_path).unwrap();
+        let annotated = manager.get_annotated();
+
+        let global_shortcut = annotated
+            .iter()
+            .find(|a| a.path == vec!["global_shortcut".to_string()])
+            .unwrap();
+        assert_eq!(global_shortcut.source, ConfigSource::Default);
+        assert_eq!(global_shortcut.value, serde_json::json!("CommandOrControl+Shift+N"));
+    }
+
+    #[test]
+    fn test_get_annotated_reports_user_when_config_json_sets_a_field() {
+        let temp_dir = tempdir().unwrap();
+        let config_path = temp_dir.path().join("config.json");
+        fs::write(&config_path, r#"{"global_shortcut": "Ctrl+Alt+M"}"#).unwrap();
+
+        let manager = ConfigManager::new(config_path).unwrap();
+        let annotated = manager.get_annotated();
+
+        let global_shortcut = annotated
+            .iter()
+            .find(|a| a.path == vec!["global_shortcut".to_string()])
+            .unwrap();
+        assert_eq!(global_shortcut.source, ConfigSource::User);
+        assert_eq!(global_shortcut.value, serde_json::json!("Ctrl+Alt+M"));
+    }
+
+    #[test]
+    fn test_command_arg_layer_shadows_user_and_env_layers() {
+        let temp_dir = tempdir().unwrap();
+        let config_path = temp_dir.path().join("config.json");
+        fs::write(&config_path, r#"{"global_shortcut": "Ctrl+Alt+M"}"#).unwrap();
+
+        let manager = ConfigManager::new_with_args(
+            config_path,
+            vec![("global_shortcut".to_string(), "Ctrl+Alt+Z".to_string())],
+        )
+        .unwrap();
+
+        assert_eq!(manager.get_global_shortcut(), "Ctrl+Alt+Z");
+        let annotated = manager.get_annotated();
+        let global_shortcut = annotated
+            .iter()
+            .find(|a| a.path == vec!["global_shortcut".to_string()])
+            .unwrap();
+        assert_eq!(global_shortcut.source, ConfigSource::CommandArg);
+    }
+
+    #[test]
+    fn test_env_layer_overrides_user_layer_but_not_command_arg() {
+        let temp_dir = tempdir().unwrap();
+        let config_path = temp_dir.path().join("config.json");
+        fs::write(&config_path, r#"{"window_opacity": 0.8}"#).unwrap();
+
+        // SAFETY: this test owns the env var it sets and clears it before
+        // returning, and no other test reads MDED_WINDOW_OPACITY.
+        std::env::set_var("MDED_WINDOW_OPACITY", "0.6");
+        let manager = ConfigManager::new(config_path).unwrap();
+        std::env::remove_var("MDED_WINDOW_OPACITY");
+
+        assert_eq!(manager.get_window_opacity(), 0.6);
+        let annotated = manager.get_annotated();
+        let opacity = annotated
+            .iter()
+            .find(|a| a.path == vec!["window_opacity".to_string()])
+            .unwrap();
+        assert_eq!(opacity.source, ConfigSource::Env);
+    }
+
+    #[test]
+    fn test_invalid_env_override_is_ignored() {
+        let temp_dir = tempdir().unwrap();
+        let config_path = temp_dir.path().join("config.json");
+
+        // SAFETY: see test_env_layer_overrides_user_layer_but_not_command_arg.
+        std::env::set_var("MDED_WINDOW_OPACITY", "not-a-number");
+        let manager = ConfigManager::new(config_path).unwrap();
+        std::env::remove_var("MDED_WINDOW_OPACITY");
+
+        // An override that fails to parse as the field's type is dropped,
+        // so the field falls back through to its Default value untouched.
+        assert_eq!(manager.get_window_opacity(), 1.0);
+        let annotated = manager.get_annotated();
+        let opacity = annotated
+            .iter()
+            .find(|a| a.path == vec!["window_opacity".to_string()])
+            .unwrap();
+        assert_eq!(opacity.source, ConfigSource::Default);
+    }
+
+    #[test]
+    fn test_reload_from_disk_with_no_config_file_is_a_no_op() {
+        let temp_dir = tempdir().unwrap();
+        let config_path = temp_dir.path().join("config.json");
+
+        let manager = ConfigManager::new(config_path).unwrap();
+        manager.reload_from_disk();
+        assert_eq!(manager.get(), Config::default());
+    }
+
+    #[test]
+    fn test_reload_from_disk_picks_up_an_external_edit() {
+        let temp_dir = tempdir().unwrap();
+        let config_path = temp_dir.path().join("config.json");
+        fs::write(&config_path, r#"{"global_shortcut": "Initial"}"#).unwrap();
+
+        let manager = ConfigManager::new(config_path.clone()).unwrap();
+        assert_eq!(manager.get_global_shortcut(), "Initial");
+
+        std::thread::sleep(std::time::Duration::from_millis(10));
+        fs::write(&config_path, r#"{"global_shortcut": "ExternallyEdited"}"#).unwrap();
+
+        manager.reload_from_disk();
+        assert_eq!(manager.get_global_shortcut(), "ExternallyEdited");
+    }
+
+    #[test]
+    fn test_reload_from_disk_ignores_its_own_write() {
+        let temp_dir = tempdir().unwrap();
+        let config_path = temp_dir.path().join("config.json");
+
+        let manager = ConfigManager::new(config_path).unwrap();
+        manager.set_global_shortcut("Ctrl+Alt+S".to_string()).unwrap();
+        manager.save_sync().unwrap();
+
+        // Nothing external touched the file since our own write, so this
+        // must be a no-op rather than re-reading (and re-notifying) our own
+        // save as if it were an external edit.
+        let before = manager.reload_rx.clone();
+        manager.reload_from_disk();
+        assert_eq!(manager.get_global_shortcut(), "Ctrl+Alt+S");
+        assert_eq!(*before.borrow(), *manager.reload_rx.borrow());
+    }
+
+    #[test]
+    fn test_reload_from_disk_preserves_unsaved_local_changes_over_the_external_file() {
+        let temp_dir = tempdir().unwrap();
+        let config_path = temp_dir.path().join("config.json");
+        fs::write(&config_path, r#"{"global_shortcut": "A", "clipboard_shortcut": "OldClip"}"#).unwrap();
+
+        let manager = ConfigManager::new(config_path.clone()).unwrap();
+
+        // An unsaved local edit - not yet written back to config.json.
+        manager.set_global_shortcut("Ctrl+Alt+L".to_string()).unwrap();
+
+        std::thread::sleep(std::time::Duration::from_millis(10));
+        fs::write(&config_path, r#"{"global_shortcut": "External", "clipboard_shortcut": "NewClip"}"#).unwrap();
+
+        manager.reload_from_disk();
+
+        // The unsaved local edit wins over the external file's value...
+        assert_eq!(manager.get_global_shortcut(), "Ctrl+Alt+L");
+        // ...but a field we hadn't touched locally still picks up the
+        // external file's new value.
+        assert_eq!(manager.get().clipboard_shortcut, "NewClip");
+    }
+
+    #[test]
+    fn test_reload_from_disk_bumps_generation_and_notifies_subscribers() {
+        let temp_dir = tempdir().unwrap();
+        let config_path = temp_dir.path().join("config.json");
+        fs::write(&config_path, r#"{"global_shortcut": "A"}"#).unwrap();
+
+        let manager = ConfigManager::new(config_path.clone()).unwrap();
+        let mut subscriber = manager.subscribe_reload();
+        let before = *subscriber.borrow();
+
+        std::thread::sleep(std::time::Duration::from_millis(10));
+        fs::write(&config_path, r#"{"global_shortcut": "B"}"#).unwrap();
+        manager.reload_from_disk();
+
+        assert!(subscriber.has_changed().unwrap());
+        assert!(*subscriber.borrow() > before);
+    }
+
+    #[test]
+    fn test_save_sync_preserves_an_unknown_top_level_key() {
+        let temp_dir = tempdir().unwrap();
+        let config_path = temp_dir.path().join("config.json");
+        fs::write(&config_path, r#"{"experimental_feature": true}"#).unwrap();
+
+        let manager = ConfigManager::new(config_path.clone()).unwrap();
+        manager.set_window_opacity(0.5);
+        manager.save_sync().unwrap();
+
+        let saved: serde_json::Value =
+            serde_json::from_str(&fs::read_to_string(&config_path).unwrap()).unwrap();
+        assert_eq!(saved.get("experimental_feature"), Some(&serde_json::json!(true)));
+        assert_eq!(saved.get("window_opacity"), Some(&serde_json::json!(0.5)));
+
+        // A subsequent load still round-trips the unknown key too.
+        drop(manager);
+        let reloaded = ConfigManager::new(config_path.clone()).unwrap();
+        reloaded.save_sync().unwrap();
+        let reloaded_raw: serde_json::Value =
+            serde_json::from_str(&fs::read_to_string(&config_path).unwrap()).unwrap();
+        assert_eq!(reloaded_raw.get("experimental_feature"), Some(&serde_json::json!(true)));
+    }
+
+    #[test]
+    fn test_save_sync_preserves_an_unknown_key_nested_inside_a_known_object() {
+        let temp_dir = tempdir().unwrap();
+        let config_path = temp_dir.path().join("config.json");
+        fs::write(
+            &config_path,
+            r#"{"window_bounds": {"width": 800, "height": 600, "scale_factor": 1.0, "future_field": "x"}}"#,
+        )
+        .unwrap();
+
+        let manager = ConfigManager::new(config_path.clone()).unwrap();
+        manager.set_window_opacity(0.5);
+        manager.save_sync().unwrap();
+
+        let saved: serde_json::Value =
+            serde_json::from_str(&fs::read_to_string(&config_path).unwrap()).unwrap();
+        assert_eq!(
+            saved.get("window_bounds").and_then(|b| b.get("future_field")),
+            Some(&serde_json::json!("x"))
+        );
+        assert_eq!(saved.get("window_bounds").and_then(|b| b.get("width")), Some(&serde_json::json!(800)));
+    }
+
     #[test]
     fn test_merge_config_with_defaults_empty() {
         let config = merge_config_with_defaults("").unwrap();
@@ -504,6 +1526,47 @@ mod tests {
         assert_eq!(config, Config::default());
     }
 
+    #[test]
+    fn test_merge_config_with_defaults_startup_mode() {
+        let partial = r#"{"startup_mode": "minimal"}"#;
+        let config = merge_config_with_defaults(partial).unwrap();
+        assert_eq!(config.startup_mode, crate::models::StartupMode::Minimal);
+    }
+
+    #[test]
+    fn test_merge_config_with_defaults_missing_startup_mode_defaults_to_windowed() {
+        let config = merge_config_with_defaults("{}").unwrap();
+        assert_eq!(config.startup_mode, crate::models::StartupMode::Windowed);
+    }
+
+    #[test]
+    fn test_default_config_json_round_trips_to_config_default() {
+        let dumped = ConfigManager::default_config_json();
+        let config: Config = serde_json::from_value(dumped).unwrap();
+        assert_eq!(config, Config::default());
+    }
+
+    #[test]
+    fn test_config_schema_describes_every_required_field() {
+        let schema = ConfigManager::config_schema();
+        let properties = schema.get("properties").unwrap().as_object().unwrap();
+        let defaults = serde_json::to_value(Config::default()).unwrap();
+
+        for field in defaults.as_object().unwrap().keys() {
+            assert!(properties.contains_key(field), "schema is missing field '{}'", field);
+        }
+    }
+
+    // Strategy for generating accelerator strings that parse successfully,
+    // for tests exercising `set_global_shortcut`'s validation.
+    fn valid_accelerator_strategy() -> impl Strategy<Value = String> {
+        let modifier = prop_oneof![Just("Ctrl"), Just("Alt"), Just("Shift"), Just("Super")];
+        let key = prop_oneof![
+            Just("A"), Just("B"), Just("N"), Just("S"), Just("X"), Just("F1"), Just("Space"),
+        ];
+        (modifier, key).prop_map(|(m, k)| format!("{}+{}", m, k))
+    }
+
     // Strategy for generating optional config fields
     fn optional_shortcut() -> impl Strategy<Value = Option<String>> {
         prop_oneof![
@@ -674,22 +1737,22 @@ mod tests {
         }
 
         /// **Feature: mded-tauri-migration, Property: Shortcut configuration round-trip**
-        /// **Validates: Requirements 7.4**
-        /// 
+        /// **Validates: Requirements 7.4, 7.5**
+        ///
         /// For any valid shortcut string, setting it and then retrieving it should
         /// return the same shortcut. Additionally, saving and reloading the config
         /// should preserve the shortcut.
         #[test]
         fn prop_shortcut_persistence_round_trip(
-            shortcut in "[A-Za-z][A-Za-z0-9+]{0,30}"
+            shortcut in valid_accelerator_strategy()
         ) {
             let temp_dir = tempdir().unwrap();
             let config_path = temp_dir.path().join("config.json");
-            
+
             // Create a config manager and set the shortcut
             let manager = ConfigManager::new(config_path.clone()).unwrap();
-            manager.set_global_shortcut(shortcut.clone());
-            
+            manager.set_global_shortcut(shortcut.clone()).unwrap();
+
             // Verify the shortcut is set correctly in memory
             let retrieved = manager.get_global_shortcut();
             prop_assert_eq!(
@@ -1,23 +1,70 @@
 use std::fs;
+use std::io::{Read, Write};
 use std::path::{Path, PathBuf};
+use serde::{Deserialize, Serialize};
+
+/// Resolves `.`/`..` segments in `path` lexically (in memory, without
+/// touching disk), the way `path-clean` does. `relative_path` inputs are
+/// already rejected for containing these before they ever reach here, but
+/// this keeps the join of `base_dir` (which could itself, in principle,
+/// contain a `..`) well-formed before it's checked for containment.
+fn lexically_normalize(path: &Path) -> PathBuf {
+    let mut stack: Vec<std::path::Component> = Vec::new();
+    for component in path.components() {
+        match component {
+            std::path::Component::CurDir => {}
+            std::path::Component::ParentDir => {
+                stack.pop();
+            }
+            other => stack.push(other),
+        }
+    }
+    stack.into_iter().collect()
+}
+
+/// Walks up from `path` to the nearest ancestor that exists on disk and
+/// canonicalizes it, so a path that doesn't exist *yet* can still have its
+/// existing parents checked for an escaping symlink (e.g. a symlinked
+/// subfolder whose target hasn't been written into yet).
+fn canonicalize_nearest_existing_ancestor(path: &Path) -> std::io::Result<PathBuf> {
+    let mut current = path.to_path_buf();
+    loop {
+        if current.exists() {
+            return current.canonicalize();
+        }
+        if !current.pop() {
+            return Err(std::io::Error::new(
+                std::io::ErrorKind::NotFound,
+                "no existing ancestor found",
+            ));
+        }
+    }
+}
 
 /// Validates a path component to prevent directory traversal attacks.
-/// 
+///
 /// This function rejects paths that contain:
 /// - ".." (parent directory traversal)
 /// - "/" (forward slash path separator)
 /// - "\\" (backslash path separator)
-/// 
-/// After validation, it verifies that the resolved path stays within the base directory.
-/// 
+///
+/// That lexical check alone misses a symlink escape - a path that looks
+/// fine as a string but resolves outside `base_dir` once symlinks are
+/// followed - so it's followed by a second, canonicalization-based layer:
+/// if the path already exists, its canonical form must still start with
+/// `base_dir`'s canonical form; if it doesn't exist yet, the nearest
+/// existing ancestor is canonicalized and checked the same way, so an
+/// intermediate symlinked folder can't smuggle a not-yet-created file
+/// outside the sandbox either.
+///
 /// # Arguments
 /// * `base_dir` - The base directory that the path must stay within
 /// * `relative_path` - The relative path component to validate
-/// 
+///
 /// # Returns
 /// * `Ok(PathBuf)` - The resolved absolute path if validation passes
 /// * `Err(String)` - An error message if validation fails
-/// 
+///
 /// # Requirements
 /// Validates: Requirements 13.1, 13.2, 13.3, 13.4
 pub fn validate_path(base_dir: &Path, relative_path: &str) -> Result<PathBuf, String> {
@@ -25,1331 +72,6006 @@ pub fn validate_path(base_dir: &Path, relative_path: &str) -> Result<PathBuf, St
     if relative_path.contains("..") {
         return Err("Path contains invalid traversal pattern '..'".to_string());
     }
-    
+
     // Check for path separators (both forward and back slashes)
     if relative_path.contains('/') {
         return Err("Path contains invalid separator '/'".to_string());
     }
-    
+
     if relative_path.contains('\\') {
         return Err("Path contains invalid separator '\\'".to_string());
     }
-    
-    // Construct the full path
-    let full_path = base_dir.join(relative_path);
-    
-    // Canonicalize both paths to resolve any symlinks and get absolute paths
-    // Note: For the full_path, we need to handle the case where it doesn't exist yet
+
+    // Construct the full path, resolving any `.`/`..` segments in memory
+    let full_path = lexically_normalize(&base_dir.join(relative_path));
+
+    // Canonicalize the base directory to resolve any symlinks in its own
+    // ancestry and get an absolute path to compare against
     let canonical_base = base_dir.canonicalize()
         .map_err(|e| format!("Failed to canonicalize base directory: {}", e))?;
-    
-    // For the full path, if it doesn't exist, we verify the parent exists and is within base
-    let resolved_path = if full_path.exists() {
-        full_path.canonicalize()
-            .map_err(|e| format!("Failed to canonicalize path: {}", e))?
-    } else {
-        // If the file doesn't exist, verify the parent directory is valid
-        // and return the constructed path
-        full_path.clone()
-    };
 
-    // Verify the resolved path is within the base directory
-    // For existing paths, use the canonical path
-    // For non-existing paths, verify the path starts with the base
-    if resolved_path.exists() {
+    if full_path.exists() {
+        // The path exists - canonicalize it (resolving any symlink the path
+        // itself is, or passes through) and make sure it's still inside base
+        let resolved_path = full_path.canonicalize()
+            .map_err(|e| format!("Failed to canonicalize path: {}", e))?;
         if !resolved_path.starts_with(&canonical_base) {
             return Err("Path resolves outside of base directory".to_string());
         }
+        Ok(resolved_path)
     } else {
-        // For non-existing paths, ensure the constructed path would be within base
-        // by checking that the path starts with the base directory
+        // The path doesn't exist yet - first the cheap lexical check...
         if !full_path.starts_with(base_dir) {
             return Err("Path resolves outside of base directory".to_string());
         }
+        // ...then canonicalize the nearest existing ancestor, so a symlinked
+        // parent folder can't route a new file outside the sandbox either.
+        let canonical_ancestor = canonicalize_nearest_existing_ancestor(&full_path)
+            .map_err(|e| format!("Failed to canonicalize path: {}", e))?;
+        if !canonical_ancestor.starts_with(&canonical_base) {
+            return Err("Path resolves outside of base directory via a symlinked parent".to_string());
+        }
+        Ok(full_path)
     }
-    
-    Ok(resolved_path)
-}
-
-/// FileSystem manages the application's data directory structure.
-/// 
-/// The structure is:
-/// - `{data_dir}/notes/` - Markdown note files organized in folders
-/// - `{data_dir}/assets/` - Screenshot and image files
-/// - `{data_dir}/config.json` - User configuration
-/// - `{data_dir}/note-order.json` - Custom note ordering
-/// 
-/// # Requirements
-/// Validates: Requirements 9.1, 9.2, 9.3, 9.4, 9.5
-#[derive(Debug, Clone)]
-pub struct FileSystem {
-    /// Base data directory for the application
-    pub base_dir: PathBuf,
-    /// Directory for storing notes (notes/)
-    pub notes_dir: PathBuf,
-    /// Directory for storing assets like screenshots (assets/)
-    pub assets_dir: PathBuf,
-    /// Path to the configuration file (config.json)
-    pub config_file: PathBuf,
-    /// Path to the note ordering file (note-order.json)
-    pub order_file: PathBuf,
 }
 
-impl FileSystem {
-    /// Creates a new FileSystem instance using the platform-appropriate data directory.
-    /// 
-    /// On Linux: ~/.local/share/mded/
-    /// On macOS: ~/Library/Application Support/mded/
-    /// On Windows: C:\Users\{user}\AppData\Roaming\mded\
-    /// 
-    /// # Returns
-    /// * `Ok(FileSystem)` - A new FileSystem instance
-    /// * `Err(String)` - If the data directory cannot be determined
-    pub fn new() -> Result<Self, String> {
-        let base_dir = dirs::data_dir()
-            .ok_or_else(|| "Could not determine data directory".to_string())?
-            .join("mded");
-        
-        Self::new_with_base(&base_dir)
+/// Validates a (possibly nested) folder path relative to a base directory.
+///
+/// Unlike [`validate_path`], this accepts `/`-separated segments so nested
+/// folders (e.g. `"Projects/2024/Research"`) can be addressed as one path.
+/// Each segment is validated individually - empty, `.`, `..`, and names
+/// rejected by `is_protected` are all invalid - before the resolved path is
+/// checked to still be within `base_dir`, exactly like [`validate_path`].
+///
+/// # Arguments
+/// * `base_dir` - The base directory that the path must stay within
+/// * `relative_path` - The possibly-nested relative folder path to validate
+/// * `is_protected` - Rejects the path if any segment is a protected name
+///
+/// # Returns
+/// * `Ok(PathBuf)` - The resolved absolute path if validation passes
+/// * `Err(String)` - An error message if validation fails
+pub fn validate_folder_path(
+    base_dir: &Path,
+    relative_path: &str,
+    is_protected: impl Fn(&str) -> bool,
+) -> Result<PathBuf, String> {
+    if relative_path.contains('\\') {
+        return Err("Path contains invalid separator '\\'".to_string());
     }
 
-    /// Creates a new FileSystem instance with a custom base directory.
-    /// Useful for testing.
-    /// 
-    /// # Arguments
-    /// * `base_dir` - The base directory for all application data
-    /// 
-    /// # Returns
-    /// * `Ok(FileSystem)` - A new FileSystem instance
-    /// * `Err(String)` - If the paths cannot be constructed
-    pub fn new_with_base(base_dir: &Path) -> Result<Self, String> {
-        let base_dir = base_dir.to_path_buf();
-        let notes_dir = base_dir.join("notes");
-        let assets_dir = base_dir.join("assets");
-        let config_file = base_dir.join("config.json");
-        let order_file = base_dir.join("note-order.json");
-
-        Ok(Self {
-            base_dir,
-            notes_dir,
-            assets_dir,
-            config_file,
-            order_file,
-        })
+    let mut resolved = base_dir.to_path_buf();
+    for segment in relative_path.split('/') {
+        if segment.is_empty() {
+            return Err("Path contains an empty segment".to_string());
+        }
+        if segment == "." || segment == ".." {
+            return Err("Path contains invalid traversal pattern '..'".to_string());
+        }
+        if is_protected(segment) {
+            return Err(format!("'{}' is a protected folder name", segment));
+        }
+        resolved = resolved.join(segment);
     }
 
-    /// Ensures all required directories exist, creating them if necessary.
-    /// 
-    /// Creates:
-    /// - Base data directory
-    /// - Notes directory
-    /// - Assets directory
-    /// 
-    /// # Returns
-    /// * `Ok(())` - If all directories exist or were created successfully
-    /// * `Err(String)` - If directory creation fails
-    pub fn ensure_directories(&self) -> Result<(), String> {
-        // Create base directory
-        fs::create_dir_all(&self.base_dir)
-            .map_err(|e| format!("Failed to create base directory: {}", e))?;
-        
-        // Create notes directory
-        fs::create_dir_all(&self.notes_dir)
-            .map_err(|e| format!("Failed to create notes directory: {}", e))?;
-        
-        // Create assets directory
-        fs::create_dir_all(&self.assets_dir)
-            .map_err(|e| format!("Failed to create assets directory: {}", e))?;
-        
-        Ok(())
-    }
+    let canonical_base = base_dir
+        .canonicalize()
+        .map_err(|e| format!("Failed to canonicalize base directory: {}", e))?;
 
-    /// Validates a path relative to the notes directory.
-    /// 
-    /// # Arguments
-    /// * `relative_path` - The relative path to validate
-    /// 
-    /// # Returns
-    /// * `Ok(PathBuf)` - The resolved absolute path
-    /// * `Err(String)` - If validation fails
-    pub fn validate_notes_path(&self, relative_path: &str) -> Result<PathBuf, String> {
-        validate_path(&self.notes_dir, relative_path)
+    if resolved.exists() {
+        let canonical = resolved
+            .canonicalize()
+            .map_err(|e| format!("Failed to canonicalize path: {}", e))?;
+        if !canonical.starts_with(&canonical_base) {
+            return Err("Path resolves outside of base directory".to_string());
+        }
+    } else {
+        if !resolved.starts_with(base_dir) {
+            return Err("Path resolves outside of base directory".to_string());
+        }
+        let canonical_ancestor = canonicalize_nearest_existing_ancestor(&resolved)
+            .map_err(|e| format!("Failed to canonicalize path: {}", e))?;
+        if !canonical_ancestor.starts_with(&canonical_base) {
+            return Err("Path resolves outside of base directory via a symlinked parent".to_string());
+        }
     }
 
-    /// Validates a path relative to the assets directory.
-    /// 
-    /// # Arguments
-    /// * `relative_path` - The relative path to validate
-    /// 
-    /// # Returns
-    /// * `Ok(PathBuf)` - The resolved absolute path
-    /// * `Err(String)` - If validation fails
-    pub fn validate_assets_path(&self, relative_path: &str) -> Result<PathBuf, String> {
-        validate_path(&self.assets_dir, relative_path)
-    }
+    Ok(resolved)
+}
 
-    /// Returns the path to a folder within the notes directory.
-    /// 
-    /// # Arguments
-    /// * `folder_name` - The name of the folder (or None for root notes directory)
-    /// 
-    /// # Returns
-    /// The path to the folder
-    pub fn get_folder_path(&self, folder_name: Option<&str>) -> PathBuf {
-        match folder_name {
-            Some(name) if !name.is_empty() => self.notes_dir.join(name),
-            _ => self.notes_dir.clone(),
+/// Matches `name` against a `*`/`?` wildcard `pattern`.
+///
+/// `*` matches any run of characters (including none); `?` matches exactly
+/// one character; every other character must match literally.
+fn matches_wildcard(pattern: &str, name: &str) -> bool {
+    fn matches(pattern: &[char], name: &[char]) -> bool {
+        match pattern.first() {
+            None => name.is_empty(),
+            Some('*') => {
+                matches(&pattern[1..], name)
+                    || (!name.is_empty() && matches(pattern, &name[1..]))
+            }
+            Some('?') => !name.is_empty() && matches(&pattern[1..], &name[1..]),
+            Some(c) => name.first() == Some(c) && matches(&pattern[1..], &name[1..]),
         }
     }
 
-    /// Lists all folders in the notes directory.
-    /// 
-    /// Returns all directories in the notes directory, with "All Notes" virtual folder
-    /// as the first entry.
-    /// 
-    /// # Returns
-    /// * `Ok(Vec<FolderInfo>)` - List of folders with "All Notes" first
-    /// * `Err(String)` - If reading the directory fails
-    /// 
-    /// # Requirements
-    /// Validates: Requirements 10.1
-    pub fn list_folders(&self) -> Result<Vec<crate::models::FolderInfo>, String> {
-        use crate::models::FolderInfo;
-        
-        // "All Notes" virtual folder uses empty string as path identifier
-        let mut folders = vec![
-            FolderInfo {
-                name: "All Notes".to_string(),
-                path: String::new(),
+    let pattern: Vec<char> = pattern.chars().collect();
+    let name: Vec<char> = name.chars().collect();
+    matches(&pattern, &name)
+}
+
+/// One parsed line of a `.mdedignore` file: the glob pattern itself, and
+/// whether it was prefixed with `!` to negate an earlier match.
+struct IgnorePattern {
+    pattern: String,
+    negated: bool,
+}
+
+/// Gitignore-style exclude rules parsed from a `.mdedignore` file, split
+/// into directory patterns (lines ending in `/`) and file patterns so a
+/// directory walk can prune an ignored folder's whole subtree up front
+/// instead of matching every file under it individually.
+#[derive(Default)]
+struct IgnoreRules {
+    dir_patterns: Vec<IgnorePattern>,
+    file_patterns: Vec<IgnorePattern>,
+}
+
+impl IgnoreRules {
+    /// Parses `.mdedignore` content: blank lines and `#`-comments are
+    /// skipped, a leading `!` negates the pattern, and a trailing `/`
+    /// marks it as matching directory names only.
+    fn parse(content: &str) -> Self {
+        let mut rules = IgnoreRules::default();
+
+        for line in content.lines() {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
             }
-        ];
 
-        // Read directories from notes_dir
-        let entries = fs::read_dir(&self.notes_dir)
-            .map_err(|e| format!("Failed to read notes directory: {}", e))?;
+            let (negated, line) = match line.strip_prefix('!') {
+                Some(rest) => (true, rest),
+                None => (false, line),
+            };
 
-        for entry in entries {
-            let entry = entry.map_err(|e| format!("Failed to read directory entry: {}", e))?;
-            let path = entry.path();
-            
-            if path.is_dir() {
-                if let Some(name) = path.file_name() {
-                    let folder_name = name.to_string_lossy().to_string();
-                    folders.push(FolderInfo {
-                        name: folder_name.clone(),
-                        path: folder_name, // Use folder name as path identifier
-                    });
-                }
+            if let Some(dir_pattern) = line.strip_suffix('/') {
+                rules.dir_patterns.push(IgnorePattern { pattern: dir_pattern.to_string(), negated });
+            } else {
+                rules.file_patterns.push(IgnorePattern { pattern: line.to_string(), negated });
             }
         }
 
-        Ok(folders)
+        rules
     }
 
-    /// Creates a new folder in the notes directory.
-    /// 
-    /// # Arguments
-    /// * `name` - The name of the folder to create
-    /// 
-    /// # Returns
-    /// * `Ok(())` - If the folder was created successfully
-    /// * `Err(String)` - If validation fails or creation fails
-    /// 
-    /// # Requirements
-    /// Validates: Requirements 10.2
-    /// Checks if a folder name is protected/reserved.
-    pub fn is_protected_name(&self, name: &str) -> bool {
-        matches!(name, "All Notes" | "Trash")
+    /// Reads and parses `.mdedignore` from `notes_dir`, or returns an empty
+    /// rule set (nothing ignored) if the file doesn't exist.
+    fn load(notes_dir: &Path) -> Self {
+        match fs::read_to_string(notes_dir.join(".mdedignore")) {
+            Ok(content) => Self::parse(&content),
+            Err(_) => Self::default(),
+        }
     }
 
-    /// Creates a new folder in the notes directory.
-    /// 
-    /// # Arguments
-    /// * `name` - The name of the folder to create
-    /// 
-    /// # Returns
-    /// * `Ok(())` - If the folder was created successfully
-    /// * `Err(String)` - If validation fails or creation fails
-    /// 
-    /// # Requirements
-    /// Validates: Requirements 10.2
-    pub fn create_folder(&self, name: &str) -> Result<(), String> {
-        // Validate the folder name
-        // Validate the folder name
-        if name.trim().is_empty() {
-             return Err("Folder name cannot be empty or whitespace only".to_string());
-        }
-        
-        if self.is_protected_name(name) {
-            return Err(format!("'{}' is a protected folder name", name));
+    /// Applies patterns in file order, like gitignore: the last matching
+    /// pattern wins, so a later `!pattern` can un-ignore an earlier match.
+    fn matches(patterns: &[IgnorePattern], name: &str) -> bool {
+        let mut ignored = false;
+        for IgnorePattern { pattern, negated } in patterns {
+            if matches_wildcard(pattern, name) {
+                ignored = !negated;
+            }
         }
+        ignored
+    }
 
-        let folder_path = self.validate_notes_path(name)?;
-        
-        // Check if folder already exists
-        if folder_path.exists() {
-            return Err(format!("Folder '{}' already exists", name));
-        }
-        
-        // Create the folder
-        fs::create_dir(&folder_path)
-            .map_err(|e| format!("Failed to create folder '{}': {}", name, e))?;
-        
-        Ok(())
+    fn is_dir_ignored(&self, name: &str) -> bool {
+        Self::matches(&self.dir_patterns, name)
     }
 
-    /// Deletes a folder and all its contents from the notes directory.
-    /// 
-    /// # Arguments
-    /// * `name` - The name of the folder to delete
-    /// 
-    /// # Returns
-    /// * `Ok(())` - If the folder was deleted successfully
-    /// * `Err(String)` - If validation fails or deletion fails
-    /// 
-    /// # Requirements
-    /// Validates: Requirements 10.3
-    pub fn delete_folder(&self, name: &str) -> Result<(), String> {
-        // Validate the folder name
-        if name.trim().is_empty() {
-             return Err("Folder name cannot be empty or whitespace only".to_string());
-        }
+    fn is_file_ignored(&self, name: &str) -> bool {
+        Self::matches(&self.file_patterns, name)
+    }
+}
 
-        if self.is_protected_name(name) {
-             return Err(format!("Cannot delete protected folder '{}'", name));
+/// A cached [`FileSystem::list_notes`] entry for one note, keyed by its
+/// path relative to `notes_dir` in [`NoteIndex::entries`].
+///
+/// `mtime_nanos`/`size` are the file's stat data at the time `title` was
+/// last derived from its content - as long as both still match, the title
+/// can be served from cache without re-reading the file.
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug, Default)]
+struct NoteIndexEntry {
+    mtime_nanos: u64,
+    size: u64,
+    title: String,
+    folder: String,
+    /// Tags parsed from the note's frontmatter. `#[serde(default)]` so an
+    /// `index.json` written before tags existed still deserializes.
+    #[serde(default)]
+    tags: Vec<String>,
+}
+
+/// The persisted dirstate-style cache behind [`FileSystem::list_notes`],
+/// stored in `index.json` alongside `config.json`.
+///
+/// On each scan, a note whose stat data (`mtime_nanos`, `size`) matches its
+/// cached entry reuses `title` instead of re-reading and re-parsing the
+/// file; a changed or newly-seen note is re-read and its entry refreshed,
+/// and an entry whose file has disappeared is dropped.
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug, Default)]
+struct NoteIndex {
+    entries: std::collections::HashMap<String, NoteIndexEntry>,
+}
+
+/// A small set of common English words dropped while tokenizing for
+/// [`SearchIndex`] - they carry little relevance signal but would otherwise
+/// dominate every document's term frequency.
+const SEARCH_STOPWORDS: &[&str] = &[
+    "a", "an", "and", "are", "as", "at", "be", "but", "by", "for", "if", "in", "into", "is", "it",
+    "no", "not", "of", "on", "or", "such", "that", "the", "their", "then", "there", "these", "they",
+    "this", "to", "was", "will", "with",
+];
+
+/// `k1` (term-frequency saturation) and `b` (document-length normalization)
+/// constants for the BM25 scoring in [`FileSystem::search_notes_ranked`] -
+/// the standard defaults used by most BM25 implementations.
+const BM25_K1: f64 = 1.2;
+const BM25_B: f64 = 0.75;
+
+/// Lowercases `text`, splits it on runs of non-alphanumeric characters, and
+/// drops [`SEARCH_STOPWORDS`], producing the token stream indexed by
+/// [`SearchIndex`] and matched against a search query.
+fn tokenize_for_search(text: &str) -> Vec<String> {
+    text.to_lowercase()
+        .split(|c: char| !c.is_alphanumeric())
+        .filter(|token| !token.is_empty() && !SEARCH_STOPWORDS.contains(token))
+        .map(|token| token.to_string())
+        .collect()
+}
+
+/// The in-memory BM25 inverted index behind [`FileSystem::search_notes_ranked`].
+///
+/// Built lazily on first search and kept up to date incrementally by
+/// [`FileSystem::save_note`], [`FileSystem::create_note`],
+/// [`FileSystem::delete_note`], and [`FileSystem::rename_note`], rather than
+/// rebuilt from scratch on every query or every edit.
+#[derive(Debug, Clone, Default)]
+struct SearchIndex {
+    /// term -> note id -> how many times the term appears in that note.
+    postings: std::collections::HashMap<String, std::collections::HashMap<String, u32>>,
+    /// note id -> token count, for BM25's document-length normalization.
+    doc_lengths: std::collections::HashMap<String, usize>,
+    /// note id -> folder name (empty string for the root), so a
+    /// folder-scoped search doesn't need a second pass over disk.
+    note_folders: std::collections::HashMap<String, String>,
+    /// Sum of every indexed note's token count, for the running average
+    /// document length BM25 needs.
+    total_tokens: usize,
+}
+
+impl SearchIndex {
+    fn avg_doc_len(&self) -> f64 {
+        if self.doc_lengths.is_empty() {
+            0.0
+        } else {
+            self.total_tokens as f64 / self.doc_lengths.len() as f64
         }
+    }
 
-        let folder_path = self.validate_notes_path(name)?;
-        
-        // Check if folder exists
-        if !folder_path.exists() {
-            return Err(format!("Folder '{}' does not exist", name));
-        }
-        
-        // Check if it's actually a directory
-        if !folder_path.is_dir() {
-            return Err(format!("'{}' is not a folder", name));
-        }
-        
-        // Recursively remove the folder and all contents
-        fs::remove_dir_all(&folder_path)
-            .map_err(|e| format!("Failed to delete folder '{}': {}", name, e))?;
-        
-        Ok(())
-    }
+    /// Indexes (or re-indexes) one note's content, replacing any entry it
+    /// already had.
+    fn upsert_note(&mut self, note_id: &str, folder: &str, content: &str) {
+        self.remove_note(note_id);
 
-    /// Renames a folder in the notes directory.
-    /// 
-    /// # Arguments
-    /// * `old_name` - The current name of the folder
-    /// * `new_name` - The new name for the folder
-    /// 
-    /// # Returns
-    /// * `Ok(())` - If the folder was renamed successfully
-    /// * `Err(String)` - If validation fails or renaming fails
-    /// 
-    /// # Requirements
-    /// Validates: Requirements 10.4
-    pub fn rename_folder(&self, old_name: &str, new_name: &str) -> Result<(), String> {
-        // Validate both folder names
-        if old_name.trim().is_empty() || new_name.trim().is_empty() {
-             return Err("Folder name cannot be empty or whitespace only".to_string());
+        let tokens = tokenize_for_search(content);
+        let mut term_frequencies: std::collections::HashMap<String, u32> = std::collections::HashMap::new();
+        for token in &tokens {
+            *term_frequencies.entry(token.clone()).or_insert(0) += 1;
         }
 
-        if self.is_protected_name(old_name) {
-             return Err(format!("Cannot rename protected folder '{}'", old_name));
-        }
-        
-        if self.is_protected_name(new_name) {
-             return Err(format!("Cannot rename to protected name '{}'", new_name));
-        }
+        self.total_tokens += tokens.len();
+        self.doc_lengths.insert(note_id.to_string(), tokens.len());
+        self.note_folders.insert(note_id.to_string(), folder.to_string());
 
-        let old_path = self.validate_notes_path(old_name)?;
-        let new_path = self.validate_notes_path(new_name)?;
-        
-        // Check if old folder exists
-        if !old_path.exists() {
-            return Err(format!("Folder '{}' does not exist", old_name));
+        for (term, frequency) in term_frequencies {
+            self.postings.entry(term).or_default().insert(note_id.to_string(), frequency);
         }
-        
-        // Check if it's actually a directory
-        if !old_path.is_dir() {
-            return Err(format!("'{}' is not a folder", old_name));
+    }
+
+    /// Drops a note from the index entirely, e.g. on delete or before a rename.
+    fn remove_note(&mut self, note_id: &str) {
+        if let Some(len) = self.doc_lengths.remove(note_id) {
+            self.total_tokens = self.total_tokens.saturating_sub(len);
         }
-        
-        // Check if new folder already exists
-        if new_path.exists() {
-            return Err(format!("Folder '{}' already exists", new_name));
+        self.note_folders.remove(note_id);
+        for postings in self.postings.values_mut() {
+            postings.remove(note_id);
         }
-        
-        // Rename the folder
-        fs::rename(&old_path, &new_path)
-            .map_err(|e| format!("Failed to rename folder '{}' to '{}': {}", old_name, new_name, e))?;
-        
-        Ok(())
+        self.postings.retain(|_, postings| !postings.is_empty());
     }
+}
 
-    // ==================== Note Operations ====================
+fn note_mtime_nanos(metadata: &fs::Metadata) -> u64 {
+    metadata
+        .modified()
+        .ok()
+        .and_then(|t| t.duration_since(std::time::UNIX_EPOCH).ok())
+        .map(|d| d.as_nanos() as u64)
+        .unwrap_or(0)
+}
 
-    /// Lists all notes, optionally filtered by folder.
-    /// 
-    /// Returns all .md files with metadata including id, title, modified date,
-    /// created date, folder, and pinned status.
-    /// 
-    /// # Arguments
-    /// * `folder` - Optional folder name to filter notes. If None or "All Notes", returns all notes.
-    /// 
-    /// # Returns
-    /// * `Ok(Vec<NoteInfo>)` - List of notes with metadata
-    /// * `Err(String)` - If reading fails
-    /// 
-    /// # Requirements
-    /// Validates: Requirements 11.1, 11.2
-    pub fn list_notes(&self, folder: Option<&str>) -> Result<Vec<crate::models::NoteInfo>, String> {
-        use crate::models::NoteInfo;
-        use chrono::{DateTime, Utc};
-        
-        let mut notes = Vec::new();
-        
-        // Determine which directories to scan
-        let dirs_to_scan: Vec<(PathBuf, String)> = if folder.is_none() || folder == Some("All Notes") || folder == Some("") {
-            // Scan all directories including root
-            let mut dirs = vec![(self.notes_dir.clone(), String::new())];
-            
-            // Add subdirectories
-            if let Ok(entries) = fs::read_dir(&self.notes_dir) {
-                for entry in entries.flatten() {
-                    let path = entry.path();
-                    if path.is_dir() {
-                        if let Some(name) = path.file_name() {
-                            let name_str = name.to_string_lossy().to_string();
-                            dirs.push((path, name_str));
-                        }
-                    }
-                }
+/// How long [`FileSystem::start_notes_watcher`] waits for a burst of
+/// filesystem events to go quiet before emitting, so a bulk operation (or a
+/// save's temp-file-then-rename) produces one event per note instead of
+/// several redundant ones. Mirrors `CONFIG_WATCH_DEBOUNCE` in `lib.rs`.
+const NOTES_WATCH_DEBOUNCE: std::time::Duration = std::time::Duration::from_millis(300);
+
+/// Derives a note's id and folder from its path, relative to `notes_dir`.
+/// Returns `None` for anything that isn't a `.md` file under `notes_dir`
+/// (e.g. `index.json`, a directory itself), which the watcher ignores.
+fn note_id_and_folder_from_path(notes_dir: &Path, path: &Path) -> Option<(String, String)> {
+    if path.extension().map_or(true, |ext| ext != "md") {
+        return None;
+    }
+    let relative = path.strip_prefix(notes_dir).ok()?;
+    let note_id = relative.file_stem()?.to_string_lossy().to_string();
+    let folder = relative
+        .parent()
+        .map(|p| p.to_string_lossy().to_string())
+        .unwrap_or_default();
+    Some((note_id, folder))
+}
+
+/// Translates one coalesced [`notify::Event`] into the matching
+/// `note-created`/`note-modified`/`note-deleted`/`note-renamed` event on
+/// `app`, via [`tauri::Emitter`]. Events outside the note file set (see
+/// [`note_id_and_folder_from_path`]) are silently dropped.
+fn emit_note_change_event(app: &tauri::AppHandle, notes_dir: &Path, event: &notify::Event) {
+    use notify::event::{ModifyKind, RenameMode};
+    use notify::EventKind;
+    use tauri::Emitter;
+
+    match &event.kind {
+        EventKind::Create(_) => {
+            if let Some((note_id, folder)) = event.paths.first().and_then(|p| note_id_and_folder_from_path(notes_dir, p)) {
+                let _ = app.emit("note-created", serde_json::json!({ "noteId": note_id, "folder": folder }));
             }
-            dirs
-        } else {
-            // Scan only the specified folder
-            let folder_name = folder.unwrap();
-            let folder_path = self.get_folder_path(Some(folder_name));
-            if !folder_path.exists() {
-                return Err(format!("Folder '{}' does not exist", folder_name));
+        }
+        EventKind::Modify(ModifyKind::Name(RenameMode::Both)) if event.paths.len() == 2 => {
+            let (old, new) = (&event.paths[0], &event.paths[1]);
+            if let (Some((old_note_id, folder)), Some((new_note_id, _))) = (
+                note_id_and_folder_from_path(notes_dir, old),
+                note_id_and_folder_from_path(notes_dir, new),
+            ) {
+                let _ = app.emit(
+                    "note-renamed",
+                    serde_json::json!({ "oldNoteId": old_note_id, "newNoteId": new_note_id, "folder": folder }),
+                );
             }
-            vec![(folder_path, folder_name.to_string())]
-        };
-        
-        // Load pinned notes from config (placeholder - will be integrated with config module later)
-        let pinned_notes: Vec<String> = self.load_pinned_notes().unwrap_or_default();
-        
-        // Scan each directory for .md files
-        for (dir_path, folder_name) in dirs_to_scan {
-            if let Ok(entries) = fs::read_dir(&dir_path) {
-                for entry in entries.flatten() {
-                    let path = entry.path();
-                    
-                    // Only process .md files
-                    if path.is_file() && path.extension().map_or(false, |ext| ext == "md") {
-                        if let Some(file_name) = path.file_name() {
-                            let file_name_str = file_name.to_string_lossy();
-                            let note_id = file_name_str.trim_end_matches(".md").to_string();
-                            
-                            // Get file metadata
-                            let metadata = fs::metadata(&path)
-                                .map_err(|e| format!("Failed to read metadata for '{}': {}", file_name_str, e))?;
-                            
-                            // Get modified time
-                            let modified: DateTime<Utc> = metadata.modified()
-                                .map(|t| t.into())
-                                .unwrap_or_else(|_| Utc::now());
-                            
-                            // Get created time (use modified as fallback)
-                            let created: DateTime<Utc> = metadata.created()
-                                .map(|t| t.into())
-                                .unwrap_or(modified);
-                            
-                            // Extract title from first line of file
-                            let title = self.extract_note_title(&path).unwrap_or_else(|| note_id.clone());
-                            
-                            // Check if note is pinned
-                            let pinned = pinned_notes.contains(&note_id);
-                            
-                            notes.push(NoteInfo {
-                                id: note_id,
-                                title,
-                                modified,
-                                created,
-                                folder: folder_name.clone(),
-                                pinned,
-                            });
-                        }
-                    }
-                }
+        }
+        EventKind::Modify(_) => {
+            if let Some((note_id, folder)) = event.paths.first().and_then(|p| note_id_and_folder_from_path(notes_dir, p)) {
+                let _ = app.emit("note-modified", serde_json::json!({ "noteId": note_id, "folder": folder }));
             }
         }
-        
-        // Sort notes: pinned first, then by modified date (newest first)
-        notes.sort_by(|a, b| {
-            match (a.pinned, b.pinned) {
-                (true, false) => std::cmp::Ordering::Less,
-                (false, true) => std::cmp::Ordering::Greater,
-                _ => b.modified.cmp(&a.modified),
+        EventKind::Remove(_) => {
+            if let Some((note_id, folder)) = event.paths.first().and_then(|p| note_id_and_folder_from_path(notes_dir, p)) {
+                let _ = app.emit("note-deleted", serde_json::json!({ "noteId": note_id, "folder": folder }));
             }
-        });
-        
-        Ok(notes)
+        }
+        _ => {}
     }
+}
 
-    /// Extracts the title from a note file.
-    /// 
-    /// The title is the first line of the file, with leading '#' characters removed.
-    fn extract_note_title(&self, path: &Path) -> Option<String> {
-        let content = fs::read_to_string(path).ok()?;
-        let first_line = content.lines().next()?;
-        let title = first_line.trim_start_matches('#').trim();
-        if title.is_empty() {
-            None
-        } else {
-            Some(title.to_string())
-        }
+/// How many `%include` hops [`resolve_layered_settings`] will follow before
+/// giving up - bounds a misconfigured (but non-cyclic) include chain the
+/// same way [`resolve_layered_settings`]'s `visiting` stack bounds a cyclic
+/// one.
+const MAX_LAYER_INCLUDE_DEPTH: usize = 10;
+
+/// Splits a comma-separated layered-settings value into trimmed,
+/// non-empty parts - the list format [`FileSystem::resolve_layers`] uses
+/// for both `pinned_notes` and `order.<folder>` values.
+fn parse_layered_list(value: &str) -> Vec<String> {
+    value
+        .split(',')
+        .map(|part| part.trim())
+        .filter(|part| !part.is_empty())
+        .map(|part| part.to_string())
+        .collect()
+}
+
+/// Parses one layered settings file (see [`FileSystem::resolve_layers`])
+/// into a flat `key -> value` map, resolving `%include` lines recursively
+/// and applying `%unset` lines as they're encountered.
+///
+/// `visiting` tracks the canonicalized path of every file currently being
+/// resolved in the current include chain, so an `%include` cycle (A includes
+/// B includes A) is rejected instead of recursing forever; `depth` is
+/// rejected past [`MAX_LAYER_INCLUDE_DEPTH`] for the same reason on a long
+/// but non-cyclic chain.
+fn resolve_layered_settings(
+    path: &Path,
+    visiting: &mut Vec<PathBuf>,
+    depth: usize,
+) -> Result<std::collections::HashMap<String, String>, String> {
+    if depth > MAX_LAYER_INCLUDE_DEPTH {
+        return Err(format!(
+            "Exceeded maximum %include depth of {} while resolving '{}'",
+            MAX_LAYER_INCLUDE_DEPTH,
+            path.display()
+        ));
     }
 
-    /// Loads pinned notes from config file.
-    fn load_pinned_notes(&self) -> Result<Vec<String>, String> {
-        use crate::models::Config;
-        
-        if !self.config_file.exists() {
-            return Ok(Vec::new());
+    let canonical = path
+        .canonicalize()
+        .map_err(|e| format!("Failed to resolve layered settings file '{}': {}", path.display(), e))?;
+    if visiting.contains(&canonical) {
+        return Err(format!("Circular %include detected at '{}'", path.display()));
+    }
+    visiting.push(canonical);
+
+    let content = fs::read_to_string(path)
+        .map_err(|e| format!("Failed to read layered settings file '{}': {}", path.display(), e))?;
+
+    let mut settings = std::collections::HashMap::new();
+    for line in content.lines() {
+        let line = line.trim();
+        if line.is_empty() || line.starts_with('#') {
+            continue;
+        }
+
+        if let Some(include_path) = line.strip_prefix("%include ") {
+            let include_path = include_path.trim();
+            let resolved_path = path
+                .parent()
+                .map(|parent| parent.join(include_path))
+                .unwrap_or_else(|| PathBuf::from(include_path));
+            let included = resolve_layered_settings(&resolved_path, visiting, depth + 1)?;
+            settings.extend(included);
+            continue;
+        }
+
+        if let Some(key) = line.strip_prefix("%unset ") {
+            settings.remove(key.trim());
+            continue;
+        }
+
+        if let Some((key, value)) = line.split_once('=') {
+            settings.insert(key.trim().to_string(), value.trim().to_string());
         }
-        
-        let content = fs::read_to_string(&self.config_file)
-            .map_err(|e| format!("Failed to read config file: {}", e))?;
-        
-        let config: Config = serde_json::from_str(&content)
-            .unwrap_or_default();
-        
-        Ok(config.pinned_notes)
     }
 
-    /// Saves pinned notes to config file.
-    pub fn save_pinned_notes(&self, pinned_notes: Vec<String>) -> Result<(), String> {
-        use crate::models::Config;
-        
-        let mut config = if self.config_file.exists() {
-            let content = fs::read_to_string(&self.config_file)
-                .map_err(|e| format!("Failed to read config file: {}", e))?;
-            serde_json::from_str(&content).unwrap_or_default()
-        } else {
-            Config::default()
-        };
-        
-        config.pinned_notes = pinned_notes;
-        
-        let content = serde_json::to_string_pretty(&config)
-            .map_err(|e| format!("Failed to serialize config: {}", e))?;
-        
-        fs::write(&self.config_file, content)
-            .map_err(|e| format!("Failed to write config file: {}", e))
+    visiting.pop();
+    Ok(settings)
+}
+
+/// Leading bytes that identify a well-known binary format, checked by
+/// [`looks_like_markdown_text`] so an extensionless file that happens to be
+/// a PNG/JPEG/PDF/ZIP/ELF is rejected instead of being misread as text.
+/// Not exhaustive - just enough to catch the formats a user is likely to
+/// have sitting next to their notes.
+const BINARY_SIGNATURES: &[&[u8]] = &[
+    b"\x89PNG\r\n\x1a\n",
+    b"\xFF\xD8\xFF",
+    b"%PDF-",
+    b"PK\x03\x04",
+    b"\x7FELF",
+];
+
+/// How many leading bytes [`FileSystem::read_external_file_sniffed`] reads
+/// to classify an extensionless file as text-Markdown vs. binary.
+const SNIFF_SAMPLE_BYTES: usize = 8192;
+
+/// Classifies a sample of a file's leading bytes as plausibly Markdown
+/// text: not matching any [`BINARY_SIGNATURES`], containing no NUL bytes,
+/// and valid UTF-8.
+fn looks_like_markdown_text(sample: &[u8]) -> bool {
+    if BINARY_SIGNATURES.iter().any(|sig| sample.starts_with(sig)) {
+        return false;
+    }
+    if sample.contains(&0u8) {
+        return false;
     }
+    std::str::from_utf8(sample).is_ok()
+}
 
-    /// Toggles the pin status of a note.
-    /// 
-    /// If the note is currently pinned, it will be unpinned.
-    /// If the note is currently unpinned, it will be pinned.
-    /// 
-    /// # Arguments
-    /// * `note_id` - The ID of the note to toggle
-    /// 
-    /// # Returns
-    /// * `Ok(bool)` - The new pinned status (true if now pinned, false if now unpinned)
-    /// * `Err(String)` - If the operation fails
-    /// 
-    /// # Requirements
-    /// Validates: Requirements 12.1
-    pub fn toggle_pin_note(&self, note_id: &str) -> Result<bool, String> {
-        let mut pinned_notes = self.load_pinned_notes()?;
-        
-        let new_pinned_status = if let Some(pos) = pinned_notes.iter().position(|id| id == note_id) {
-            // Note is currently pinned, remove it
-            pinned_notes.remove(pos);
-            false
-        } else {
-            // Note is not pinned, add it
-            pinned_notes.push(note_id.to_string());
-            true
-        };
-        
-        // Save the updated pinned notes list
-        self.save_pinned_notes(pinned_notes)?;
-        
-        Ok(new_pinned_status)
+/// Computes the Levenshtein (edit) distance between two strings.
+fn levenshtein_distance(a: &str, b: &str) -> usize {
+    let a: Vec<char> = a.chars().collect();
+    let b: Vec<char> = b.chars().collect();
+
+    let mut prev: Vec<usize> = (0..=b.len()).collect();
+    let mut curr = vec![0usize; b.len() + 1];
+
+    for i in 1..=a.len() {
+        curr[0] = i;
+        for j in 1..=b.len() {
+            let cost = if a[i - 1] == b[j - 1] { 0 } else { 1 };
+            curr[j] = (prev[j] + 1).min(curr[j - 1] + 1).min(prev[j - 1] + cost);
+        }
+        std::mem::swap(&mut prev, &mut curr);
     }
 
-    /// Gets the custom note ordering from note-order.json.
-    /// 
-    /// Returns a map of folder names to ordered note ID arrays.
-    /// Returns an empty map if the file doesn't exist.
-    /// 
-    /// # Returns
-    /// * `Ok(HashMap<String, Vec<String>>)` - The note ordering map
-    /// * `Err(String)` - If reading fails
-    /// 
-    /// # Requirements
-    /// Validates: Requirements 12.2
-    pub fn get_note_order(&self) -> Result<std::collections::HashMap<String, Vec<String>>, String> {
-        use std::collections::HashMap;
-        
-        if !self.order_file.exists() {
-            return Ok(HashMap::new());
+    prev[b.len()]
+}
+
+/// Finds the candidate closest to `target` by edit distance, for "did you
+/// mean...?" suggestions on not-found errors.
+///
+/// A candidate only qualifies if its distance is within `max(2, target.len() / 3)`
+/// - close enough to plausibly be a typo, not just any name.
+fn did_you_mean<'a>(target: &str, candidates: impl IntoIterator<Item = &'a str>) -> Option<&'a str> {
+    let threshold = (target.chars().count() / 3).max(2);
+
+    candidates
+        .into_iter()
+        .map(|candidate| (candidate, levenshtein_distance(target, candidate)))
+        .filter(|(_, distance)| *distance <= threshold)
+        .min_by_key(|(_, distance)| *distance)
+        .map(|(candidate, _)| candidate)
+}
+
+/// Writes `content` to `path` atomically: writes to a sibling
+/// `<file-name>.tmp-<pid>` file in the same directory, flushes and `fsync`s
+/// it so the bytes are durable, then `fs::rename`s it over `path`. A reader
+/// always sees either the previous complete file or the fully-written new
+/// one - never a half-written `config.json`/`note-order.json` from a process
+/// killed or a disk that fills up mid-write, as a plain `fs::write` would
+/// risk. The pid suffix keeps concurrent writers (e.g. two `mded` instances)
+/// from colliding on the same temp file.
+fn write_atomic(path: &Path, content: &str) -> Result<(), String> {
+    let tmp_path = path.with_file_name(format!(
+        "{}.tmp-{}",
+        path.file_name().map(|n| n.to_string_lossy()).unwrap_or_default(),
+        std::process::id()
+    ));
+
+    let mut tmp_file = fs::File::create(&tmp_path)
+        .map_err(|e| format!("Failed to create temp file for '{}': {}", path.display(), e))?;
+    tmp_file
+        .write_all(content.as_bytes())
+        .map_err(|e| format!("Failed to write temp file for '{}': {}", path.display(), e))?;
+    tmp_file
+        .sync_all()
+        .map_err(|e| format!("Failed to sync temp file for '{}': {}", path.display(), e))?;
+    drop(tmp_file);
+
+    fs::rename(&tmp_path, path).map_err(|e| format!("Failed to replace '{}': {}", path.display(), e))
+}
+
+/// Splits `content` into its optional leading `---`-delimited YAML
+/// frontmatter and the body that follows, returning the `tags: [...]` list
+/// the frontmatter declares (an empty list if there's no frontmatter block,
+/// or no `tags` key in it).
+///
+/// Only the `tags` key is understood for now - frontmatter's `pinned`/
+/// `created` keys that some note apps also place here aren't parsed, since
+/// migrating pin state to live in-file would mean replacing the existing
+/// pinned-notes store ([`FileSystem::load_pinned_notes`]) wholesale, which
+/// is out of scope for adding tags.
+fn parse_frontmatter(content: &str) -> (Vec<String>, &str) {
+    let Some(rest) = content.strip_prefix("---\n") else {
+        return (Vec::new(), content);
+    };
+    let Some(end) = rest.find("\n---") else {
+        return (Vec::new(), content);
+    };
+
+    let block = &rest[..end];
+    let after_marker = &rest[end + "\n---".len()..];
+    let body = after_marker.strip_prefix('\n').unwrap_or(after_marker);
+
+    let mut tags = Vec::new();
+    for line in block.lines() {
+        if let Some(value) = line.trim_start().strip_prefix("tags:") {
+            tags = parse_frontmatter_tag_list(value.trim());
         }
-        
-        let content = fs::read_to_string(&self.order_file)
-            .map_err(|e| format!("Failed to read note order file: {}", e))?;
-        
-        let order: HashMap<String, Vec<String>> = serde_json::from_str(&content)
-            .map_err(|e| format!("Failed to parse note order file: {}", e))?;
-        
-        Ok(order)
     }
 
-    /// Saves the custom note ordering to note-order.json.
-    /// 
-    /// # Arguments
-    /// * `order` - A map of folder names to ordered note ID arrays
-    /// 
-    /// # Returns
-    /// * `Ok(())` - If save was successful
-    /// * `Err(String)` - If saving fails
-    /// 
-    /// # Requirements
-    /// Validates: Requirements 12.3
-    pub fn save_note_order(&self, order: std::collections::HashMap<String, Vec<String>>) -> Result<(), String> {
-        let content = serde_json::to_string_pretty(&order)
-            .map_err(|e| format!("Failed to serialize note order: {}", e))?;
-        
-        fs::write(&self.order_file, content)
-            .map_err(|e| format!("Failed to write note order file: {}", e))
+    (tags, body)
+}
+
+/// Parses a frontmatter `tags` value, accepting the flow-sequence form
+/// (`[a, b, "c"]`) this crate writes, as well as a bare comma-separated list
+/// without brackets, so a hand-edited frontmatter block still works.
+fn parse_frontmatter_tag_list(value: &str) -> Vec<String> {
+    let inner = value.trim().trim_start_matches('[').trim_end_matches(']');
+    inner
+        .split(',')
+        .map(|tag| tag.trim().trim_matches('"').trim_matches('\'').to_string())
+        .filter(|tag| !tag.is_empty())
+        .collect()
+}
+
+/// Serializes `tags` back into the flow-sequence frontmatter form this
+/// crate parses, e.g. `[work, idea]`.
+fn format_frontmatter_tag_list(tags: &[String]) -> String {
+    format!("[{}]", tags.join(", "))
+}
+
+/// Derives a display title from note content.
+///
+/// The title is the first line of the content after any leading YAML
+/// frontmatter block, with leading '#' characters removed. Returns `None`
+/// if that line doesn't exist or is empty once trimmed.
+pub fn title_from_content(content: &str) -> Option<String> {
+    let (_, body) = parse_frontmatter(content);
+    let first_line = body.lines().next()?;
+    let title = first_line.trim_start_matches('#').trim();
+    if title.is_empty() {
+        None
+    } else {
+        Some(title.to_string())
     }
+}
 
-    /// Reads the content of a note.
-    /// 
-    /// # Arguments
-    /// * `note_id` - The ID of the note (filename without extension)
-    /// * `folder` - Optional folder containing the note
-    /// 
-    /// # Returns
-    /// * `Ok(String)` - The note content
-    /// * `Err(String)` - If reading fails
-    /// 
-    /// # Requirements
-    /// Validates: Requirements 11.3
-    pub fn read_note(&self, note_id: &str, folder: Option<&str>) -> Result<String, String> {
-        let file_name = format!("{}.md", note_id);
-        
-        // Validate the note_id
-        validate_path(&self.notes_dir, &file_name)?;
-        
-        // Get the folder path
-        let folder_path = self.get_folder_path(folder);
-        
-        // Validate folder if specified
-        if let Some(f) = folder {
-            if !f.is_empty() {
-                validate_path(&self.notes_dir, f)?;
+/// Counts occurrences of `query_lower` in `content_lower` and builds a short
+/// snippet around the first match.
+///
+/// `content`/`content_lower` must be the same text, differing only in case;
+/// the snippet is taken from `content` (original case) so matches aren't
+/// case-mangled in the returned text. When `whole_word` is `true`, a match is
+/// only counted if neither neighboring character is alphanumeric or `_`.
+fn count_matches_and_snippet(content: &str, content_lower: &str, query_lower: &str, whole_word: bool) -> (usize, String) {
+    const SNIPPET_RADIUS: usize = 40;
+
+    let is_word_char = |c: char| c.is_alphanumeric() || c == '_';
+    let mut count = 0;
+    let mut first_match: Option<usize> = None;
+    let mut search_from = 0;
+
+    while let Some(found) = content_lower[search_from..].find(query_lower) {
+        let start = search_from + found;
+        let end = start + query_lower.len();
+
+        let matches = !whole_word || {
+            let before_ok = content_lower[..start].chars().next_back().map_or(true, |c| !is_word_char(c));
+            let after_ok = content_lower[end..].chars().next().map_or(true, |c| !is_word_char(c));
+            before_ok && after_ok
+        };
+
+        if matches {
+            count += 1;
+            if first_match.is_none() {
+                first_match = Some(start);
             }
         }
-        
-        let note_path = folder_path.join(&file_name);
-        
-        if !note_path.exists() {
-            return Err(format!("Note '{}' does not exist", note_id));
+
+        // Advance to the next char boundary after `start` (never mid-match),
+        // so the next `find` call always starts at a valid UTF-8 boundary.
+        search_from = match content_lower[start..].chars().nth(1) {
+            Some(_) => start + content_lower[start..].chars().next().unwrap().len_utf8(),
+            None => content_lower.len(),
+        };
+        if search_from >= content_lower.len() {
+            break;
         }
-        
-        fs::read_to_string(&note_path)
-            .map_err(|e| format!("Failed to read note '{}': {}", note_id, e))
     }
 
-    /// Saves content to a note.
-    /// 
-    /// # Arguments
-    /// * `note_id` - The ID of the note (filename without extension)
-    /// * `content` - The content to save
-    /// * `folder` - Optional folder containing the note
-    /// 
-    /// # Returns
-    /// * `Ok(())` - If save was successful
-    /// * `Err(String)` - If saving fails
-    /// 
-    /// # Requirements
-    /// Validates: Requirements 11.4
-    pub fn save_note(&self, note_id: &str, content: &str, folder: Option<&str>) -> Result<(), String> {
-        let file_name = format!("{}.md", note_id);
-        
-        // Validate the note_id
-        validate_path(&self.notes_dir, &file_name)?;
-        
-        // Get the folder path
-        let folder_path = self.get_folder_path(folder);
-        
-        // Validate folder if specified
-        if let Some(f) = folder {
-            if !f.is_empty() {
-                validate_path(&self.notes_dir, f)?;
+    let snippet = match first_match {
+        Some(start) => {
+            let line_start = content[..start].rfind('\n').map(|i| i + 1).unwrap_or(0);
+            let line_end = content[start..].find('\n').map(|i| start + i).unwrap_or(content.len());
+            let line = &content[line_start..line_end];
+            let match_len_in_line = query_lower.len().min(line.len() - (start - line_start));
+
+            let chars: Vec<(usize, char)> = line.char_indices().collect();
+            let offset_in_line = start - line_start;
+            let match_char_idx = chars.iter().position(|(byte_idx, _)| *byte_idx >= offset_in_line).unwrap_or(chars.len());
+            let match_end_char_idx = chars.iter().position(|(byte_idx, _)| *byte_idx >= offset_in_line + match_len_in_line).unwrap_or(chars.len());
+
+            let snippet_char_start = match_char_idx.saturating_sub(SNIPPET_RADIUS);
+            let snippet_char_end = (match_end_char_idx + SNIPPET_RADIUS).min(chars.len());
+
+            let byte_start = chars.get(snippet_char_start).map_or(0, |(b, _)| *b);
+            let byte_end = chars.get(snippet_char_end).map_or(line.len(), |(b, _)| *b);
+            let mut snippet = line[byte_start..byte_end].trim().to_string();
+            if snippet_char_start > 0 {
+                snippet = format!("...{}", snippet);
+            }
+            if snippet_char_end < chars.len() {
+                snippet = format!("{}...", snippet);
             }
+            snippet
         }
-        
-        // Ensure folder exists
-        if !folder_path.exists() {
-            fs::create_dir_all(&folder_path)
-                .map_err(|e| format!("Failed to create folder: {}", e))?;
+        None => String::new(),
+    };
+
+    (count, snippet)
+}
+
+/// Number of bytes read per chunk while streaming a file through a
+/// [`RollingHasher`], so [`read_file_with_checksum`] never has to hold the
+/// whole file in memory twice (once as the returned content, once again as
+/// a re-assembled buffer for hashing).
+const CHECKSUM_CHUNK_BYTES: usize = 64 * 1024;
+
+/// Which digest algorithm a [`Checksum`] was computed with, kept as an enum
+/// so a future algorithm can be added without changing any call site.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ChecksumAlgorithm {
+    /// CRC-32 (IEEE polynomial) - cheap "did this change?" check, not
+    /// collision-resistant.
+    Crc32,
+    /// SHA-256 - cryptographic, for when a digest needs to be trusted
+    /// rather than just convenient.
+    Sha256,
+}
+
+impl ChecksumAlgorithm {
+    /// The name used when surfacing this algorithm outside the process
+    /// (e.g. alongside a digest returned to the frontend).
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ChecksumAlgorithm::Crc32 => "crc32",
+            ChecksumAlgorithm::Sha256 => "sha256",
         }
-        
-        let note_path = folder_path.join(&file_name);
-        
-        fs::write(&note_path, content)
-            .map_err(|e| format!("Failed to save note '{}': {}", note_id, e))
     }
+}
 
-    /// Creates a new note with a UUID-based filename.
-    /// 
-    /// # Arguments
-    /// * `folder` - Optional folder to create the note in
-    /// 
-    /// # Returns
-    /// * `Ok((String, String))` - Tuple of (note_id, full_path)
-    /// * `Err(String)` - If creation fails
-    /// 
-    /// # Requirements
-    /// Validates: Requirements 11.5
-    pub fn create_note(&self, folder: Option<&str>) -> Result<(String, String), String> {
-        use uuid::Uuid;
-        
-        // Generate UUID-based filename
-        let uuid = Uuid::new_v4();
-        let note_id = format!("note-{}", uuid);
-        let file_name = format!("{}.md", note_id);
-        
-        // Get the folder path
-        let folder_path = self.get_folder_path(folder);
-        
-        // Validate folder if specified
-        if let Some(f) = folder {
-            if !f.is_empty() {
-                validate_path(&self.notes_dir, f)?;
+/// A digest over a file's bytes, tagged with the algorithm it was computed
+/// with so [`FileSystem::verify_checksum`] knows how to recompute it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Checksum {
+    pub algorithm: ChecksumAlgorithm,
+    pub digest: String,
+}
+
+/// Incremental CRC-32 (IEEE) state, updated one chunk at a time rather than
+/// over a single fully-buffered slice.
+struct Crc32State(u32);
+
+impl Crc32State {
+    fn new() -> Self {
+        Crc32State(0xFFFFFFFF)
+    }
+
+    fn update(&mut self, bytes: &[u8]) {
+        for &byte in bytes {
+            let mut value = self.0 ^ byte as u32;
+            for _ in 0..8 {
+                value = if value & 1 != 0 { (value >> 1) ^ 0xEDB88320 } else { value >> 1 };
             }
+            self.0 = value;
         }
-        
-        // Ensure folder exists
-        if !folder_path.exists() {
-            fs::create_dir_all(&folder_path)
-                .map_err(|e| format!("Failed to create folder: {}", e))?;
+    }
+
+    fn finalize(self) -> u32 {
+        self.0 ^ 0xFFFFFFFF
+    }
+}
+
+/// Wraps whichever digest state matches a [`ChecksumAlgorithm`], so
+/// [`read_file_with_checksum`] can feed both through the same streaming
+/// read loop without branching at every chunk.
+enum RollingHasher {
+    Crc32(Crc32State),
+    Sha256(sha2::Sha256),
+}
+
+impl RollingHasher {
+    fn new(algorithm: ChecksumAlgorithm) -> Self {
+        match algorithm {
+            ChecksumAlgorithm::Crc32 => RollingHasher::Crc32(Crc32State::new()),
+            ChecksumAlgorithm::Sha256 => RollingHasher::Sha256(sha2::Sha256::new()),
         }
-        
-        let note_path = folder_path.join(&file_name);
-        
-        // Create file with default content
-        let default_content = "# New Note\n\n";
-        fs::write(&note_path, default_content)
-            .map_err(|e| format!("Failed to create note: {}", e))?;
-        
-        Ok((note_id, note_path.to_string_lossy().to_string()))
     }
 
-    /// Deletes a note.
-    /// 
-    /// # Arguments
-    /// * `note_id` - The ID of the note to delete
-    /// * `folder` - Optional folder containing the note
-    /// 
-    /// # Returns
-    /// * `Ok(())` - If deletion was successful
-    /// * `Err(String)` - If deletion fails
-    /// 
-    /// # Requirements
-    /// Validates: Requirements 11.6
-    pub fn delete_note(&self, note_id: &str, folder: Option<&str>) -> Result<(), String> {
-        let file_name = format!("{}.md", note_id);
-        
-        // Validate the note_id
-        validate_path(&self.notes_dir, &file_name)?;
-        
-        // Get the folder path
-        let folder_path = self.get_folder_path(folder);
-        
-        // Validate folder if specified
-        if let Some(f) = folder {
-            if !f.is_empty() {
-                validate_path(&self.notes_dir, f)?;
+    fn update(&mut self, bytes: &[u8]) {
+        match self {
+            RollingHasher::Crc32(state) => state.update(bytes),
+            RollingHasher::Sha256(hasher) => {
+                use sha2::Digest;
+                hasher.update(bytes);
             }
         }
-        
-        let note_path = folder_path.join(&file_name);
-        
-        if !note_path.exists() {
-            return Err(format!("Note '{}' does not exist", note_id));
-        }
-        
-        fs::remove_file(&note_path)
-            .map_err(|e| format!("Failed to delete note '{}': {}", note_id, e))
     }
 
-    /// Renames a note.
-    /// 
-    /// # Arguments
-    /// * `note_id` - The current ID of the note
-    /// * `new_name` - The new name for the note (without .md extension)
-    /// * `folder` - Optional folder containing the note
-    /// 
-    /// # Returns
-    /// * `Ok(String)` - The new note ID
-    /// * `Err(String)` - If renaming fails
-    /// 
-    /// # Requirements
-    /// Validates: Requirements 11.7
-    pub fn rename_note(&self, note_id: &str, new_name: &str, folder: Option<&str>) -> Result<String, String> {
-        let old_file_name = format!("{}.md", note_id);
-        let new_file_name = format!("{}.md", new_name);
-        
-        // Validate both names
-        validate_path(&self.notes_dir, &old_file_name)?;
-        validate_path(&self.notes_dir, &new_file_name)?;
-        
-        // Get the folder path
-        let folder_path = self.get_folder_path(folder);
-        
-        // Validate folder if specified
-        if let Some(f) = folder {
-            if !f.is_empty() {
-                validate_path(&self.notes_dir, f)?;
+    fn finalize(self, algorithm: ChecksumAlgorithm) -> Checksum {
+        let digest = match self {
+            RollingHasher::Crc32(state) => format!("{:08x}", state.finalize()),
+            RollingHasher::Sha256(hasher) => {
+                use sha2::Digest;
+                format!("{:x}", hasher.finalize())
             }
+        };
+        Checksum { algorithm, digest }
+    }
+}
+
+/// Reads `path` in [`CHECKSUM_CHUNK_BYTES`]-sized chunks, feeding each chunk
+/// to a [`RollingHasher`] as it arrives and appending it to the returned
+/// buffer, so the file's bytes are only ever held in one place rather than
+/// read once for content and hashed again from a second full copy.
+fn read_file_with_checksum(path: &Path, algorithm: ChecksumAlgorithm) -> Result<(Vec<u8>, Checksum), String> {
+    let mut file = fs::File::open(path).map_err(|e| format!("Failed to open file: {}", e))?;
+    let mut buffer = Vec::new();
+    let mut chunk = [0u8; CHECKSUM_CHUNK_BYTES];
+    let mut hasher = RollingHasher::new(algorithm);
+
+    loop {
+        let read = file.read(&mut chunk).map_err(|e| format!("Failed to read file: {}", e))?;
+        if read == 0 {
+            break;
         }
-        
-        let old_path = folder_path.join(&old_file_name);
-        let new_path = folder_path.join(&new_file_name);
-        
-        if !old_path.exists() {
-            return Err(format!("Note '{}' does not exist", note_id));
+        hasher.update(&chunk[..read]);
+        buffer.extend_from_slice(&chunk[..read]);
+    }
+
+    Ok((buffer, hasher.finalize(algorithm)))
+}
+
+/// Magic bytes identifying an mded vault archive, written by
+/// [`FileSystem::export_vault`] and checked by [`FileSystem::import_vault`].
+///
+/// Note: entries are stored uncompressed in this small custom container
+/// rather than a standard zip/tar.gz, to avoid hand-rolling deflate/zip
+/// encoding that can't be verified against a real decompressor in this
+/// environment - still one portable file, just not openable by
+/// general-purpose archive tools.
+const VAULT_ARCHIVE_MAGIC: &[u8; 8] = b"MDEDVLT1";
+
+/// Bumped whenever [`crate::models::VaultManifest`]'s fields or the archive
+/// entry layout change incompatibly, so [`FileSystem::import_vault`] can
+/// refuse an archive it doesn't know how to read instead of misreading it.
+const VAULT_ARCHIVE_SCHEMA_VERSION: u32 = 1;
+
+/// Appends one `[path_len][path][content_len][content][crc32]` entry to
+/// `writer` - see [`VAULT_ARCHIVE_MAGIC`].
+fn write_archive_entry(writer: &mut impl Write, relative_path: &str, content: &[u8]) -> Result<(), String> {
+    let path_bytes = relative_path.as_bytes();
+    writer
+        .write_all(&(path_bytes.len() as u32).to_le_bytes())
+        .and_then(|_| writer.write_all(path_bytes))
+        .and_then(|_| writer.write_all(&(content.len() as u64).to_le_bytes()))
+        .and_then(|_| writer.write_all(content))
+        .map_err(|e| format!("Failed to write archive entry '{}': {}", relative_path, e))?;
+
+    let mut crc = Crc32State::new();
+    crc.update(content);
+    writer
+        .write_all(&crc.finalize().to_le_bytes())
+        .map_err(|e| format!("Failed to write archive entry '{}': {}", relative_path, e))
+}
+
+/// Reads `len` bytes from `bytes` starting at `*offset`, advancing it, or a
+/// clear "truncated" error if fewer remain.
+fn read_archive_bytes<'a>(bytes: &'a [u8], offset: &mut usize, len: usize) -> Result<&'a [u8], String> {
+    let end = offset.checked_add(len).filter(|&end| end <= bytes.len());
+    match end {
+        Some(end) => {
+            let slice = &bytes[*offset..end];
+            *offset = end;
+            Ok(slice)
         }
-        
-        if new_path.exists() {
-            return Err(format!("Note '{}' already exists", new_name));
+        None => Err("Archive is truncated or corrupt".to_string()),
+    }
+}
+
+/// Parses every `[path_len][path][content_len][content][crc32]` entry out
+/// of `bytes` (after [`VAULT_ARCHIVE_MAGIC`]'s header), verifying each
+/// entry's CRC-32 as it goes.
+fn read_archive_entries(bytes: &[u8]) -> Result<Vec<(String, Vec<u8>)>, String> {
+    if bytes.len() < VAULT_ARCHIVE_MAGIC.len() || &bytes[..VAULT_ARCHIVE_MAGIC.len()] != VAULT_ARCHIVE_MAGIC {
+        return Err("Not an mded vault archive".to_string());
+    }
+
+    let mut offset = VAULT_ARCHIVE_MAGIC.len();
+    let mut entries = Vec::new();
+
+    while offset < bytes.len() {
+        let path_len = u32::from_le_bytes(read_archive_bytes(bytes, &mut offset, 4)?.try_into().unwrap()) as usize;
+        let path = std::str::from_utf8(read_archive_bytes(bytes, &mut offset, path_len)?)
+            .map_err(|_| "Archive entry path is not valid UTF-8".to_string())?
+            .to_string();
+        let content_len = u64::from_le_bytes(read_archive_bytes(bytes, &mut offset, 8)?.try_into().unwrap()) as usize;
+        let content = read_archive_bytes(bytes, &mut offset, content_len)?.to_vec();
+        let expected_crc = u32::from_le_bytes(read_archive_bytes(bytes, &mut offset, 4)?.try_into().unwrap());
+
+        let mut crc = Crc32State::new();
+        crc.update(&content);
+        if crc.finalize() != expected_crc {
+            return Err(format!("Archive entry '{}' failed its integrity check", path));
         }
-        
-        fs::rename(&old_path, &new_path)
-            .map_err(|e| format!("Failed to rename note '{}' to '{}': {}", note_id, new_name, e))?;
-        
-        Ok(new_name.to_string())
+
+        entries.push((path, content));
     }
 
-    /// Moves a note from one folder to another.
-    /// 
-    /// # Arguments
-    /// * `note_id` - The ID of the note to move
-    /// * `from_folder` - The source folder
-    /// * `to_folder` - The target folder
-    /// 
-    /// # Returns
-    /// * `Ok(())` - If move was successful
-    /// * `Err(String)` - If moving fails
-    /// 
-    /// # Requirements
-    /// Validates: Requirements 11.8
-    pub fn move_note(&self, note_id: &str, from_folder: &str, to_folder: &str) -> Result<(), String> {
-        let file_name = format!("{}.md", note_id);
-        
-        // Validate the note_id
-        validate_path(&self.notes_dir, &file_name)?;
-        
-        // Validate folders
-        let from_path = if from_folder.is_empty() || from_folder == "All Notes" {
-            self.notes_dir.clone()
-        } else {
-            validate_path(&self.notes_dir, from_folder)?;
-            self.notes_dir.join(from_folder)
-        };
-        
-        let to_path = if to_folder.is_empty() || to_folder == "All Notes" {
-            self.notes_dir.clone()
+    Ok(entries)
+}
+
+/// Recursively collects every file under `dir`, sorted by name at each
+/// level for a deterministic archive entry order. Mirrors
+/// [`FileSystem::import_directory_into`]'s walk.
+fn collect_files_recursive(dir: &Path, out: &mut Vec<PathBuf>) -> Result<(), String> {
+    let mut entries: Vec<_> = fs::read_dir(dir)
+        .map_err(|e| format!("Failed to read '{}': {}", dir.display(), e))?
+        .filter_map(|e| e.ok())
+        .collect();
+    entries.sort_by_key(|e| e.file_name());
+
+    for entry in entries {
+        let path = entry.path();
+        if path.is_dir() {
+            collect_files_recursive(&path, out)?;
         } else {
-            validate_path(&self.notes_dir, to_folder)?;
-            self.notes_dir.join(to_folder)
+            out.push(path);
+        }
+    }
+    Ok(())
+}
+
+/// How [`FileSystem::read_external_file_with_symlink_policy`] should treat a
+/// path that resolves through a symbolic link.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum SymlinkPolicy {
+    /// Refuse to read a path that is itself a symlink.
+    Reject,
+    /// Follow the symlink chain and read the resolved target, returning its
+    /// canonicalized real path as the third tuple element.
+    Follow,
+    /// Follow the symlink chain only if the canonicalized target stays
+    /// inside this directory; otherwise refuse.
+    FollowWithinBase(PathBuf),
+}
+
+/// FileSystem manages the application's data directory structure.
+///
+/// The structure is:
+/// - `{data_dir}/notes/` - Markdown note files organized in folders
+/// - `{data_dir}/assets/` - Screenshot and image files
+/// - `{data_dir}/config.json` - User configuration
+/// - `{data_dir}/note-order.json` - Custom note ordering
+///
+/// `FileSystem` routes its simplest primitive operations (directory
+/// creation, and `read_note`/`delete_note`'s non-versioned, non-atomic reads
+/// and removes) through [`FileSystem::backend`], a [`crate::storage::FileSystemLike`],
+/// so those can run against [`crate::storage::MemFileSystem`] in a test
+/// instead of real disk. Everything with a subtler contract than the trait
+/// models - `atomic_write`'s fsync-then-rename, `.trash`/`.versions`
+/// directory walks, the `with_lock` flock - still talks to `std::fs`
+/// directly; wiring those through `FileSystemLike` too is follow-up work.
+///
+/// # Requirements
+/// Validates: Requirements 9.1, 9.2, 9.3, 9.4, 9.5
+#[derive(Clone)]
+pub struct FileSystem {
+    /// Base data directory for the application
+    pub base_dir: PathBuf,
+    /// Directory for storing notes (notes/)
+    pub notes_dir: PathBuf,
+    /// Directory for storing assets like screenshots (assets/)
+    pub assets_dir: PathBuf,
+    /// Path to the configuration file (config.json)
+    pub config_file: PathBuf,
+    /// Path to the note ordering file (note-order.json)
+    pub order_file: PathBuf,
+    /// Path to the [`NoteIndex`] cache (index.json), letting [`FileSystem::list_notes`]
+    /// skip re-reading notes whose mtime/size haven't changed since the last scan.
+    index_file: PathBuf,
+    /// Path to the optional layered settings file (mded.layers) for pinned
+    /// notes and note order - see [`FileSystem::resolve_layers`]. Absent by
+    /// default; [`FileSystem::load_pinned_notes`]/[`FileSystem::get_note_order`]
+    /// fall back to `config.json`/`note-order.json` when it doesn't exist.
+    layers_file: PathBuf,
+    /// Directory holding soft-deleted folders (.trash/), hidden from
+    /// [`FileSystem::list_folders`] the same way `notes_dir` itself is.
+    pub trash_dir: PathBuf,
+    /// Path to the trash's metadata index (.trash/index.json), mapping
+    /// trash id to the [`crate::models::TrashEntry`] needed to restore it.
+    pub trash_index_file: PathBuf,
+    /// Directory holding per-note version history (.versions/<note_id>/...),
+    /// written by [`FileSystem::snapshot_note_version`] before a destructive
+    /// save overwrites a note.
+    pub versions_dir: PathBuf,
+    /// Path to the data directory's schema/feature manifest (requirements),
+    /// checked by [`FileSystem::check_requirements`] so an older build
+    /// opening a newer data directory fails clearly instead of misreading it.
+    pub requirements_file: PathBuf,
+    /// The BM25 full-text search index behind [`FileSystem::search_notes_ranked`],
+    /// built lazily on first use. Shared (not duplicated) across clones of
+    /// `FileSystem`, since they all refer to the same on-disk vault.
+    search_index: std::sync::Arc<std::sync::Mutex<Option<SearchIndex>>>,
+    /// The background watcher started by [`FileSystem::start_notes_watcher`],
+    /// if running. Dropping the [`notify::RecommendedWatcher`] unsubscribes
+    /// and ends its event thread, which is how [`FileSystem::stop_notes_watcher`]
+    /// stops it. Shared across clones so any of them can start/stop the one
+    /// watcher for the vault.
+    notes_watcher: std::sync::Arc<std::sync::Mutex<Option<notify::RecommendedWatcher>>>,
+    /// The backend the primitive operations noted above go through - the
+    /// real disk ([`crate::storage::RealFileSystem`]) outside of tests, or
+    /// an in-memory one ([`crate::storage::MemFileSystem`]) passed to
+    /// [`FileSystem::new_with_backend`].
+    backend: std::sync::Arc<dyn crate::storage::FileSystemLike>,
+}
+
+impl std::fmt::Debug for FileSystem {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("FileSystem")
+            .field("base_dir", &self.base_dir)
+            .finish_non_exhaustive()
+    }
+}
+
+impl FileSystem {
+    /// [`FileSystem::list_notes`] only spreads candidate parsing across a
+    /// rayon thread pool above this many `.md` files - below it, thread
+    /// pool overhead outweighs the serial scan it would replace.
+    const PARALLEL_LIST_NOTES_THRESHOLD: usize = 200;
+
+    /// Creates a new FileSystem instance using the platform-appropriate data directory.
+    ///
+    /// The directory is resolved in this order, so users can relocate their
+    /// notes to a synced drive or run isolated profiles:
+    /// 1. `MDED_DATA_DIR`, if set - must be an absolute path
+    /// 2. `XDG_DATA_HOME/mded`, if `XDG_DATA_HOME` is set (Unix only)
+    /// 3. The platform default:
+    ///    - Linux: ~/.local/share/mded/
+    ///    - macOS: ~/Library/Application Support/mded/
+    ///    - Windows: C:\Users\{user}\AppData\Roaming\mded\
+    ///
+    /// # Returns
+    /// * `Ok(FileSystem)` - A new FileSystem instance
+    /// * `Err(String)` - If `MDED_DATA_DIR` isn't an absolute path, or the
+    ///   data directory can't otherwise be determined
+    pub fn new() -> Result<Self, String> {
+        let base_dir = if let Ok(override_dir) = std::env::var("MDED_DATA_DIR") {
+            let override_dir = PathBuf::from(override_dir);
+            if !override_dir.is_absolute() {
+                return Err(format!(
+                    "MDED_DATA_DIR must be an absolute path, got '{}'",
+                    override_dir.display()
+                ));
+            }
+            override_dir
+        } else if cfg!(unix) {
+            match std::env::var("XDG_DATA_HOME") {
+                Ok(xdg_data_home) if !xdg_data_home.is_empty() => PathBuf::from(xdg_data_home).join("mded"),
+                _ => dirs::data_dir()
+                    .ok_or_else(|| "Could not determine data directory".to_string())?
+                    .join("mded"),
+            }
+        } else {
+            dirs::data_dir()
+                .ok_or_else(|| "Could not determine data directory".to_string())?
+                .join("mded")
         };
-        
-        let source_file = from_path.join(&file_name);
-        let target_file = to_path.join(&file_name);
-        
-        if !source_file.exists() {
-            return Err(format!("Note '{}' does not exist in folder '{}'", note_id, from_folder));
+
+        Self::new_with_base(&base_dir)
+    }
+
+    /// Creates a new FileSystem instance with a custom base directory.
+    /// Useful for testing.
+    /// 
+    /// # Arguments
+    /// * `base_dir` - The base directory for all application data
+    /// 
+    /// # Returns
+    /// * `Ok(FileSystem)` - A new FileSystem instance
+    /// * `Err(String)` - If the paths cannot be constructed
+    pub fn new_with_base(base_dir: &Path) -> Result<Self, String> {
+        Self::new_with_backend(base_dir, std::sync::Arc::new(crate::storage::RealFileSystem::new(base_dir.to_path_buf())))
+    }
+
+    /// Creates a new FileSystem instance with a custom base directory and an
+    /// explicit [`crate::storage::FileSystemLike`] backend.
+    ///
+    /// [`FileSystem::new_with_base`] is this with a real-disk backend; tests
+    /// (or a downstream consumer) can pass a [`crate::storage::MemFileSystem`]
+    /// instead to exercise the operations that go through it without
+    /// touching disk.
+    ///
+    /// # Arguments
+    /// * `base_dir` - The base directory for all application data
+    /// * `backend` - Where the wired primitive operations actually read/write
+    ///
+    /// # Returns
+    /// * `Ok(FileSystem)` - A new FileSystem instance
+    /// * `Err(String)` - If the paths cannot be constructed
+    pub fn new_with_backend(base_dir: &Path, backend: std::sync::Arc<dyn crate::storage::FileSystemLike>) -> Result<Self, String> {
+        let base_dir = base_dir.to_path_buf();
+        let notes_dir = base_dir.join("notes");
+        let assets_dir = base_dir.join("assets");
+        let config_file = base_dir.join("config.json");
+        let order_file = base_dir.join("note-order.json");
+        let index_file = base_dir.join("index.json");
+        let layers_file = base_dir.join("mded.layers");
+        let trash_dir = base_dir.join(".trash");
+        let trash_index_file = trash_dir.join("index.json");
+        let requirements_file = base_dir.join("requirements");
+        let versions_dir = base_dir.join(".versions");
+
+        Ok(Self {
+            base_dir,
+            notes_dir,
+            assets_dir,
+            config_file,
+            order_file,
+            index_file,
+            layers_file,
+            trash_dir,
+            trash_index_file,
+            requirements_file,
+            versions_dir,
+            search_index: std::sync::Arc::new(std::sync::Mutex::new(None)),
+            notes_watcher: std::sync::Arc::new(std::sync::Mutex::new(None)),
+            backend,
+        })
+    }
+
+    /// Ensures all required directories exist, creating them if necessary.
+    /// 
+    /// Creates:
+    /// - Base data directory
+    /// - Notes directory
+    /// - Assets directory
+    /// 
+    /// # Returns
+    /// * `Ok(())` - If all directories exist or were created successfully
+    /// * `Err(String)` - If directory creation fails
+    pub fn ensure_directories(&self) -> Result<(), String> {
+        // Create base directory
+        self.backend.dir_create_all(&self.base_dir)
+            .map_err(|e| format!("Failed to create base directory: {}", e))?;
+
+        // Create notes directory
+        self.backend.dir_create_all(&self.notes_dir)
+            .map_err(|e| format!("Failed to create notes directory: {}", e))?;
+
+        // Create assets directory
+        self.backend.dir_create_all(&self.assets_dir)
+            .map_err(|e| format!("Failed to create assets directory: {}", e))?;
+
+        // Create trash directory
+        self.backend.dir_create_all(&self.trash_dir)
+            .map_err(|e| format!("Failed to create trash directory: {}", e))?;
+
+        // Write the requirements manifest for a brand-new data directory, so
+        // a future (possibly older) build can tell what it's looking at.
+        // An existing manifest is left untouched here - upgrading it is
+        // `FileSystem::migrate_if_needed`'s job, not this one's.
+        if !self.requirements_file.exists() {
+            self.write_requirements(&crate::models::DataDirRequirements::default())?;
         }
-        
-        // Ensure target folder exists
-        if !to_path.exists() {
-            fs::create_dir_all(&to_path)
-                .map_err(|e| format!("Failed to create target folder: {}", e))?;
+
+        Ok(())
+    }
+
+    /// Writes the data directory's requirements manifest.
+    fn write_requirements(&self, requirements: &crate::models::DataDirRequirements) -> Result<(), String> {
+        let content = serde_json::to_string_pretty(requirements)
+            .map_err(|e| format!("Failed to serialize requirements: {}", e))?;
+        self.write_file_atomic(&self.requirements_file, &content)
+    }
+
+    /// Reads the data directory's requirements manifest, treating a missing
+    /// file as a pre-existing directory from before this subsystem existed
+    /// (equivalent to [`crate::models::DATA_DIR_VERSION`] `0`, no features).
+    fn read_requirements(&self) -> Result<crate::models::DataDirRequirements, String> {
+        if !self.requirements_file.exists() {
+            return Ok(crate::models::DataDirRequirements { version: 0, features: Vec::new() });
         }
-        
-        if target_file.exists() {
-            return Err(format!("Note '{}' already exists in folder '{}'", note_id, to_folder));
+
+        let content = fs::read_to_string(&self.requirements_file)
+            .map_err(|e| format!("Failed to read requirements file: {}", e))?;
+        serde_json::from_str(&content).map_err(|e| format!("Failed to parse requirements file: {}", e))
+    }
+
+    /// Checks that this build understands the data directory's on-disk
+    /// format version and every feature it declares, returning a descriptive
+    /// error instead of risking silently misreading (and corrupting) it.
+    ///
+    /// # Returns
+    /// * `Ok(())` - If this build can safely read the data directory
+    /// * `Err(String)` - If the directory was written by a newer/incompatible build
+    pub fn check_requirements(&self) -> Result<(), String> {
+        let requirements = self.read_requirements()?;
+
+        if requirements.version > crate::models::DATA_DIR_VERSION {
+            return Err(format!(
+                "Data directory format v{} is newer than this build understands (v{}); upgrade mded to open it",
+                requirements.version,
+                crate::models::DATA_DIR_VERSION
+            ));
         }
-        
-        fs::rename(&source_file, &target_file)
-            .map_err(|e| format!("Failed to move note '{}': {}", note_id, e))
+
+        for feature in &requirements.features {
+            if !crate::models::DATA_DIR_FEATURES.contains(&feature.as_str()) {
+                return Err(format!(
+                    "Data directory requires feature '{}', which this build doesn't understand; upgrade mded to open it",
+                    feature
+                ));
+            }
+        }
+
+        Ok(())
     }
 
-    // ==================== Screenshot Operations ====================
+    /// Brings an older data directory's requirements manifest up to
+    /// [`crate::models::DATA_DIR_VERSION`], or creates one for a directory
+    /// that predates this subsystem entirely. Run this before
+    /// [`FileSystem::check_requirements`] at startup so a directory that's
+    /// merely *older* (not incompatible) doesn't need manual intervention.
+    ///
+    /// There have been no breaking data-directory format changes yet, so
+    /// this currently only adopts pre-existing directories at the current
+    /// version; future version bumps should add their upgrade step here.
+    ///
+    /// # Returns
+    /// * `Ok(())` - If the directory is now at (or confirmed to already be at) the current version
+    /// * `Err(String)` - If the directory is incompatible, or the manifest can't be read/written
+    pub fn migrate_if_needed(&self) -> Result<(), String> {
+        let requirements = self.read_requirements()?;
 
-    /// Saves a screenshot from base64 PNG data.
+        if requirements.version < crate::models::DATA_DIR_VERSION {
+            return self.write_requirements(&crate::models::DataDirRequirements::default());
+        }
+
+        self.check_requirements()
+    }
+
+    /// Validates a path relative to the notes directory.
     /// 
-    /// Decodes the base64 data and saves it to the assets directory with a unique
-    /// timestamp-based filename.
+    /// # Arguments
+    /// * `relative_path` - The relative path to validate
+    /// 
+    /// # Returns
+    /// * `Ok(PathBuf)` - The resolved absolute path
+    /// * `Err(String)` - If validation fails
+    pub fn validate_notes_path(&self, relative_path: &str) -> Result<PathBuf, String> {
+        validate_path(&self.notes_dir, relative_path)
+    }
+
+    /// Validates a (possibly nested) folder path relative to the notes
+    /// directory, e.g. `"Projects/2024/Research"`.
+    ///
+    /// # Arguments
+    /// * `relative_path` - The relative folder path to validate
+    ///
+    /// # Returns
+    /// * `Ok(PathBuf)` - The resolved absolute path
+    /// * `Err(String)` - If validation fails
+    pub fn validate_notes_folder_path(&self, relative_path: &str) -> Result<PathBuf, String> {
+        validate_folder_path(&self.notes_dir, relative_path, |segment| {
+            self.is_protected_name(segment)
+        })
+    }
+
+    /// Validates a path relative to the assets directory.
     /// 
     /// # Arguments
-    /// * `base64_data` - The base64-encoded PNG image data (may include data URL prefix)
+    /// * `relative_path` - The relative path to validate
     /// 
     /// # Returns
-    /// * `Ok((String, String))` - Tuple of (image_id, absolute_path)
-    /// * `Err(String)` - If decoding or saving fails
+    /// * `Ok(PathBuf)` - The resolved absolute path
+    /// * `Err(String)` - If validation fails
+    pub fn validate_assets_path(&self, relative_path: &str) -> Result<PathBuf, String> {
+        validate_path(&self.assets_dir, relative_path)
+    }
+
+    /// Returns the path to a folder within the notes directory.
     /// 
-    /// # Requirements
-    /// Validates: Requirements 14.1, 14.2
-    pub fn save_screenshot(&self, base64_data: &str) -> Result<(String, String), String> {
-        use base64::Engine;
-        use chrono::Utc;
-        
-        // Strip data URL prefix if present (e.g., "data:image/png;base64,")
-        let base64_content = if let Some(pos) = base64_data.find(",") {
-            &base64_data[pos + 1..]
-        } else {
-            base64_data
-        };
-        
-        // Decode base64 data
-        let image_data = base64::engine::general_purpose::STANDARD
-            .decode(base64_content)
-            .map_err(|e| format!("Failed to decode base64 image data: {}", e))?;
-        
-        // Validate that we have some data
-        if image_data.is_empty() {
-            return Err("Image data is empty".to_string());
+    /// # Arguments
+    /// * `folder_name` - The name of the folder (or None for root notes directory)
+    /// 
+    /// # Returns
+    /// The path to the folder
+    pub fn get_folder_path(&self, folder_name: Option<&str>) -> PathBuf {
+        match folder_name {
+            Some(name) if !name.is_empty() => self.notes_dir.join(name),
+            _ => self.notes_dir.clone(),
         }
-        
-        // Generate unique filename with timestamp
-        let timestamp = Utc::now().format("%Y%m%d%H%M%S%3f").to_string();
-        let image_id = format!("screenshot-{}", timestamp);
-        let file_name = format!("{}.png", image_id);
-        
-        // Ensure assets directory exists
-        if !self.assets_dir.exists() {
-            fs::create_dir_all(&self.assets_dir)
-                .map_err(|e| format!("Failed to create assets directory: {}", e))?;
+    }
+
+    /// Lists all folders in the notes directory.
+    /// 
+    /// Returns all directories in the notes directory, with "All Notes" virtual folder
+    /// as the first entry.
+    /// 
+    /// # Returns
+    /// * `Ok(Vec<FolderInfo>)` - List of folders with "All Notes" first
+    /// * `Err(String)` - If reading the directory fails
+    /// 
+    /// # Requirements
+    /// Validates: Requirements 10.1
+    pub fn list_folders(&self) -> Result<Vec<crate::models::FolderInfo>, String> {
+        use crate::models::FolderInfo;
+
+        // "All Notes" virtual folder uses empty string as path identifier
+        let mut folders = vec![
+            FolderInfo {
+                name: "All Notes".to_string(),
+                path: String::new(),
+                parent: None,
+                depth: 0,
+            }
+        ];
+
+        let ignore_rules = IgnoreRules::load(&self.notes_dir);
+        Self::collect_folders(&self.notes_dir, &[], &ignore_rules, &mut folders)?;
+
+        Ok(folders)
+    }
+
+    /// Recursively walks `dir`, appending a [`crate::models::FolderInfo`] for
+    /// every subdirectory found, depth-first. A directory matched by
+    /// `ignore_rules` is skipped entirely - neither it nor anything under it
+    /// is ever visited, so an ignored folder's whole subtree is pruned in
+    /// one step instead of filtering each entry afterward.
+    ///
+    /// # Arguments
+    /// * `dir` - The directory to scan (the notes root on the initial call)
+    /// * `path_segments` - The `/`-separated path segments from the notes
+    ///   root down to `dir`, used to build each entry's `path`/`parent`/`depth`
+    /// * `ignore_rules` - `.mdedignore` rules to prune matching folders with
+    /// * `folders` - Accumulates discovered [`crate::models::FolderInfo`] entries
+    fn collect_folders(
+        dir: &Path,
+        path_segments: &[String],
+        ignore_rules: &IgnoreRules,
+        folders: &mut Vec<crate::models::FolderInfo>,
+    ) -> Result<(), String> {
+        use crate::models::FolderInfo;
+
+        let entries = fs::read_dir(dir)
+            .map_err(|e| format!("Failed to read notes directory: {}", e))?;
+
+        for entry in entries {
+            let entry = entry.map_err(|e| format!("Failed to read directory entry: {}", e))?;
+            let path = entry.path();
+
+            if !path.is_dir() {
+                continue;
+            }
+
+            let Some(name) = path.file_name() else { continue };
+            let folder_name = name.to_string_lossy().to_string();
+
+            if ignore_rules.is_dir_ignored(&folder_name) {
+                continue;
+            }
+
+            let mut segments = path_segments.to_vec();
+            segments.push(folder_name.clone());
+            let folder_path = segments.join("/");
+            let parent = if path_segments.is_empty() {
+                None
+            } else {
+                Some(path_segments.join("/"))
+            };
+
+            folders.push(FolderInfo {
+                name: folder_name,
+                path: folder_path,
+                parent,
+                depth: segments.len() - 1,
+            });
+
+            Self::collect_folders(&path, &segments, ignore_rules, folders)?;
         }
+
+        Ok(())
+    }
+
+    /// Finds every folder in the notes tree that holds no notes, directly or
+    /// in any of its subfolders.
+    ///
+    /// A folder counts as empty if it holds only other empty folders, so the
+    /// walk is bottom-up and a parent is only flagged once all its children
+    /// have been confirmed empty. Hidden/system files are ignored, and
+    /// protected names (see [`FileSystem::is_protected_name`]) are never
+    /// flagged.
+    ///
+    /// # Returns
+    /// * `Ok(Vec<String>)` - Relative paths of empty folders, deepest first
+    /// * `Err(String)` - If the notes directory can't be read
+    pub fn find_empty_folders(&self) -> Result<Vec<String>, String> {
+        let mut empty = Vec::new();
+        self.collect_empty_folders(&self.notes_dir, &[], &mut empty)?;
+        Ok(empty)
+    }
+
+    /// Bottom-up walk backing [`FileSystem::find_empty_folders`]. Returns
+    /// whether `dir` itself holds no visible files anywhere in its subtree,
+    /// appending every empty, non-protected descendant folder's relative
+    /// path to `empty` (deepest first, thanks to post-order recursion).
+    fn collect_empty_folders(
+        &self,
+        dir: &Path,
+        path_segments: &[String],
+        empty: &mut Vec<String>,
+    ) -> Result<bool, String> {
+        let entries = fs::read_dir(dir)
+            .map_err(|e| format!("Failed to read notes directory: {}", e))?;
+
+        let mut all_descendants_empty = true;
+        let mut subfolders = Vec::new();
+
+        for entry in entries {
+            let entry = entry.map_err(|e| format!("Failed to read directory entry: {}", e))?;
+            let path = entry.path();
+            let entry_name = entry.file_name().to_string_lossy().to_string();
+
+            if entry_name.starts_with('.') {
+                continue;
+            }
+
+            if path.is_dir() {
+                subfolders.push((path, entry_name));
+            } else {
+                all_descendants_empty = false;
+            }
+        }
+
+        for (path, name) in subfolders {
+            let mut segments = path_segments.to_vec();
+            segments.push(name.clone());
+
+            let child_is_empty = self.collect_empty_folders(&path, &segments, empty)?;
+            if !child_is_empty || self.is_protected_name(&name) {
+                all_descendants_empty = false;
+            }
+        }
+
+        // The notes root itself isn't a folder entry - only its subfolders are.
+        if !path_segments.is_empty()
+            && all_descendants_empty
+            && !path_segments.last().map_or(false, |name| self.is_protected_name(name))
+        {
+            empty.push(path_segments.join("/"));
+        }
+
+        Ok(all_descendants_empty)
+    }
+
+    /// Moves every folder returned by [`FileSystem::find_empty_folders`] to
+    /// the trash (non-permanent [`FileSystem::delete_folder`]), in the same
+    /// deepest-first order so a parent is only removed once its
+    /// (already-empty) children are gone.
+    ///
+    /// # Returns
+    /// * `Ok(Vec<String>)` - Relative paths of the folders that were removed
+    /// * `Err(String)` - If the sweep or a deletion fails
+    pub fn remove_empty_folders(&self) -> Result<Vec<String>, String> {
+        let empty = self.find_empty_folders()?;
+        for path in &empty {
+            self.delete_folder(path, false, false)?;
+        }
+        Ok(empty)
+    }
+
+    /// Creates a new folder in the notes directory.
+    /// 
+    /// # Arguments
+    /// * `name` - The name of the folder to create
+    /// 
+    /// # Returns
+    /// * `Ok(())` - If the folder was created successfully
+    /// * `Err(String)` - If validation fails or creation fails
+    /// 
+    /// # Requirements
+    /// Validates: Requirements 10.2
+    /// Checks if a folder name is protected/reserved.
+    pub fn is_protected_name(&self, name: &str) -> bool {
+        matches!(name, "All Notes" | "Trash")
+    }
+
+    /// Builds a "does not exist" error for `name`, appending a "did you mean
+    /// '...'?" suggestion when another existing folder is a close typo match.
+    fn folder_not_found_error(&self, name: &str) -> String {
+        let candidates = self.list_folders().unwrap_or_default();
+        let candidate_names: Vec<&str> = candidates
+            .iter()
+            .filter(|f| !f.path.is_empty())
+            .map(|f| f.path.as_str())
+            .collect();
+
+        match did_you_mean(name, candidate_names) {
+            Some(suggestion) => format!("Folder '{}' does not exist; did you mean '{}'?", name, suggestion),
+            None => format!("Folder '{}' does not exist", name),
+        }
+    }
+
+    /// Builds a "does not exist" error for `note_id`, appending a "did you
+    /// mean '...'?" suggestion when another note in the same folder is a
+    /// close typo match.
+    fn note_not_found_error(&self, note_id: &str, folder: Option<&str>) -> String {
+        let candidates = self.list_notes(folder).unwrap_or_default();
+        let candidate_ids: Vec<&str> = candidates.iter().map(|n| n.id.as_str()).collect();
+
+        match did_you_mean(note_id, candidate_ids) {
+            Some(suggestion) => format!("Note '{}' does not exist; did you mean '{}'?", note_id, suggestion),
+            None => format!("Note '{}' does not exist", note_id),
+        }
+    }
+
+    /// Creates a new folder in the notes directory.
+    ///
+    /// `name` may be a nested path (e.g. `"Projects/2024/Research"`); any
+    /// missing parent segments are created along with it, mirroring `mkdir -p`.
+    ///
+    /// # Arguments
+    /// * `name` - The name (or nested path) of the folder to create
+    ///
+    /// # Returns
+    /// * `Ok(())` - If the folder was created successfully
+    /// * `Err(String)` - If validation fails or creation fails
+    ///
+    /// # Requirements
+    /// Validates: Requirements 10.2
+    pub fn create_folder(&self, name: &str) -> Result<(), String> {
+        // Validate the folder name
+        if name.trim().is_empty() {
+             return Err("Folder name cannot be empty or whitespace only".to_string());
+        }
+
+        if self.is_protected_name(name) {
+            return Err(format!("'{}' is a protected folder name", name));
+        }
+
+        let folder_path = self.validate_notes_folder_path(name)?;
+
+        // Check if folder already exists
+        if folder_path.exists() {
+            return Err(format!("Folder '{}' already exists", name));
+        }
+
+        // Create the folder, along with any missing parent segments
+        fs::create_dir_all(&folder_path)
+            .map_err(|e| format!("Failed to create folder '{}': {}", name, e))?;
+
+        Ok(())
+    }
+
+    /// Counts the non-hidden entries directly inside `folder_path` (files or
+    /// subfolders whose name doesn't start with `.`).
+    fn count_visible_entries(folder_path: &Path) -> Result<usize, String> {
+        let entries = fs::read_dir(folder_path)
+            .map_err(|e| format!("Failed to read folder: {}", e))?;
+
+        let mut count = 0usize;
+        for entry in entries {
+            let entry = entry.map_err(|e| format!("Failed to read directory entry: {}", e))?;
+            if !entry.file_name().to_string_lossy().starts_with('.') {
+                count += 1;
+            }
+        }
+
+        Ok(count)
+    }
+
+    /// Checks whether a folder holds no visible notes or subfolders.
+    ///
+    /// Hidden/system entries (names starting with `.`) are ignored, so a
+    /// folder holding only stray dotfiles still counts as empty.
+    ///
+    /// # Arguments
+    /// * `name` - The name (or nested path) of the folder to check
+    ///
+    /// # Returns
+    /// * `Ok(true)` - If the folder has no non-hidden entries
+    /// * `Ok(false)` - If the folder has at least one non-hidden entry
+    /// * `Err(String)` - If validation fails or the folder can't be read
+    pub fn is_folder_empty(&self, name: &str) -> Result<bool, String> {
+        let folder_path = self.validate_notes_folder_path(name)?;
+        if !folder_path.exists() {
+            return Err(format!("Folder '{}' does not exist", name));
+        }
+
+        Ok(Self::count_visible_entries(&folder_path)? == 0)
+    }
+
+    /// Deletes a folder from the notes directory.
+    ///
+    /// By default (`permanent: false`) the folder is moved into the trash
+    /// ([`FileSystem::trash_dir`]) with a [`crate::models::TrashEntry`]
+    /// recording its original name and deletion time, so it can be restored
+    /// via [`FileSystem::restore_folder`]. Pass `permanent: true` to skip the
+    /// trash and remove the folder and all its contents irreversibly.
+    ///
+    /// A folder that still holds notes or subfolders is refused unless
+    /// `recursive` is `true`, mirroring a shell's `rmdir` vs `rm -r` split -
+    /// hidden/system entries don't count towards "non-empty".
+    ///
+    /// # Arguments
+    /// * `name` - The name of the folder to delete
+    /// * `permanent` - If `true`, deletes immediately instead of trashing
+    /// * `recursive` - If `false`, refuses to delete a non-empty folder
+    ///
+    /// # Returns
+    /// * `Ok(())` - If the folder was deleted (or trashed) successfully
+    /// * `Err(String)` - If validation fails, the folder is non-empty and
+    ///   `recursive` is `false`, or deletion fails
+    ///
+    /// # Requirements
+    /// Validates: Requirements 10.3
+    pub fn delete_folder(&self, name: &str, permanent: bool, recursive: bool) -> Result<(), String> {
+        // Validate the folder name
+        if name.trim().is_empty() {
+             return Err("Folder name cannot be empty or whitespace only".to_string());
+        }
+
+        if self.is_protected_name(name) {
+             return Err(format!("Cannot delete protected folder '{}'", name));
+        }
+
+        let folder_path = self.validate_notes_folder_path(name)?;
+
+        // Check if folder exists
+        if !folder_path.exists() {
+            return Err(self.folder_not_found_error(name));
+        }
+
+        // Check if it's actually a directory
+        if !folder_path.is_dir() {
+            return Err(format!("'{}' is not a folder", name));
+        }
+
+        if !recursive {
+            let visible_count = Self::count_visible_entries(&folder_path)?;
+            if visible_count > 0 {
+                return Err(format!(
+                    "Folder '{}' is not empty ({} items); pass recursive=true to delete",
+                    name, visible_count
+                ));
+            }
+        }
+
+        if permanent {
+            // Recursively remove the folder and all contents
+            fs::remove_dir_all(&folder_path)
+                .map_err(|e| format!("Failed to delete folder '{}': {}", name, e))?;
+            return Ok(());
+        }
+
+        // Flatten any nested path segments into the trash id so trashed
+        // entries never create their own subdirectories under `trash_dir`.
+        let flattened_name = name.replace('/', "_");
+        let trash_id = format!("{}-{}", flattened_name, chrono::Utc::now().timestamp_millis());
+        let trash_path = self.trash_dir.join(&trash_id);
+        fs::rename(&folder_path, &trash_path)
+            .map_err(|e| format!("Failed to move folder '{}' to trash: {}", name, e))?;
+
+        let mut entries = self.load_trash_index()?;
+        entries.push(crate::models::TrashEntry {
+            trash_id,
+            kind: crate::models::TrashItemKind::Folder,
+            original_name: name.to_string(),
+            original_folder: None,
+            deleted_at: chrono::Utc::now(),
+        });
+        self.save_trash_index(&entries)?;
+
+        Ok(())
+    }
+
+    /// Reads the trash metadata index (`.trash/index.json`).
+    ///
+    /// # Returns
+    /// * `Ok(Vec<TrashEntry>)` - Every folder and note currently in the
+    ///   trash, or an empty list if the index doesn't exist yet
+    /// * `Err(String)` - If the index exists but can't be read or parsed
+    fn load_trash_index(&self) -> Result<Vec<crate::models::TrashEntry>, String> {
+        if !self.trash_index_file.exists() {
+            return Ok(Vec::new());
+        }
+
+        let content = fs::read_to_string(&self.trash_index_file)
+            .map_err(|e| format!("Failed to read trash index: {}", e))?;
+
+        serde_json::from_str(&content).map_err(|e| format!("Failed to parse trash index: {}", e))
+    }
+
+    /// Writes the trash metadata index (`.trash/index.json`).
+    fn save_trash_index(&self, entries: &[crate::models::TrashEntry]) -> Result<(), String> {
+        let content = serde_json::to_string_pretty(entries)
+            .map_err(|e| format!("Failed to serialize trash index: {}", e))?;
+
+        fs::write(&self.trash_index_file, content)
+            .map_err(|e| format!("Failed to write trash index: {}", e))
+    }
+
+    /// Lists every folder and note currently in the trash.
+    ///
+    /// # Returns
+    /// * `Ok(Vec<TrashEntry>)` - Trashed folders and notes, in deletion order
+    /// * `Err(String)` - If the trash index can't be read
+    pub fn list_trash(&self) -> Result<Vec<crate::models::TrashEntry>, String> {
+        self.load_trash_index()
+    }
+
+    /// Restores a trashed folder to its original location under the notes
+    /// directory.
+    ///
+    /// # Arguments
+    /// * `trash_id` - The id of the trash entry to restore, from [`FileSystem::list_trash`]
+    ///
+    /// # Returns
+    /// * `Ok(String)` - The restored folder's original name
+    /// * `Err(String)` - If `trash_id` isn't found, the entry is a trashed
+    ///   note (use [`FileSystem::restore_note`] instead), or a folder with
+    ///   the original name already exists at the destination
+    pub fn restore_folder(&self, trash_id: &str) -> Result<String, String> {
+        let mut entries = self.load_trash_index()?;
+        let index = entries
+            .iter()
+            .position(|e| e.trash_id == trash_id)
+            .ok_or_else(|| format!("No trash entry '{}'", trash_id))?;
+
+        let entry = &entries[index];
+        if entry.kind != crate::models::TrashItemKind::Folder {
+            return Err(format!("'{}' is a trashed note, not a folder; use restore_note", entry.original_name));
+        }
+        let destination = self.validate_notes_folder_path(&entry.original_name)?;
+        if destination.exists() {
+            return Err(format!(
+                "Cannot restore '{}': a folder with that name already exists",
+                entry.original_name
+            ));
+        }
+
+        if let Some(parent) = destination.parent() {
+            fs::create_dir_all(parent)
+                .map_err(|e| format!("Failed to recreate parent folders for '{}': {}", entry.original_name, e))?;
+        }
+
+        fs::rename(self.trash_dir.join(trash_id), &destination)
+            .map_err(|e| format!("Failed to restore folder '{}': {}", entry.original_name, e))?;
+
+        let original_name = entries.remove(index).original_name;
+        self.save_trash_index(&entries)?;
+
+        Ok(original_name)
+    }
+
+    /// Restores a trashed note to its original folder.
+    ///
+    /// # Arguments
+    /// * `trash_id` - The id of the trash entry to restore, from [`FileSystem::list_trash`]
+    ///
+    /// # Returns
+    /// * `Ok((String, Option<String>))` - The restored note's original id and folder
+    /// * `Err(String)` - If `trash_id` isn't found, the entry is a trashed
+    ///   folder (use [`FileSystem::restore_folder`] instead), or a note with
+    ///   the original name already exists at the destination
+    pub fn restore_note(&self, trash_id: &str) -> Result<(String, Option<String>), String> {
+        let mut entries = self.load_trash_index()?;
+        let index = entries
+            .iter()
+            .position(|e| e.trash_id == trash_id)
+            .ok_or_else(|| format!("No trash entry '{}'", trash_id))?;
+
+        let entry = &entries[index];
+        if entry.kind != crate::models::TrashItemKind::Note {
+            return Err(format!("'{}' is a trashed folder, not a note; use restore_folder", entry.original_name));
+        }
+
+        let folder_path = self.get_folder_path(entry.original_folder.as_deref());
+        let destination = folder_path.join(format!("{}.md", entry.original_name));
+        if destination.exists() {
+            return Err(format!(
+                "Cannot restore '{}': a note with that name already exists",
+                entry.original_name
+            ));
+        }
+
+        if let Some(parent) = destination.parent() {
+            fs::create_dir_all(parent)
+                .map_err(|e| format!("Failed to recreate parent folder for '{}': {}", entry.original_name, e))?;
+        }
+
+        fs::rename(self.trash_dir.join(trash_id), &destination)
+            .map_err(|e| format!("Failed to restore note '{}': {}", entry.original_name, e))?;
+
+        let restored = entries.remove(index);
+        self.save_trash_index(&entries)?;
+
+        Ok((restored.original_name, restored.original_folder))
+    }
+
+    /// Permanently deletes everything currently in the trash, folders and notes alike.
+    ///
+    /// # Returns
+    /// * `Ok(())` - If every trashed item was removed
+    /// * `Err(String)` - If any item failed to delete; the trash index is
+    ///   left as-is so a retry doesn't lose track of what remains
+    pub fn empty_trash(&self) -> Result<(), String> {
+        let entries = self.load_trash_index()?;
+        for entry in &entries {
+            let trash_path = self.trash_dir.join(&entry.trash_id);
+            if trash_path.is_dir() {
+                fs::remove_dir_all(&trash_path).map_err(|e| {
+                    format!("Failed to permanently delete '{}': {}", entry.original_name, e)
+                })?;
+            } else if trash_path.exists() {
+                fs::remove_file(&trash_path).map_err(|e| {
+                    format!("Failed to permanently delete '{}': {}", entry.original_name, e)
+                })?;
+            }
+        }
+
+        self.save_trash_index(&[])
+    }
+
+    /// Renames (or moves) a folder in the notes directory.
+    ///
+    /// When `new_name` already exists, the default (`overwrite: false,
+    /// merge: false`) is to fail with "already exists", exactly as before.
+    /// Pass `overwrite: true` to replace the destination outright, or
+    /// `merge: true` to move `old_name`'s notes into the destination
+    /// instead, resolving filename collisions by suffixing (`note (1).md`,
+    /// `note (2).md`, ...). `merge` takes precedence if both are set.
+    ///
+    /// # Arguments
+    /// * `old_name` - The current name (or nested path) of the folder
+    /// * `new_name` - The new name (or nested path) for the folder
+    /// * `overwrite` - If `true` and `new_name` exists, replace it
+    /// * `merge` - If `true` and `new_name` exists, merge `old_name`'s notes into it
+    ///
+    /// # Returns
+    /// * `Ok(())` - If the folder was renamed, replaced, or merged successfully
+    /// * `Err(String)` - If validation fails or the operation fails
+    ///
+    /// # Requirements
+    /// Validates: Requirements 10.4
+    pub fn rename_folder(
+        &self,
+        old_name: &str,
+        new_name: &str,
+        overwrite: bool,
+        merge: bool,
+    ) -> Result<(), String> {
+        // Validate both folder names
+        if old_name.trim().is_empty() || new_name.trim().is_empty() {
+             return Err("Folder name cannot be empty or whitespace only".to_string());
+        }
+
+        if self.is_protected_name(old_name) {
+             return Err(format!("Cannot rename protected folder '{}'", old_name));
+        }
+
+        if self.is_protected_name(new_name) {
+             return Err(format!("Cannot rename to protected name '{}'", new_name));
+        }
+
+        let old_path = self.validate_notes_folder_path(old_name)?;
+        let new_path = self.validate_notes_folder_path(new_name)?;
+
+        // Check if old folder exists
+        if !old_path.exists() {
+            return Err(self.folder_not_found_error(old_name));
+        }
+
+        // Check if it's actually a directory
+        if !old_path.is_dir() {
+            return Err(format!("'{}' is not a folder", old_name));
+        }
+
+        if new_path.exists() {
+            if merge {
+                return self.merge_folder(&old_path, &new_path, old_name, new_name);
+            }
+            if overwrite {
+                fs::remove_dir_all(&new_path)
+                    .map_err(|e| format!("Failed to replace folder '{}': {}", new_name, e))?;
+            } else {
+                return Err(format!("Folder '{}' already exists", new_name));
+            }
+        }
+
+        // Ensure the new location's parent segments exist (the new name may
+        // nest the folder under a path that doesn't exist yet)
+        if let Some(parent) = new_path.parent() {
+            fs::create_dir_all(parent)
+                .map_err(|e| format!("Failed to create parent folders for '{}': {}", new_name, e))?;
+        }
+
+        // Rename the folder
+        fs::rename(&old_path, &new_path)
+            .map_err(|e| format!("Failed to rename folder '{}' to '{}': {}", old_name, new_name, e))?;
+
+        Ok(())
+    }
+
+    /// Moves every note file from `old_path` into `new_path`, suffixing a
+    /// colliding destination filename (`note.md` -> `note (1).md`, `note
+    /// (2).md`, ...) rather than overwriting it, then removes `old_path`.
+    fn merge_folder(&self, old_path: &Path, new_path: &Path, old_name: &str, new_name: &str) -> Result<(), String> {
+        let entries = fs::read_dir(old_path)
+            .map_err(|e| format!("Failed to read folder '{}': {}", old_name, e))?;
+
+        for entry in entries {
+            let entry = entry.map_err(|e| format!("Failed to read directory entry: {}", e))?;
+            let source = entry.path();
+
+            if source.is_dir() {
+                return Err(format!(
+                    "Cannot merge '{}' into '{}': subfolder '{}' is not supported",
+                    old_name,
+                    new_name,
+                    entry.file_name().to_string_lossy()
+                ));
+            }
+
+            let file_name = entry.file_name();
+            let destination = Self::unique_destination(new_path, Path::new(&file_name));
+            fs::rename(&source, &destination)
+                .map_err(|e| format!("Failed to move '{}' while merging: {}", file_name.to_string_lossy(), e))?;
+        }
+
+        fs::remove_dir_all(old_path)
+            .map_err(|e| format!("Failed to remove '{}' after merging: {}", old_name, e))
+    }
+
+    /// Returns a path inside `dir` for `file_name` that doesn't already
+    /// exist, suffixing ` (1)`, ` (2)`, ... before the extension on collision.
+    fn unique_destination(dir: &Path, file_name: &Path) -> PathBuf {
+        let candidate = dir.join(file_name);
+        if !candidate.exists() {
+            return candidate;
+        }
+
+        let stem = file_name.file_stem().map(|s| s.to_string_lossy().to_string()).unwrap_or_default();
+        let extension = file_name.extension().map(|e| e.to_string_lossy().to_string());
+
+        let mut attempt = 1;
+        loop {
+            let candidate_name = match &extension {
+                Some(ext) => format!("{} ({}).{}", stem, attempt, ext),
+                None => format!("{} ({})", stem, attempt),
+            };
+            let candidate = dir.join(candidate_name);
+            if !candidate.exists() {
+                return candidate;
+            }
+            attempt += 1;
+        }
+    }
+
+    /// Recursively copies a folder and everything under it to a new
+    /// location within the notes directory.
+    ///
+    /// `from` must already exist; `to` is created (along with any missing
+    /// parent segments) if it doesn't. Pass `overwrite: true` to replace a
+    /// colliding destination file; otherwise a collision fails the whole
+    /// copy, leaving whatever was already copied in place.
+    ///
+    /// # Arguments
+    /// * `from` - The folder (or nested path) to copy
+    /// * `to` - The destination folder (or nested path)
+    /// * `overwrite` - If `true`, replaces a file already at the destination
+    ///
+    /// # Returns
+    /// * `Ok(usize)` - The number of files copied
+    /// * `Err(String)` - If validation fails or the copy fails partway through
+    pub fn copy_folder(&self, from: &str, to: &str, overwrite: bool) -> Result<usize, String> {
+        let from_path = self.validate_notes_folder_path(from)?;
+        let to_path = self.validate_notes_folder_path(to)?;
+
+        if !from_path.exists() || !from_path.is_dir() {
+            return Err(self.folder_not_found_error(from));
+        }
+
+        self.reject_copy_into_self_or_descendant(&from_path, &to_path, from, to)?;
+
+        Self::copy_dir_recursive(&from_path, &to_path, overwrite)
+    }
+
+    /// Moves a folder and everything under it to a new location within the
+    /// notes directory.
+    ///
+    /// Tries a single `fs::rename` first, which is atomic and the common
+    /// case (same filesystem, no existing destination). Falls back to a
+    /// recursive copy followed by removing `from` when the rename fails
+    /// because the move crosses filesystem boundaries or `to` already
+    /// exists.
+    ///
+    /// # Arguments
+    /// * `from` - The folder (or nested path) to move
+    /// * `to` - The destination folder (or nested path)
+    /// * `overwrite` - If falling back to copy, replaces a colliding destination file
+    ///
+    /// # Returns
+    /// * `Ok(usize)` - The number of files moved
+    /// * `Err(String)` - If validation fails or the move fails partway through
+    pub fn move_folder(&self, from: &str, to: &str, overwrite: bool) -> Result<usize, String> {
+        let from_path = self.validate_notes_folder_path(from)?;
+        let to_path = self.validate_notes_folder_path(to)?;
+
+        if !from_path.exists() || !from_path.is_dir() {
+            return Err(self.folder_not_found_error(from));
+        }
+
+        self.reject_copy_into_self_or_descendant(&from_path, &to_path, from, to)?;
+
+        if !to_path.exists() {
+            if let Some(parent) = to_path.parent() {
+                fs::create_dir_all(parent)
+                    .map_err(|e| format!("Failed to create parent folders for '{}': {}", to, e))?;
+            }
+            if fs::rename(&from_path, &to_path).is_ok() {
+                return Ok(Self::count_files(&to_path));
+            }
+        }
+
+        let count = Self::copy_dir_recursive(&from_path, &to_path, overwrite)?;
+        fs::remove_dir_all(&from_path)
+            .map_err(|e| format!("Failed to remove '{}' after moving: {}", from, e))?;
+        Ok(count)
+    }
+
+    /// Refuses a copy/move whose destination is `from` itself or nested
+    /// inside it, comparing canonicalized paths so a collision can't be
+    /// hidden behind a symlink.
+    fn reject_copy_into_self_or_descendant(&self, from_path: &Path, to_path: &Path, from: &str, to: &str) -> Result<(), String> {
+        let canonical_from = from_path
+            .canonicalize()
+            .map_err(|e| format!("Failed to canonicalize '{}': {}", from, e))?;
+        let canonical_to_base = to_path
+            .parent()
+            .filter(|p| p.exists())
+            .map(|p| p.canonicalize())
+            .transpose()
+            .map_err(|e| format!("Failed to canonicalize '{}': {}", to, e))?
+            .unwrap_or_else(|| self.notes_dir.clone());
+        let canonical_to = if to_path.exists() {
+            to_path.canonicalize().map_err(|e| format!("Failed to canonicalize '{}': {}", to, e))?
+        } else {
+            canonical_to_base.join(to_path.file_name().unwrap_or_default())
+        };
+
+        if canonical_to == canonical_from || canonical_to.starts_with(&canonical_from) {
+            return Err(format!("Cannot copy or move '{}' into itself or one of its own subfolders", from));
+        }
+
+        Ok(())
+    }
+
+    /// Recreates the directory tree rooted at `from` under `to`, copying
+    /// every file. Returns the number of files copied.
+    fn copy_dir_recursive(from: &Path, to: &Path, overwrite: bool) -> Result<usize, String> {
+        fs::create_dir_all(to).map_err(|e| format!("Failed to create '{}': {}", to.display(), e))?;
+
+        let mut count = 0;
+        let entries = fs::read_dir(from).map_err(|e| format!("Failed to read '{}': {}", from.display(), e))?;
+        for entry in entries {
+            let entry = entry.map_err(|e| format!("Failed to read directory entry: {}", e))?;
+            let source = entry.path();
+            let destination = to.join(entry.file_name());
+
+            if source.is_dir() {
+                count += Self::copy_dir_recursive(&source, &destination, overwrite)?;
+            } else {
+                if destination.exists() && !overwrite {
+                    return Err(format!("'{}' already exists", destination.display()));
+                }
+                fs::copy(&source, &destination)
+                    .map_err(|e| format!("Failed to copy '{}': {}", source.display(), e))?;
+                count += 1;
+            }
+        }
+
+        Ok(count)
+    }
+
+    /// Counts every file nested under `dir`, recursively.
+    fn count_files(dir: &Path) -> usize {
+        let Ok(entries) = fs::read_dir(dir) else { return 0 };
+        entries
+            .filter_map(|e| e.ok())
+            .map(|e| {
+                let path = e.path();
+                if path.is_dir() { Self::count_files(&path) } else { 1 }
+            })
+            .sum()
+    }
+
+    /// Matches `pattern` (`*`/`?` wildcards) against the direct child folder
+    /// names of the notes root only - never against full filesystem paths -
+    /// so a pattern can't be used to reach outside the sandbox enforced by
+    /// [`FileSystem::validate_notes_folder_path`].
+    fn matching_top_level_folder_names(&self, pattern: &str) -> Result<Vec<String>, String> {
+        let entries = fs::read_dir(&self.notes_dir)
+            .map_err(|e| format!("Failed to read notes directory: {}", e))?;
+
+        let mut matches = Vec::new();
+        for entry in entries {
+            let entry = entry.map_err(|e| format!("Failed to read directory entry: {}", e))?;
+            let path = entry.path();
+            if !path.is_dir() {
+                continue;
+            }
+
+            let Some(name) = path.file_name() else { continue };
+            let name = name.to_string_lossy().to_string();
+            if matches_wildcard(pattern, &name) {
+                matches.push(name);
+            }
+        }
+
+        matches.sort();
+        Ok(matches)
+    }
+
+    /// Deletes every top-level folder matching a `*`/`?` wildcard `pattern`.
+    ///
+    /// Each match is deleted independently through [`FileSystem::delete_folder`]
+    /// (so it's still trashed, not permanently removed, and still subject to
+    /// the `recursive` guard) - one failure doesn't stop the rest of the batch.
+    ///
+    /// # Arguments
+    /// * `pattern` - A `*`/`?` wildcard matched against direct folder names
+    /// * `recursive` - If `false`, refuses to delete any matched non-empty folder
+    ///
+    /// # Returns
+    /// * `Ok(BatchFolderResult)` - Which matched folders succeeded or failed
+    /// * `Err(String)` - If the notes directory can't be read
+    pub fn delete_folders(&self, pattern: &str, recursive: bool) -> Result<crate::models::BatchFolderResult, String> {
+        let mut result = crate::models::BatchFolderResult::default();
+        for name in self.matching_top_level_folder_names(pattern)? {
+            match self.delete_folder(&name, false, recursive) {
+                Ok(()) => result.succeeded.push(name),
+                Err(e) => result.failed.push((name, e)),
+            }
+        }
+        Ok(result)
+    }
+
+    /// Renames every top-level folder matching a `*`/`?` wildcard `pattern`.
+    ///
+    /// `template` is the destination name, with every `{}` replaced by the
+    /// matched folder's original name (e.g. `"Archived-{}"`).
+    ///
+    /// # Arguments
+    /// * `pattern` - A `*`/`?` wildcard matched against direct folder names
+    /// * `template` - The destination name template, `{}` standing in for the original name
+    ///
+    /// # Returns
+    /// * `Ok(BatchFolderResult)` - Which matched folders succeeded or failed
+    /// * `Err(String)` - If the notes directory can't be read
+    pub fn rename_folders(&self, pattern: &str, template: &str) -> Result<crate::models::BatchFolderResult, String> {
+        let mut result = crate::models::BatchFolderResult::default();
+        for name in self.matching_top_level_folder_names(pattern)? {
+            let new_name = template.replace("{}", &name);
+            match self.rename_folder(&name, &new_name, false, false) {
+                Ok(()) => result.succeeded.push(name),
+                Err(e) => result.failed.push((name, e)),
+            }
+        }
+        Ok(result)
+    }
+
+    /// Atomically writes `data` to `path`: writes the bytes to a temporary
+    /// file in the same directory (so the final `fs::rename` stays on one
+    /// filesystem and is atomic), flushes and `sync_all()`s it, then renames
+    /// it over `path`. A reader never observes a half-written note or image,
+    /// even if the process is killed mid-write, as a plain `fs::write` would
+    /// risk. If `path`'s parent directory doesn't exist yet (a brand-new
+    /// folder), it's created and the write retried once. The temp file is
+    /// removed on any failure so a crash doesn't leave it behind.
+    fn atomic_write(&self, path: &Path, data: &[u8]) -> Result<(), String> {
+        match Self::atomic_write_attempt(path, data) {
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => {
+                if let Some(parent) = path.parent() {
+                    fs::create_dir_all(parent)
+                        .map_err(|e| format!("Failed to create parent directory for '{}': {}", path.display(), e))?;
+                }
+                Self::atomic_write_attempt(path, data)
+                    .map_err(|e| format!("Failed to write '{}': {}", path.display(), e))
+            }
+            Err(e) => Err(format!("Failed to write '{}': {}", path.display(), e)),
+            Ok(()) => Ok(()),
+        }
+    }
+
+    /// One attempt at the write-temp/sync/rename sequence behind
+    /// [`FileSystem::atomic_write`], cleaning up its temp file on failure.
+    fn atomic_write_attempt(path: &Path, data: &[u8]) -> std::io::Result<()> {
+        let file_name = path.file_name().map(|n| n.to_string_lossy().into_owned()).unwrap_or_default();
+        let tmp_path = path.with_file_name(format!(".{}.{}.tmp", file_name, uuid::Uuid::new_v4()));
+
+        let result = (|| -> std::io::Result<()> {
+            let mut tmp_file = fs::File::create(&tmp_path)?;
+            tmp_file.write_all(data)?;
+            tmp_file.sync_all()?;
+            drop(tmp_file);
+            fs::rename(&tmp_path, path)
+        })();
+
+        if result.is_err() {
+            let _ = fs::remove_file(&tmp_path);
+        }
+        result
+    }
+
+    /// Atomically writes `contents` to `path`, preserving the target's Unix
+    /// permission mode across the write.
+    ///
+    /// Like [`FileSystem::atomic_write`], this writes to a sibling
+    /// `<file-name>.<4-random-hex>.tmp` file, `fsync`s it, then
+    /// `fs::rename`s it over `path` so a crash or power loss mid-write never
+    /// leaves a truncated file behind. Unlike `atomic_write`, it also reads
+    /// `path`'s existing permission mode (if any) before writing and
+    /// restores it on the temp file before the rename, so a save doesn't
+    /// silently reset a note's permissions to the process umask.
+    pub fn write_file_atomic(&self, path: &Path, contents: &str) -> Result<(), String> {
+        let file_name = path.file_name().map(|n| n.to_string_lossy().into_owned()).unwrap_or_default();
+        let suffix = uuid::Uuid::new_v4().simple().to_string()[..4].to_string();
+        let tmp_path = path.with_file_name(format!("{}.{}.tmp", file_name, suffix));
+
+        #[cfg(unix)]
+        let original_mode = {
+            use std::os::unix::fs::PermissionsExt;
+            fs::metadata(path).ok().map(|m| m.permissions().mode())
+        };
+
+        let result = (|| -> std::io::Result<()> {
+            let mut tmp_file = fs::File::create(&tmp_path)?;
+            tmp_file.write_all(contents.as_bytes())?;
+            tmp_file.sync_all()?;
+            drop(tmp_file);
+
+            #[cfg(unix)]
+            {
+                use std::os::unix::fs::PermissionsExt;
+                if let Some(mode) = original_mode {
+                    fs::set_permissions(&tmp_path, fs::Permissions::from_mode(mode))?;
+                }
+            }
+
+            fs::rename(&tmp_path, path)
+        })();
+
+        if result.is_err() {
+            let _ = fs::remove_file(&tmp_path);
+        }
+
+        result.map_err(|e| format!("Failed to write '{}': {}", path.display(), e))
+    }
+
+    /// Runs `f` while holding an exclusive lock on `{base_dir}/lock`, so two
+    /// `mded` processes can't interleave a read-modify-write on the same
+    /// metadata file (e.g. `toggle_pin_note`'s load-then-save).
+    ///
+    /// The lock is a real OS-level `flock` via [`fs2::FileExt::try_lock_exclusive`]
+    /// (the same mechanism [`crate::config::ConfigManager`]'s config-file lock
+    /// uses) rather than a plain `create_new` marker file - a second instance
+    /// finding it already held fails fast with a clear error instead of
+    /// blocking, mirroring Mercurial's `try_with_lock_no_wait`, but unlike a
+    /// marker file the kernel releases it automatically if this process dies
+    /// while holding it, so an unclean shutdown can never leave the lock
+    /// permanently stuck.
+    fn with_lock<T>(&self, f: impl FnOnce() -> Result<T, String>) -> Result<T, String> {
+        use fs2::FileExt;
+
+        let lock_path = self.base_dir.join("lock");
+        let file = fs::OpenOptions::new()
+            .read(true)
+            .write(true)
+            .create(true)
+            .open(&lock_path)
+            .map_err(|e| format!("Failed to open lock file: {}", e))?;
+
+        file.try_lock_exclusive().map_err(|e| match e.kind() {
+            std::io::ErrorKind::WouldBlock => "Another mded instance is modifying data; try again".to_string(),
+            _ => format!("Failed to acquire lock: {}", e),
+        })?;
+
+        // `file`'s flock is released when it's dropped at the end of this
+        // scope (or if the process exits/crashes before then), so there's
+        // nothing to explicitly unlock even on an early return from `f`.
+        f()
+    }
+
+    // ==================== Note Operations ====================
+
+    /// Lists all notes, optionally filtered by folder.
+    /// 
+    /// Returns all .md files with metadata including id, title, modified date,
+    /// created date, folder, and pinned status.
+    /// 
+    /// # Arguments
+    /// * `folder` - Optional folder name to filter notes. If None or "All Notes", returns all notes.
+    /// 
+    /// # Returns
+    /// * `Ok(Vec<NoteInfo>)` - List of notes with metadata
+    /// * `Err(String)` - If reading fails
+    /// 
+    /// # Requirements
+    /// Validates: Requirements 11.1, 11.2
+    pub fn list_notes(&self, folder: Option<&str>) -> Result<Vec<crate::models::NoteInfo>, String> {
+        self.list_notes_inner(folder, false)
+    }
+
+    /// The body of [`FileSystem::list_notes`], with the parallel/serial
+    /// choice exposed so tests can force the serial path and compare it
+    /// against whatever the size-based heuristic would have picked.
+    fn list_notes_inner(&self, folder: Option<&str>, force_serial: bool) -> Result<Vec<crate::models::NoteInfo>, String> {
+        let ignore_rules = IgnoreRules::load(&self.notes_dir);
+        let scanning_everything = folder.is_none() || folder == Some("All Notes") || folder == Some("");
+
+        // Determine which directories to scan
+        let dirs_to_scan: Vec<(PathBuf, String)> = if scanning_everything {
+            // Scan all directories including root
+            let mut dirs = vec![(self.notes_dir.clone(), String::new())];
+
+            // Add subdirectories
+            if let Ok(entries) = fs::read_dir(&self.notes_dir) {
+                for entry in entries.flatten() {
+                    let path = entry.path();
+                    if path.is_dir() {
+                        if let Some(name) = path.file_name() {
+                            let name_str = name.to_string_lossy().to_string();
+                            if ignore_rules.is_dir_ignored(&name_str) {
+                                continue;
+                            }
+                            dirs.push((path, name_str));
+                        }
+                    }
+                }
+            }
+            dirs
+        } else {
+            // Scan only the specified folder
+            let folder_name = folder.unwrap();
+            let folder_path = self.get_folder_path(Some(folder_name));
+            if !folder_path.exists() {
+                return Err(format!("Folder '{}' does not exist", folder_name));
+            }
+            vec![(folder_path, folder_name.to_string())]
+        };
+
+        // Load pinned notes from config (placeholder - will be integrated with config module later)
+        let pinned_notes: Vec<String> = self.load_pinned_notes().unwrap_or_default();
+
+        // The index lets an unchanged note reuse its cached title instead of
+        // being re-read and re-parsed on every call - the dominant cost on a
+        // vault with thousands of notes. Only a scan covering the whole
+        // vault can safely drop stale entries below; a single-folder scan
+        // can't tell whether an entry elsewhere is still current.
+        let mut index = self.load_index();
+
+        // Collect every candidate .md path up front; the (possibly
+        // parallel) work below is just turning each one into a NoteInfo.
+        let mut candidates: Vec<(PathBuf, String)> = Vec::new();
+        for (dir_path, folder_name) in dirs_to_scan {
+            if let Ok(entries) = fs::read_dir(&dir_path) {
+                for entry in entries.flatten() {
+                    let path = entry.path();
+                    if path.is_file() && path.extension().map_or(false, |ext| ext == "md") {
+                        if let Some(file_name) = path.file_name() {
+                            if ignore_rules.is_file_ignored(&file_name.to_string_lossy()) {
+                                continue;
+                            }
+                            candidates.push((path, folder_name.clone()));
+                        }
+                    }
+                }
+            }
+        }
+
+        // Parsing each candidate is independent of the others, so above a
+        // size threshold it's worth spreading the work across a thread
+        // pool; below it, the overhead of spinning one up isn't worth it.
+        let results: Vec<Result<(String, crate::models::NoteInfo, NoteIndexEntry), String>> =
+            if candidates.len() > Self::PARALLEL_LIST_NOTES_THRESHOLD && !force_serial {
+                use rayon::prelude::*;
+                candidates
+                    .par_iter()
+                    .map(|(path, folder_name)| self.build_note_entry(path, folder_name, &index, &pinned_notes))
+                    .collect()
+            } else {
+                candidates
+                    .iter()
+                    .map(|(path, folder_name)| self.build_note_entry(path, folder_name, &index, &pinned_notes))
+                    .collect()
+            };
+
+        let mut notes = Vec::with_capacity(results.len());
+        let mut seen_keys = std::collections::HashSet::with_capacity(results.len());
+        for result in results {
+            let (key, note, entry) = result?;
+            seen_keys.insert(key.clone());
+            index.entries.insert(key, entry);
+            notes.push(note);
+        }
+
+        if scanning_everything {
+            index.entries.retain(|key, _| seen_keys.contains(key));
+        }
+        let _ = self.save_index(&index);
+
+        // Sort notes: pinned first, then by modified date (newest first).
+        // Applying this after the parallel map above (rather than ordering
+        // within it) is what keeps the output deterministic regardless of
+        // which thread finished first.
+        notes.sort_by(|a, b| {
+            match (a.pinned, b.pinned) {
+                (true, false) => std::cmp::Ordering::Less,
+                (false, true) => std::cmp::Ordering::Greater,
+                _ => b.modified.cmp(&a.modified),
+            }
+        });
+
+        Ok(notes)
+    }
+
+    /// Reads one candidate `.md` file's metadata and (cache permitting)
+    /// title, producing the triple [`FileSystem::list_notes`] needs: the
+    /// note's index key, its [`crate::models::NoteInfo`], and the
+    /// [`NoteIndexEntry`] to store back into the index.
+    ///
+    /// Takes no `&mut self` state so it can run from either a serial
+    /// iterator or a rayon `par_iter` unchanged.
+    fn build_note_entry(
+        &self,
+        path: &Path,
+        folder_name: &str,
+        index: &NoteIndex,
+        pinned_notes: &[String],
+    ) -> Result<(String, crate::models::NoteInfo, NoteIndexEntry), String> {
+        use crate::models::NoteInfo;
+        use chrono::{DateTime, Utc};
+
+        let file_name_str = path.file_name().map(|n| n.to_string_lossy().to_string()).unwrap_or_default();
+        let note_id = file_name_str.trim_end_matches(".md").to_string();
+
+        let metadata = fs::metadata(path)
+            .map_err(|e| format!("Failed to read metadata for '{}': {}", file_name_str, e))?;
+
+        let modified: DateTime<Utc> = metadata.modified()
+            .map(|t| t.into())
+            .unwrap_or_else(|_| Utc::now());
+        let created: DateTime<Utc> = metadata.created()
+            .map(|t| t.into())
+            .unwrap_or(modified);
+
+        let index_key = path
+            .strip_prefix(&self.notes_dir)
+            .unwrap_or(path)
+            .to_string_lossy()
+            .to_string();
+        let mtime_nanos = note_mtime_nanos(&metadata);
+        let size = metadata.len();
+
+        let (title, tags, entry) = match index.entries.get(&index_key) {
+            Some(cached)
+                if cached.mtime_nanos == mtime_nanos && cached.size == size && cached.folder == folder_name =>
+            {
+                (cached.title.clone(), cached.tags.clone(), cached.clone())
+            }
+            _ => {
+                let (title, tags) = self.extract_note_title_and_tags(path);
+                let title = title.unwrap_or_else(|| note_id.clone());
+                let entry = NoteIndexEntry {
+                    mtime_nanos,
+                    size,
+                    title: title.clone(),
+                    folder: folder_name.to_string(),
+                    tags: tags.clone(),
+                };
+                (title, tags, entry)
+            }
+        };
+
+        let pinned = pinned_notes.contains(&note_id);
+
+        Ok((
+            index_key,
+            NoteInfo {
+                id: note_id,
+                title,
+                modified,
+                created,
+                folder: folder_name.to_string(),
+                pinned,
+                tags,
+            },
+            entry,
+        ))
+    }
+
+    /// Reads the persisted [`NoteIndex`] cache, or an empty one if it
+    /// doesn't exist yet or fails to parse.
+    fn load_index(&self) -> NoteIndex {
+        fs::read_to_string(&self.index_file)
+            .ok()
+            .and_then(|content| serde_json::from_str(&content).ok())
+            .unwrap_or_default()
+    }
+
+    /// Persists `index` to [`FileSystem::index_file`].
+    fn save_index(&self, index: &NoteIndex) -> Result<(), String> {
+        let content = serde_json::to_string_pretty(index)
+            .map_err(|e| format!("Failed to serialize note index: {}", e))?;
+        write_atomic(&self.index_file, &content)
+    }
+
+    /// Forces a full re-read of every note, bypassing whatever is cached in
+    /// the [`NoteIndex`], and persists the freshly rebuilt index.
+    ///
+    /// Useful after an external tool has rewritten note files without going
+    /// through `mded` (an mtime/size match could otherwise coincidentally
+    /// hide a real content change), or to recover from a corrupted index.
+    pub fn rebuild_index(&self) -> Result<(), String> {
+        self.save_index(&NoteIndex::default())?;
+        self.list_notes(None)?;
+        Ok(())
+    }
+
+    /// Lists every tag found in frontmatter across the whole vault, with how
+    /// many notes carry each one, sorted by descending count (ties broken
+    /// alphabetically).
+    pub fn list_tags(&self) -> Result<Vec<(String, usize)>, String> {
+        let mut counts: std::collections::HashMap<String, usize> = std::collections::HashMap::new();
+        for note in self.list_notes(None)? {
+            for tag in note.tags {
+                *counts.entry(tag).or_insert(0) += 1;
+            }
+        }
+
+        let mut counted: Vec<(String, usize)> = counts.into_iter().collect();
+        counted.sort_by(|a, b| b.1.cmp(&a.1).then_with(|| a.0.cmp(&b.0)));
+        Ok(counted)
+    }
+
+    /// Lists every note whose frontmatter carries `tag`, in the same order
+    /// [`FileSystem::list_notes`] would return them.
+    pub fn list_notes_by_tag(&self, tag: &str) -> Result<Vec<crate::models::NoteInfo>, String> {
+        Ok(self
+            .list_notes(None)?
+            .into_iter()
+            .filter(|note| note.tags.iter().any(|t| t == tag))
+            .collect())
+    }
+
+    /// Searches note content for `query`, optionally restricted to one folder.
+    ///
+    /// Walks the same directory set as [`FileSystem::list_notes`]. Matching is
+    /// always case-insensitive; pass `whole_word: true` to additionally require
+    /// the match not be adjacent to another word character. Results are ranked
+    /// by match count descending, then modified date (newest first), with
+    /// pinned notes floating to the top as the tiebreak [`FileSystem::list_notes`]
+    /// already uses.
+    ///
+    /// # Arguments
+    /// * `query` - The text to search for
+    /// * `folder` - Optional folder name to restrict the search to
+    /// * `whole_word` - If `true`, only counts matches not adjacent to a word character
+    ///
+    /// # Returns
+    /// * `Ok(Vec<SearchHit>)` - Matching notes, ranked highest-relevance first
+    /// * `Err(String)` - If `query` is empty or reading a note fails
+    pub fn search_notes(&self, query: &str, folder: Option<&str>, whole_word: bool) -> Result<Vec<crate::models::SearchHit>, String> {
+        use crate::models::SearchHit;
+
+        if query.trim().is_empty() {
+            return Err("Search query cannot be empty".to_string());
+        }
+
+        let query_lower = query.to_lowercase();
+        let pinned_notes: Vec<String> = self.load_pinned_notes().unwrap_or_default();
+        let mut hits = Vec::new();
+
+        for note in self.list_notes(folder)? {
+            let note_folder = if note.folder.is_empty() { None } else { Some(note.folder.as_str()) };
+            let path = self.get_folder_path(note_folder).join(format!("{}.md", note.id));
+            let content = match fs::read_to_string(&path) {
+                Ok(content) => content,
+                Err(_) => continue,
+            };
+            let content_lower = content.to_lowercase();
+
+            let (match_count, snippet) = count_matches_and_snippet(&content, &content_lower, &query_lower, whole_word);
+            if match_count == 0 {
+                continue;
+            }
+
+            hits.push(SearchHit {
+                id: note.id.clone(),
+                title: note.title.clone(),
+                folder: note.folder.clone(),
+                match_count,
+                snippet,
+                modified: note.modified,
+                pinned: pinned_notes.contains(&note.id),
+                score: match_count as f64,
+            });
+        }
+
+        hits.sort_by(|a, b| {
+            match (a.pinned, b.pinned) {
+                (true, false) => std::cmp::Ordering::Less,
+                (false, true) => std::cmp::Ordering::Greater,
+                _ => b.match_count.cmp(&a.match_count).then_with(|| b.modified.cmp(&a.modified)),
+            }
+        });
+
+        Ok(hits)
+    }
+
+    /// Runs `f` against the lazily-built [`SearchIndex`], building it first
+    /// via [`FileSystem::build_search_index`] if this is the first search (or
+    /// the first indexing call) since the `FileSystem` was created.
+    fn with_search_index<R>(&self, f: impl FnOnce(&mut SearchIndex) -> R) -> Result<R, String> {
+        let mut guard = self
+            .search_index
+            .lock()
+            .map_err(|_| "Search index lock was poisoned".to_string())?;
+        if guard.is_none() {
+            *guard = Some(self.build_search_index()?);
+        }
+        Ok(f(guard.as_mut().expect("search index was just initialized")))
+    }
+
+    /// Builds a fresh [`SearchIndex`] by tokenizing every note currently in
+    /// the vault, the same set [`FileSystem::list_notes`] walks.
+    fn build_search_index(&self) -> Result<SearchIndex, String> {
+        let mut index = SearchIndex::default();
+        for note in self.list_notes(None)? {
+            let note_folder = if note.folder.is_empty() { None } else { Some(note.folder.as_str()) };
+            let path = self.get_folder_path(note_folder).join(format!("{}.md", note.id));
+            if let Ok(content) = fs::read_to_string(&path) {
+                index.upsert_note(&note.id, &note.folder, &content);
+            }
+        }
+        Ok(index)
+    }
+
+    /// Re-indexes one note in place, used by [`FileSystem::save_note`] and
+    /// [`FileSystem::create_note`] so a save doesn't require rebuilding the
+    /// whole index. Indexing failures (e.g. a poisoned lock) are swallowed -
+    /// a stale or missing index entry only degrades search, it never breaks
+    /// the save itself.
+    fn index_upsert_note(&self, note_id: &str, folder: &str, content: &str) {
+        let _ = self.with_search_index(|index| index.upsert_note(note_id, folder, content));
+    }
+
+    /// Drops a note from the index, used by [`FileSystem::delete_note`].
+    fn index_remove_note(&self, note_id: &str) {
+        let _ = self.with_search_index(|index| index.remove_note(note_id));
+    }
+
+    /// Re-indexes a note under its new id, used by [`FileSystem::rename_note`].
+    fn index_rename_note(&self, old_note_id: &str, new_note_id: &str, folder: &str, content: &str) {
+        let _ = self.with_search_index(|index| {
+            index.remove_note(old_note_id);
+            index.upsert_note(new_note_id, folder, content);
+        });
+    }
+
+    /// Forces the search index to be rebuilt from scratch on the next call,
+    /// mirroring [`FileSystem::rebuild_index`] for the note-title cache.
+    pub fn rebuild_search_index(&self) -> Result<(), String> {
+        let fresh = self.build_search_index()?;
+        let mut guard = self
+            .search_index
+            .lock()
+            .map_err(|_| "Search index lock was poisoned".to_string())?;
+        *guard = Some(fresh);
+        Ok(())
+    }
+
+    /// Starts a background watcher that recursively monitors [`FileSystem::notes_dir`]
+    /// for changes made outside the app (a synced folder, another editor, or
+    /// a direct filesystem operation) and emits `note-created`,
+    /// `note-modified`, `note-deleted`, or `note-renamed` on `app` for each
+    /// one, so windows already open pick up the change without a manual
+    /// refresh. This generalizes the single `refresh-notes` event
+    /// [`crate::commands::save_quick_note`] emits into a real change stream.
+    ///
+    /// Bursts within [`NOTES_WATCH_DEBOUNCE`] are coalesced to one event per
+    /// path, so a bulk operation (or a save's temp-file-then-rename) doesn't
+    /// spam the frontend with intermediate events.
+    ///
+    /// A no-op if the watcher is already running. The frontend should pair
+    /// this with [`FileSystem::stop_notes_watcher`] around the app's own
+    /// writes, to avoid a feedback loop between its own mutation and the
+    /// resulting filesystem event.
+    pub fn start_notes_watcher(&self, app: tauri::AppHandle) -> Result<(), String> {
+        use notify::Watcher;
+
+        let mut guard = self
+            .notes_watcher
+            .lock()
+            .map_err(|_| "Notes watcher lock was poisoned".to_string())?;
+        if guard.is_some() {
+            return Ok(());
+        }
+
+        let notes_dir = self.notes_dir.clone();
+        let (tx, rx) = std::sync::mpsc::channel();
+        let mut watcher = notify::recommended_watcher(tx)
+            .map_err(|e| format!("Failed to start notes watcher: {}", e))?;
+        watcher
+            .watch(&notes_dir, notify::RecursiveMode::Recursive)
+            .map_err(|e| format!("Failed to watch notes directory: {}", e))?;
+
+        std::thread::spawn(move || {
+            for event in rx.iter() {
+                let Ok(event) = event else { continue };
+
+                // Coalesce a burst to its latest event per path, so a save's
+                // temp-file-then-rename (or a bulk operation) emits once per
+                // note instead of once per underlying fs event.
+                let mut latest: std::collections::HashMap<PathBuf, notify::Event> = std::collections::HashMap::new();
+                for path in &event.paths {
+                    latest.insert(path.clone(), event.clone());
+                }
+                while let Ok(Ok(next)) = rx.recv_timeout(NOTES_WATCH_DEBOUNCE) {
+                    for path in &next.paths {
+                        latest.insert(path.clone(), next.clone());
+                    }
+                }
+
+                for event in latest.values() {
+                    emit_note_change_event(&app, &notes_dir, event);
+                }
+            }
+        });
+
+        *guard = Some(watcher);
+        Ok(())
+    }
+
+    /// Stops the watcher started by [`FileSystem::start_notes_watcher`], if
+    /// running - a no-op otherwise. Dropping the underlying watcher
+    /// unsubscribes and closes its event channel, which ends its background
+    /// thread.
+    pub fn stop_notes_watcher(&self) -> Result<(), String> {
+        let mut guard = self
+            .notes_watcher
+            .lock()
+            .map_err(|_| "Notes watcher lock was poisoned".to_string())?;
+        *guard = None;
+        Ok(())
+    }
+
+    /// Searches note content for `query` using BM25 relevance ranking,
+    /// optionally restricted to one folder.
+    ///
+    /// Unlike [`FileSystem::search_notes`]'s match-count ranking, this scores
+    /// each note against the query's tokens with the standard BM25 formula
+    /// (`k1 = `[`BM25_K1`]`, b = `[`BM25_B`]), so a note with a few dense,
+    /// rare-term matches can outrank one with many matches of a common word.
+    /// The backing index is built lazily on first call and kept current
+    /// incrementally by the note-mutating methods, rather than rebuilt here.
+    ///
+    /// # Arguments
+    /// * `query` - The text to search for
+    /// * `folder` - Optional folder name to restrict the search to
+    ///
+    /// # Returns
+    /// * `Ok(Vec<SearchHit>)` - Matching notes, ranked highest-score first
+    /// * `Err(String)` - If `query` is empty or the index can't be built
+    pub fn search_notes_ranked(&self, query: &str, folder: Option<&str>) -> Result<Vec<crate::models::SearchHit>, String> {
+        use crate::models::SearchHit;
+
+        let query_terms = tokenize_for_search(query);
+        if query_terms.is_empty() {
+            return Err("Search query cannot be empty".to_string());
+        }
+
+        let pinned_notes: Vec<String> = self.load_pinned_notes().unwrap_or_default();
+
+        let scores = self.with_search_index(|index| {
+            let note_count = index.doc_lengths.len() as f64;
+            let avg_doc_len = index.avg_doc_len().max(1.0);
+            let mut scores: std::collections::HashMap<String, f64> = std::collections::HashMap::new();
+
+            for term in &query_terms {
+                let Some(postings) = index.postings.get(term) else { continue };
+                let n = postings.len() as f64;
+                let idf = ((note_count - n + 0.5) / (n + 0.5) + 1.0).ln();
+
+                for (note_id, &term_frequency) in postings {
+                    let doc_len = *index.doc_lengths.get(note_id).unwrap_or(&0) as f64;
+                    let term_frequency = term_frequency as f64;
+                    let denom = term_frequency + BM25_K1 * (1.0 - BM25_B + BM25_B * doc_len / avg_doc_len);
+                    let term_score = idf * (term_frequency * (BM25_K1 + 1.0)) / denom;
+                    *scores.entry(note_id.clone()).or_insert(0.0) += term_score;
+                }
+            }
+
+            scores
+        })?;
+
+        let query_lower = query.to_lowercase();
+        let mut hits = Vec::new();
+        for note in self.list_notes(folder)? {
+            let Some(&score) = scores.get(&note.id) else { continue };
+
+            let note_folder = if note.folder.is_empty() { None } else { Some(note.folder.as_str()) };
+            let path = self.get_folder_path(note_folder).join(format!("{}.md", note.id));
+            let content = fs::read_to_string(&path).unwrap_or_default();
+            let content_lower = content.to_lowercase();
+            let (match_count, mut snippet) = count_matches_and_snippet(&content, &content_lower, &query_lower, false);
+            if snippet.is_empty() {
+                snippet = content.lines().next().unwrap_or("").chars().take(80).collect();
+            }
+
+            hits.push(SearchHit {
+                id: note.id.clone(),
+                title: note.title.clone(),
+                folder: note.folder.clone(),
+                match_count,
+                snippet,
+                modified: note.modified,
+                pinned: pinned_notes.contains(&note.id),
+                score,
+            });
+        }
+
+        hits.sort_by(|a, b| b.score.partial_cmp(&a.score).unwrap_or(std::cmp::Ordering::Equal));
+
+        Ok(hits)
+    }
+
+    /// Extracts the title and frontmatter tags from a note file.
+    ///
+    /// The title is the first line of the file after any leading frontmatter
+    /// block, with leading '#' characters removed; see [`title_from_content`]
+    /// and [`parse_frontmatter`].
+    fn extract_note_title_and_tags(&self, path: &Path) -> (Option<String>, Vec<String>) {
+        let Ok(content) = fs::read_to_string(path) else {
+            return (None, Vec::new());
+        };
+        let (tags, body) = parse_frontmatter(&content);
+        (title_from_content(body), tags)
+    }
+
+    /// Resolves [`FileSystem::layers_file`] into a flat key-value map via
+    /// [`resolve_layered_settings`], or an empty map if it doesn't exist -
+    /// layered settings are opt-in, so most installs never have this file.
+    ///
+    /// [`FileSystem::load_pinned_notes`] and [`FileSystem::get_note_order`]
+    /// both read from this; a team can keep a shared base layer (pins and
+    /// ordering everyone starts with) and `%include` it from a local layer
+    /// that overrides or `%unset`s individual keys.
+    fn resolve_layers(&self) -> Result<std::collections::HashMap<String, String>, String> {
+        if !self.layers_file.exists() {
+            return Ok(std::collections::HashMap::new());
+        }
+        resolve_layered_settings(&self.layers_file, &mut Vec::new(), 0)
+    }
+
+    /// Sets `key` to `value` in [`FileSystem::layers_file`] itself, leaving
+    /// every other line (including `%include`s and unrelated keys) alone.
+    ///
+    /// This is how writes stay scoped to the local layer: an included file
+    /// is never modified, only read. An existing `key = ...` line is
+    /// replaced in place; an inherited `%unset key` line is dropped, since
+    /// setting the key locally supersedes it.
+    fn set_local_layer_value(&self, key: &str, value: &str) -> Result<(), String> {
+        let existing = fs::read_to_string(&self.layers_file).unwrap_or_default();
+        let mut lines: Vec<String> = Vec::new();
+        let mut replaced = false;
+
+        for line in existing.lines() {
+            let trimmed = line.trim();
+            if trimmed.strip_prefix("%unset ").map(|k| k.trim()) == Some(key) {
+                continue;
+            }
+            if let Some((line_key, _)) = trimmed.split_once('=') {
+                if line_key.trim() == key {
+                    lines.push(format!("{} = {}", key, value));
+                    replaced = true;
+                    continue;
+                }
+            }
+            lines.push(line.to_string());
+        }
+
+        if !replaced {
+            lines.push(format!("{} = {}", key, value));
+        }
+
+        write_atomic(&self.layers_file, &format!("{}\n", lines.join("\n")))
+    }
+
+    /// Loads pinned notes, preferring the `pinned_notes` key from
+    /// [`FileSystem::resolve_layers`] when a layers file exists, and
+    /// falling back to `config.json`'s `pinned_notes` field otherwise.
+    fn load_pinned_notes(&self) -> Result<Vec<String>, String> {
+        use crate::models::Config;
+
+        let layered = self.resolve_layers()?;
+        if let Some(value) = layered.get("pinned_notes") {
+            return Ok(parse_layered_list(value));
+        }
+
+        if !self.config_file.exists() {
+            return Ok(Vec::new());
+        }
+
+        let content = fs::read_to_string(&self.config_file)
+            .map_err(|e| format!("Failed to read config file: {}", e))?;
+
+        let config: Config = serde_json::from_str(&content)
+            .unwrap_or_default();
+
+        Ok(config.pinned_notes)
+    }
+
+    /// Saves pinned notes to config file, holding [`FileSystem::with_lock`]
+    /// so a concurrent `mded` instance can't interleave its own save.
+    pub fn save_pinned_notes(&self, pinned_notes: Vec<String>) -> Result<(), String> {
+        self.with_lock(|| self.save_pinned_notes_locked(pinned_notes))
+    }
+
+    /// The body of [`FileSystem::save_pinned_notes`], assuming the caller
+    /// already holds the lock - used directly by [`FileSystem::toggle_pin_note`]
+    /// so its load-then-save doesn't try to re-acquire a lock it's already holding.
+    ///
+    /// When [`FileSystem::layers_file`] exists, the write targets only that
+    /// local layer (see [`FileSystem::set_local_layer_value`]); otherwise it
+    /// falls back to `config.json`, same as before layered settings existed.
+    fn save_pinned_notes_locked(&self, pinned_notes: Vec<String>) -> Result<(), String> {
+        use crate::models::Config;
+
+        if self.layers_file.exists() {
+            return self.set_local_layer_value("pinned_notes", &pinned_notes.join(","));
+        }
+
+        let mut config = if self.config_file.exists() {
+            let content = fs::read_to_string(&self.config_file)
+                .map_err(|e| format!("Failed to read config file: {}", e))?;
+            serde_json::from_str(&content).unwrap_or_default()
+        } else {
+            Config::default()
+        };
+
+        config.pinned_notes = pinned_notes;
+
+        let content = serde_json::to_string_pretty(&config)
+            .map_err(|e| format!("Failed to serialize config: {}", e))?;
+
+        write_atomic(&self.config_file, &content)
+    }
+
+    /// Toggles the pin status of a note.
+    ///
+    /// If the note is currently pinned, it will be unpinned.
+    /// If the note is currently unpinned, it will be pinned.
+    ///
+    /// Holds [`FileSystem::with_lock`] for the whole load-then-save so a
+    /// concurrent `mded` instance can't clobber this update.
+    ///
+    /// # Arguments
+    /// * `note_id` - The ID of the note to toggle
+    ///
+    /// # Returns
+    /// * `Ok(bool)` - The new pinned status (true if now pinned, false if now unpinned)
+    /// * `Err(String)` - If the operation fails
+    ///
+    /// # Requirements
+    /// Validates: Requirements 12.1
+    pub fn toggle_pin_note(&self, note_id: &str) -> Result<bool, String> {
+        self.with_lock(|| {
+            let mut pinned_notes = self.load_pinned_notes()?;
+
+            let new_pinned_status = if let Some(pos) = pinned_notes.iter().position(|id| id == note_id) {
+                // Note is currently pinned, remove it
+                pinned_notes.remove(pos);
+                false
+            } else {
+                // Note is not pinned, add it
+                pinned_notes.push(note_id.to_string());
+                true
+            };
+
+            // Save the updated pinned notes list
+            self.save_pinned_notes_locked(pinned_notes)?;
+
+            Ok(new_pinned_status)
+        })
+    }
+
+    /// The literal key `order.<folder>` uses in a layered settings file for
+    /// the root folder, since an empty string isn't a usable directive key.
+    const ROOT_FOLDER_LAYER_KEY: &'static str = "__root__";
+
+    /// Gets the custom note ordering, merging `note-order.json` with any
+    /// `order.<folder>` keys from [`FileSystem::resolve_layers`] - a layered
+    /// override replaces that folder's entry entirely, but a folder absent
+    /// from the layers is still served from `note-order.json`.
+    ///
+    /// Returns a map of folder names to ordered note ID arrays.
+    /// Returns an empty map if neither source has any entries.
+    ///
+    /// # Returns
+    /// * `Ok(HashMap<String, Vec<String>>)` - The note ordering map
+    /// * `Err(String)` - If reading fails
+    ///
+    /// # Requirements
+    /// Validates: Requirements 12.2
+    pub fn get_note_order(&self) -> Result<std::collections::HashMap<String, Vec<String>>, String> {
+        use std::collections::HashMap;
+
+        let mut order: HashMap<String, Vec<String>> = if !self.order_file.exists() {
+            HashMap::new()
+        } else {
+            let content = fs::read_to_string(&self.order_file)
+                .map_err(|e| format!("Failed to read note order file: {}", e))?;
+
+            serde_json::from_str(&content)
+                .map_err(|e| format!("Failed to parse note order file: {}", e))?
+        };
+
+        for (key, value) in self.resolve_layers()? {
+            if let Some(folder) = key.strip_prefix("order.") {
+                let folder_name = if folder == Self::ROOT_FOLDER_LAYER_KEY { String::new() } else { folder.to_string() };
+                order.insert(folder_name, parse_layered_list(&value));
+            }
+        }
+
+        Ok(order)
+    }
+
+    /// Saves the custom note ordering. When [`FileSystem::layers_file`]
+    /// exists, each folder's order is written as its own `order.<folder>`
+    /// key in that local layer (see [`FileSystem::set_local_layer_value`]);
+    /// otherwise this falls back to `note-order.json` whole-file replacement,
+    /// same as before layered settings existed.
+    ///
+    /// # Arguments
+    /// * `order` - A map of folder names to ordered note ID arrays
+    ///
+    /// Holds [`FileSystem::with_lock`] so a concurrent `mded` instance can't
+    /// interleave its own save.
+    ///
+    /// # Returns
+    /// * `Ok(())` - If save was successful
+    /// * `Err(String)` - If saving fails
+    ///
+    /// # Requirements
+    /// Validates: Requirements 12.3
+    pub fn save_note_order(&self, order: std::collections::HashMap<String, Vec<String>>) -> Result<(), String> {
+        self.with_lock(|| {
+            if self.layers_file.exists() {
+                for (folder, ids) in &order {
+                    let folder_key = if folder.is_empty() { Self::ROOT_FOLDER_LAYER_KEY } else { folder.as_str() };
+                    self.set_local_layer_value(&format!("order.{}", folder_key), &ids.join(","))?;
+                }
+                return Ok(());
+            }
+
+            let content = serde_json::to_string_pretty(&order)
+                .map_err(|e| format!("Failed to serialize note order: {}", e))?;
+
+            write_atomic(&self.order_file, &content)
+        })
+    }
+
+    /// How many snapshots [`FileSystem::snapshot_note_version`] keeps per
+    /// note before pruning the oldest.
+    const MAX_VERSIONS_PER_NOTE: usize = 20;
+
+    /// The directory holding `note_id`'s version history, under
+    /// [`FileSystem::versions_dir`].
+    fn note_versions_dir(&self, note_id: &str) -> PathBuf {
+        self.versions_dir.join(note_id)
+    }
+
+    /// Snapshots `content` as a new version of `note_id`, so a destructive
+    /// save that's about to overwrite it has a recovery path via
+    /// [`FileSystem::list_note_versions`]/[`FileSystem::restore_note_version`].
+    /// Each version is named by its nanosecond timestamp, which also gives
+    /// their natural (lexicographic) sort order; pruned to
+    /// [`FileSystem::MAX_VERSIONS_PER_NOTE`] afterwards, oldest first.
+    ///
+    /// Note: to keep this safe to land without a compiler/test loop to
+    /// verify against, each version is stored as a full content snapshot
+    /// rather than a diff against the previous one - simpler to reconstruct
+    /// correctly, at the cost of more disk use than a delta chain would take.
+    ///
+    /// Failures are swallowed the same way [`FileSystem::index_upsert_note`]'s
+    /// are: a missed or unprunable snapshot degrades history, it never
+    /// blocks the save itself.
+    fn snapshot_note_version(&self, note_id: &str, content: &str) {
+        let dir = self.note_versions_dir(note_id);
+        if fs::create_dir_all(&dir).is_err() {
+            return;
+        }
+
+        let version_id = chrono::Utc::now().timestamp_nanos_opt().unwrap_or_default().to_string();
+        let _ = fs::write(dir.join(&version_id), content);
+
+        self.prune_note_versions(&dir);
+    }
+
+    /// Removes the oldest versions in `dir` past [`FileSystem::MAX_VERSIONS_PER_NOTE`].
+    fn prune_note_versions(&self, dir: &Path) {
+        let Ok(entries) = fs::read_dir(dir) else { return };
+        let mut version_ids: Vec<String> = entries
+            .flatten()
+            .filter_map(|e| e.file_name().to_str().map(|s| s.to_string()))
+            .collect();
+        version_ids.sort();
+
+        while version_ids.len() > Self::MAX_VERSIONS_PER_NOTE {
+            let oldest = version_ids.remove(0);
+            let _ = fs::remove_file(dir.join(oldest));
+        }
+    }
+
+    /// Lists `note_id`'s version history, newest first.
+    ///
+    /// # Arguments
+    /// * `note_id` - The note to list versions for
+    /// * `folder` - Unused; versions are keyed only by `note_id`, kept for
+    ///   API symmetry with the rest of the note commands
+    ///
+    /// # Returns
+    /// * `Ok(Vec<VersionInfo>)` - The note's snapshots, or an empty list if
+    ///   it has none yet
+    /// * `Err(String)` - If the version directory exists but can't be read
+    pub fn list_note_versions(&self, note_id: &str, folder: Option<&str>) -> Result<Vec<crate::models::VersionInfo>, String> {
+        let _ = folder;
+        let dir = self.note_versions_dir(note_id);
+        if !dir.exists() {
+            return Ok(Vec::new());
+        }
+
+        let entries = fs::read_dir(&dir)
+            .map_err(|e| format!("Failed to read version history for '{}': {}", note_id, e))?;
+
+        let mut versions = Vec::new();
+        for entry in entries.flatten() {
+            let Some(version_id) = entry.file_name().to_str().map(|s| s.to_string()) else { continue };
+            let Ok(metadata) = entry.metadata() else { continue };
+            let created_at = version_id
+                .parse::<i64>()
+                .ok()
+                .and_then(|nanos| chrono::DateTime::from_timestamp(nanos / 1_000_000_000, (nanos % 1_000_000_000) as u32))
+                .unwrap_or_else(chrono::Utc::now);
+
+            versions.push(crate::models::VersionInfo {
+                version_id,
+                note_id: note_id.to_string(),
+                created_at,
+                size: metadata.len(),
+            });
+        }
+
+        versions.sort_by(|a, b| b.created_at.cmp(&a.created_at));
+        Ok(versions)
+    }
+
+    /// Reads one past revision of a note's content, to preview it before
+    /// deciding whether to restore it.
+    ///
+    /// # Arguments
+    /// * `note_id` - The note the version belongs to
+    /// * `version_id` - A version id from [`FileSystem::list_note_versions`]
+    pub fn read_note_version(&self, note_id: &str, version_id: &str) -> Result<String, String> {
+        let path = self.note_versions_dir(note_id).join(version_id);
+        fs::read_to_string(&path)
+            .map_err(|e| format!("Failed to read version '{}' of note '{}': {}", version_id, note_id, e))
+    }
+
+    /// Rolls `note_id` back to an earlier version.
+    ///
+    /// The current content is snapshotted first (the same way every
+    /// [`FileSystem::save_note`] snapshots what it's about to overwrite), so
+    /// rolling back is itself undoable.
+    ///
+    /// # Arguments
+    /// * `note_id` - The note to restore
+    /// * `version_id` - A version id from [`FileSystem::list_note_versions`]
+    /// * `folder` - Optional folder containing the note
+    pub fn restore_note_version(&self, note_id: &str, version_id: &str, folder: Option<&str>) -> Result<(), String> {
+        let snapshot_content = self.read_note_version(note_id, version_id)?;
+        self.save_note(note_id, &snapshot_content, folder)
+    }
+
+    /// Reads the content of a note.
+    /// 
+    /// # Arguments
+    /// * `note_id` - The ID of the note (filename without extension)
+    /// * `folder` - Optional folder containing the note
+    /// 
+    /// # Returns
+    /// * `Ok(String)` - The note content
+    /// * `Err(String)` - If reading fails
+    /// 
+    /// # Requirements
+    /// Validates: Requirements 11.3
+    pub fn read_note(&self, note_id: &str, folder: Option<&str>) -> Result<String, String> {
+        let file_name = format!("{}.md", note_id);
+        
+        // Validate the note_id
+        validate_path(&self.notes_dir, &file_name)?;
+        
+        // Get the folder path
+        let folder_path = self.get_folder_path(folder);
+        
+        // Validate folder if specified
+        if let Some(f) = folder {
+            if !f.is_empty() {
+                validate_path(&self.notes_dir, f)?;
+            }
+        }
+        
+        let note_path = folder_path.join(&file_name);
+
+        if !self.backend.path_exists(&note_path) {
+            return Err(self.note_not_found_error(note_id, folder));
+        }
+
+        self.backend.file_read_to_string(&note_path)
+            .map_err(|e| format!("Failed to read note '{}': {}", note_id, e))
+    }
+
+    /// Saves content to a note.
+    /// 
+    /// # Arguments
+    /// * `note_id` - The ID of the note (filename without extension)
+    /// * `content` - The content to save
+    /// * `folder` - Optional folder containing the note
+    /// 
+    /// # Returns
+    /// * `Ok(())` - If save was successful
+    /// * `Err(String)` - If saving fails
+    /// 
+    /// # Requirements
+    /// Validates: Requirements 11.4
+    pub fn save_note(&self, note_id: &str, content: &str, folder: Option<&str>) -> Result<(), String> {
+        let file_name = format!("{}.md", note_id);
+        
+        // Validate the note_id
+        validate_path(&self.notes_dir, &file_name)?;
+        
+        // Get the folder path
+        let folder_path = self.get_folder_path(folder);
+        
+        // Validate folder if specified
+        if let Some(f) = folder {
+            if !f.is_empty() {
+                validate_path(&self.notes_dir, f)?;
+            }
+        }
+        
+        // Ensure folder exists
+        if !folder_path.exists() {
+            fs::create_dir_all(&folder_path)
+                .map_err(|e| format!("Failed to create folder: {}", e))?;
+        }
+        
+        let note_path = folder_path.join(&file_name);
+
+        if let Ok(previous_content) = fs::read_to_string(&note_path) {
+            self.snapshot_note_version(note_id, &previous_content);
+        }
+
+        self.atomic_write(&note_path, content.as_bytes())?;
+        self.index_upsert_note(note_id, folder.unwrap_or(""), content);
+        Ok(())
+    }
+
+    /// Replaces a note's frontmatter block with one holding only the new
+    /// `tags` list (or removes the block when `tags` is empty), leaving the
+    /// body content untouched. Other keys in an existing frontmatter block
+    /// are not kept.
+    ///
+    /// # Arguments
+    /// * `note_id` - The ID of the note to tag
+    /// * `tags` - The note's new, complete tag list
+    /// * `folder` - Optional folder containing the note
+    pub fn set_note_tags(&self, note_id: &str, tags: Vec<String>, folder: Option<&str>) -> Result<(), String> {
+        let content = self.read_note(note_id, folder)?;
+        let (_, body) = parse_frontmatter(&content);
+
+        let new_content = if tags.is_empty() {
+            body.to_string()
+        } else {
+            format!("---\ntags: {}\n---\n{}", format_frontmatter_tag_list(&tags), body)
+        };
+
+        self.save_note(note_id, &new_content, folder)
+    }
+
+    /// Creates a new note with a UUID-based filename.
+    /// 
+    /// # Arguments
+    /// * `folder` - Optional folder to create the note in
+    /// 
+    /// # Returns
+    /// * `Ok((String, String))` - Tuple of (note_id, full_path)
+    /// * `Err(String)` - If creation fails
+    /// 
+    /// # Requirements
+    /// Validates: Requirements 11.5
+    pub fn create_note(&self, folder: Option<&str>) -> Result<(String, String), String> {
+        use uuid::Uuid;
+        
+        // Generate UUID-based filename
+        let uuid = Uuid::new_v4();
+        let note_id = format!("note-{}", uuid);
+        let file_name = format!("{}.md", note_id);
+        
+        // Get the folder path
+        let folder_path = self.get_folder_path(folder);
+        
+        // Validate folder if specified
+        if let Some(f) = folder {
+            if !f.is_empty() {
+                validate_path(&self.notes_dir, f)?;
+            }
+        }
+        
+        // Ensure folder exists
+        if !folder_path.exists() {
+            fs::create_dir_all(&folder_path)
+                .map_err(|e| format!("Failed to create folder: {}", e))?;
+        }
+        
+        let note_path = folder_path.join(&file_name);
+        
+        // Create file with default content
+        let default_content = "# New Note\n\n";
+        self.atomic_write(&note_path, default_content.as_bytes())
+            .map_err(|e| format!("Failed to create note: {}", e))?;
+        self.index_upsert_note(&note_id, folder.unwrap_or(""), default_content);
+
+        Ok((note_id, note_path.to_string_lossy().to_string()))
+    }
+
+    /// Deletes a note.
+    ///
+    /// By default the note is moved to the trash and can be restored with
+    /// [`FileSystem::restore_note`]. Pass `permanent: true` to delete it
+    /// immediately instead.
+    ///
+    /// # Arguments
+    /// * `note_id` - The ID of the note to delete
+    /// * `folder` - Optional folder containing the note
+    /// * `permanent` - If `true`, skips the trash and deletes irreversibly
+    ///
+    /// # Returns
+    /// * `Ok(())` - If deletion was successful
+    /// * `Err(String)` - If deletion fails
+    ///
+    /// # Requirements
+    /// Validates: Requirements 11.6
+    pub fn delete_note(&self, note_id: &str, folder: Option<&str>, permanent: bool) -> Result<(), String> {
+        let file_name = format!("{}.md", note_id);
+
+        // Validate the note_id
+        validate_path(&self.notes_dir, &file_name)?;
+
+        // Get the folder path
+        let folder_path = self.get_folder_path(folder);
+
+        // Validate folder if specified
+        if let Some(f) = folder {
+            if !f.is_empty() {
+                validate_path(&self.notes_dir, f)?;
+            }
+        }
+
+        let note_path = folder_path.join(&file_name);
+
+        if !note_path.exists() {
+            return Err(format!("Note '{}' does not exist", note_id));
+        }
+
+        if permanent {
+            // A permanent delete skips the trash entirely, so this is the
+            // version history's last chance to capture the note's final
+            // state before it's gone.
+            if let Ok(content) = self.backend.file_read_to_string(&note_path) {
+                self.snapshot_note_version(note_id, &content);
+            }
+            self.backend.file_remove(&note_path)
+                .map_err(|e| format!("Failed to delete note '{}': {}", note_id, e))?;
+            self.index_remove_note(note_id);
+            return Ok(());
+        }
+
+        let flattened_folder = folder.unwrap_or("").replace('/', "_");
+        let trash_id = if flattened_folder.is_empty() {
+            format!("{}-{}", note_id, chrono::Utc::now().timestamp_millis())
+        } else {
+            format!("{}__{}-{}", flattened_folder, note_id, chrono::Utc::now().timestamp_millis())
+        };
+        let trash_path = self.trash_dir.join(&trash_id);
+        fs::rename(&note_path, &trash_path)
+            .map_err(|e| format!("Failed to move note '{}' to trash: {}", note_id, e))?;
+
+        let mut entries = self.load_trash_index()?;
+        entries.push(crate::models::TrashEntry {
+            trash_id,
+            kind: crate::models::TrashItemKind::Note,
+            original_name: note_id.to_string(),
+            original_folder: folder.filter(|f| !f.is_empty()).map(String::from),
+            deleted_at: chrono::Utc::now(),
+        });
+        self.save_trash_index(&entries)?;
+        self.index_remove_note(note_id);
+
+        Ok(())
+    }
+
+    /// Renames a note.
+    /// 
+    /// # Arguments
+    /// * `note_id` - The current ID of the note
+    /// * `new_name` - The new name for the note (without .md extension)
+    /// * `folder` - Optional folder containing the note
+    /// 
+    /// # Returns
+    /// * `Ok(String)` - The new note ID
+    /// * `Err(String)` - If renaming fails
+    /// 
+    /// # Requirements
+    /// Validates: Requirements 11.7
+    pub fn rename_note(&self, note_id: &str, new_name: &str, folder: Option<&str>) -> Result<String, String> {
+        let old_file_name = format!("{}.md", note_id);
+        let new_file_name = format!("{}.md", new_name);
+        
+        // Validate both names
+        validate_path(&self.notes_dir, &old_file_name)?;
+        validate_path(&self.notes_dir, &new_file_name)?;
+        
+        // Get the folder path
+        let folder_path = self.get_folder_path(folder);
+        
+        // Validate folder if specified
+        if let Some(f) = folder {
+            if !f.is_empty() {
+                validate_path(&self.notes_dir, f)?;
+            }
+        }
+        
+        let old_path = folder_path.join(&old_file_name);
+        let new_path = folder_path.join(&new_file_name);
+        
+        if !old_path.exists() {
+            return Err(format!("Note '{}' does not exist", note_id));
+        }
+        
+        if new_path.exists() {
+            return Err(format!("Note '{}' already exists", new_name));
+        }
+        
+        fs::rename(&old_path, &new_path)
+            .map_err(|e| format!("Failed to rename note '{}' to '{}': {}", note_id, new_name, e))?;
+
+        let old_versions_dir = self.note_versions_dir(note_id);
+        if old_versions_dir.exists() {
+            let _ = fs::rename(&old_versions_dir, self.note_versions_dir(new_name));
+        }
+
+        if let Ok(content) = fs::read_to_string(&new_path) {
+            self.index_rename_note(note_id, new_name, folder.unwrap_or(""), &content);
+        }
+
+        Ok(new_name.to_string())
+    }
+
+    /// Moves a note from one folder to another.
+    /// 
+    /// # Arguments
+    /// * `note_id` - The ID of the note to move
+    /// * `from_folder` - The source folder
+    /// * `to_folder` - The target folder
+    /// 
+    /// # Returns
+    /// * `Ok(())` - If move was successful
+    /// * `Err(String)` - If moving fails
+    /// 
+    /// # Requirements
+    /// Validates: Requirements 11.8
+    pub fn move_note(&self, note_id: &str, from_folder: &str, to_folder: &str) -> Result<(), String> {
+        let file_name = format!("{}.md", note_id);
+        
+        // Validate the note_id
+        validate_path(&self.notes_dir, &file_name)?;
+        
+        // Validate folders
+        let from_path = if from_folder.is_empty() || from_folder == "All Notes" {
+            self.notes_dir.clone()
+        } else {
+            validate_path(&self.notes_dir, from_folder)?;
+            self.notes_dir.join(from_folder)
+        };
+        
+        let to_path = if to_folder.is_empty() || to_folder == "All Notes" {
+            self.notes_dir.clone()
+        } else {
+            validate_path(&self.notes_dir, to_folder)?;
+            self.notes_dir.join(to_folder)
+        };
+        
+        let source_file = from_path.join(&file_name);
+        let target_file = to_path.join(&file_name);
+        
+        if !source_file.exists() {
+            return Err(format!("Note '{}' does not exist in folder '{}'", note_id, from_folder));
+        }
+        
+        // Ensure target folder exists
+        if !to_path.exists() {
+            fs::create_dir_all(&to_path)
+                .map_err(|e| format!("Failed to create target folder: {}", e))?;
+        }
+        
+        if target_file.exists() {
+            return Err(format!("Note '{}' already exists in folder '{}'", note_id, to_folder));
+        }
+        
+        fs::rename(&source_file, &target_file)
+            .map_err(|e| format!("Failed to move note '{}': {}", note_id, e))
+    }
+
+    // ==================== Screenshot Operations ====================
+
+    /// Saves a screenshot from base64 PNG data.
+    /// 
+    /// Decodes the base64 data and saves it to the assets directory with a unique
+    /// timestamp-based filename.
+    /// 
+    /// # Arguments
+    /// * `base64_data` - The base64-encoded PNG image data (may include data URL prefix)
+    /// 
+    /// # Returns
+    /// * `Ok((String, String))` - Tuple of (image_id, absolute_path)
+    /// * `Err(String)` - If decoding or saving fails
+    /// 
+    /// # Requirements
+    /// Validates: Requirements 14.1, 14.2
+    pub fn save_screenshot(&self, base64_data: &str) -> Result<(String, String), String> {
+        use base64::Engine;
+        use chrono::Utc;
+        
+        // Strip data URL prefix if present (e.g., "data:image/png;base64,")
+        let base64_content = if let Some(pos) = base64_data.find(",") {
+            &base64_data[pos + 1..]
+        } else {
+            base64_data
+        };
+        
+        // Decode base64 data
+        let image_data = base64::engine::general_purpose::STANDARD
+            .decode(base64_content)
+            .map_err(|e| format!("Failed to decode base64 image data: {}", e))?;
+        
+        // Validate that we have some data
+        if image_data.is_empty() {
+            return Err("Image data is empty".to_string());
+        }
+        
+        // Generate unique filename with timestamp
+        let timestamp = Utc::now().format("%Y%m%d%H%M%S%3f").to_string();
+        let image_id = format!("screenshot-{}", timestamp);
+        let file_name = format!("{}.png", image_id);
+        
+        // Ensure assets directory exists
+        if !self.assets_dir.exists() {
+            fs::create_dir_all(&self.assets_dir)
+                .map_err(|e| format!("Failed to create assets directory: {}", e))?;
+        }
+        
+        // Construct the full path
+        let file_path = self.assets_dir.join(&file_name);
+        
+        // Write the image data
+        self.atomic_write(&file_path, &image_data)
+            .map_err(|e| format!("Failed to save screenshot: {}", e))?;
+
+        Ok((image_id, file_path.to_string_lossy().to_string()))
+    }
+
+    /// Saves a base64-encoded screenshot, content-addressed by the SHA-256
+    /// hash of its decoded bytes rather than a timestamp.
+    ///
+    /// Pasting the same image twice reuses the existing asset file instead
+    /// of writing a duplicate - the hash *is* the filename, so a matching
+    /// file on disk means the bytes are already stored.
+    ///
+    /// # Arguments
+    /// * `base64_data` - The base64-encoded PNG image data (may include data URL prefix)
+    ///
+    /// # Returns
+    /// * `Ok((image_id, path, was_new))` - `was_new` is `false` when an
+    ///   asset with the same hash already existed and the write was skipped
+    /// * `Err(String)` - If decoding or saving fails
+    pub fn save_screenshot_dedup(&self, base64_data: &str) -> Result<(String, String, bool), String> {
+        use base64::Engine;
+        use sha2::{Digest, Sha256};
+
+        let base64_content = if let Some(pos) = base64_data.find(',') {
+            &base64_data[pos + 1..]
+        } else {
+            base64_data
+        };
+
+        let image_data = base64::engine::general_purpose::STANDARD
+            .decode(base64_content)
+            .map_err(|e| format!("Failed to decode base64 image data: {}", e))?;
+
+        if image_data.is_empty() {
+            return Err("Image data is empty".to_string());
+        }
+
+        let mut hasher = Sha256::new();
+        hasher.update(&image_data);
+        let image_id = format!("{:x}", hasher.finalize());
+        let file_name = format!("{}.png", image_id);
+
+        if !self.assets_dir.exists() {
+            fs::create_dir_all(&self.assets_dir)
+                .map_err(|e| format!("Failed to create assets directory: {}", e))?;
+        }
+
+        let file_path = self.assets_dir.join(&file_name);
+        if file_path.exists() {
+            return Ok((image_id, file_path.to_string_lossy().to_string(), false));
+        }
+
+        self.atomic_write(&file_path, &image_data)
+            .map_err(|e| format!("Failed to save screenshot: {}", e))?;
+
+        Ok((image_id, file_path.to_string_lossy().to_string(), true))
+    }
+
+    /// Removes every asset file not referenced by any note's content,
+    /// mirroring how a content store reclaims unreachable blobs.
+    ///
+    /// A file is "referenced" if its filename appears anywhere in any
+    /// note's markdown, which is how notes embed images saved via
+    /// [`FileSystem::save_screenshot_dedup`].
+    ///
+    /// # Returns
+    /// * `Ok(usize)` - The number of asset files removed
+    /// * `Err(String)` - If the assets or notes directory can't be read
+    pub fn gc_assets(&self) -> Result<usize, String> {
+        if !self.assets_dir.exists() {
+            return Ok(0);
+        }
+
+        let asset_names: Vec<String> = fs::read_dir(&self.assets_dir)
+            .map_err(|e| format!("Failed to read assets directory: {}", e))?
+            .filter_map(|e| e.ok())
+            .filter(|e| e.path().is_file())
+            .map(|e| e.file_name().to_string_lossy().to_string())
+            .collect();
+
+        let mut referenced = std::collections::HashSet::new();
+        self.collect_referenced_assets(&self.notes_dir, &asset_names, &mut referenced)?;
+        if self.versions_dir.exists() {
+            self.collect_referenced_assets_in_versions(&asset_names, &mut referenced)?;
+        }
+        if self.trash_dir.exists() {
+            self.collect_referenced_assets_in_trash(&self.trash_dir, &asset_names, &mut referenced)?;
+        }
+
+        let mut removed = 0;
+        for name in asset_names {
+            if !referenced.contains(&name) {
+                fs::remove_file(self.assets_dir.join(&name))
+                    .map_err(|e| format!("Failed to remove unreferenced asset '{}': {}", name, e))?;
+                removed += 1;
+            }
+        }
+
+        Ok(removed)
+    }
+
+    /// Recursively scans `.md` files under `dir` for occurrences of any name
+    /// in `asset_names`, adding each match to `referenced`.
+    fn collect_referenced_assets(
+        &self,
+        dir: &Path,
+        asset_names: &[String],
+        referenced: &mut std::collections::HashSet<String>,
+    ) -> Result<(), String> {
+        let entries = fs::read_dir(dir).map_err(|e| format!("Failed to read '{}': {}", dir.display(), e))?;
+        for entry in entries {
+            let entry = entry.map_err(|e| format!("Failed to read directory entry: {}", e))?;
+            let path = entry.path();
+
+            if path.is_dir() {
+                self.collect_referenced_assets(&path, asset_names, referenced)?;
+            } else if path.extension().is_some_and(|e| e == "md") {
+                let Ok(content) = fs::read_to_string(&path) else { continue };
+                for name in asset_names {
+                    if referenced.contains(name) {
+                        continue;
+                    }
+                    if content.contains(name.as_str()) {
+                        referenced.insert(name.clone());
+                    }
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Scans every stored snapshot under [`FileSystem::versions_dir`] for
+    /// occurrences of any name in `asset_names`, adding each match to
+    /// `referenced`.
+    ///
+    /// A version snapshot (see [`FileSystem::snapshot_note_version`]) is a
+    /// full content file named by timestamp with no `.md` extension, so it
+    /// can't reuse [`FileSystem::collect_referenced_assets`]'s
+    /// extension-filtered walk. Without this, [`FileSystem::gc_assets`]
+    /// would delete an asset still embedded in an old version, and
+    /// restoring that version afterwards would show a broken image.
+    fn collect_referenced_assets_in_versions(
+        &self,
+        asset_names: &[String],
+        referenced: &mut std::collections::HashSet<String>,
+    ) -> Result<(), String> {
+        let note_dirs = fs::read_dir(&self.versions_dir)
+            .map_err(|e| format!("Failed to read '{}': {}", self.versions_dir.display(), e))?;
+
+        for note_dir in note_dirs {
+            let note_dir = note_dir.map_err(|e| format!("Failed to read directory entry: {}", e))?;
+            let path = note_dir.path();
+            if !path.is_dir() {
+                continue;
+            }
+
+            let versions = fs::read_dir(&path).map_err(|e| format!("Failed to read '{}': {}", path.display(), e))?;
+            for version in versions {
+                let version = version.map_err(|e| format!("Failed to read directory entry: {}", e))?;
+                let version_path = version.path();
+                if !version_path.is_file() {
+                    continue;
+                }
+
+                let Ok(content) = fs::read_to_string(&version_path) else { continue };
+                for name in asset_names {
+                    if referenced.contains(name) {
+                        continue;
+                    }
+                    if content.contains(name.as_str()) {
+                        referenced.insert(name.clone());
+                    }
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Recursively scans every file under [`FileSystem::trash_dir`] for
+    /// occurrences of any name in `asset_names`, adding each match to
+    /// `referenced`.
+    ///
+    /// A soft-deleted note ([`FileSystem::delete_note`]) is moved into
+    /// `trash_dir` under a timestamped `trash_id` with no `.md` extension,
+    /// so (like a `.versions` snapshot) it can't reuse
+    /// [`FileSystem::collect_referenced_assets`]'s extension-filtered walk.
+    /// A soft-deleted folder keeps its nested notes' original filenames, so
+    /// this recurses into directories the same way. Skips `index.json`
+    /// ([`FileSystem::trash_index_file`]), which holds trash metadata, not
+    /// note content. Without this, [`FileSystem::gc_assets`] would delete an
+    /// asset referenced only by a trashed note, and restoring that note
+    /// afterwards would show a broken image.
+    fn collect_referenced_assets_in_trash(
+        &self,
+        dir: &Path,
+        asset_names: &[String],
+        referenced: &mut std::collections::HashSet<String>,
+    ) -> Result<(), String> {
+        let entries = fs::read_dir(dir).map_err(|e| format!("Failed to read '{}': {}", dir.display(), e))?;
+        for entry in entries {
+            let entry = entry.map_err(|e| format!("Failed to read directory entry: {}", e))?;
+            let path = entry.path();
+
+            if path.is_dir() {
+                self.collect_referenced_assets_in_trash(&path, asset_names, referenced)?;
+            } else if path != self.trash_index_file {
+                let Ok(content) = fs::read_to_string(&path) else { continue };
+                for name in asset_names {
+                    if referenced.contains(name) {
+                        continue;
+                    }
+                    if content.contains(name.as_str()) {
+                        referenced.insert(name.clone());
+                    }
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Packages the whole vault - every note (preserving folder structure
+    /// and frontmatter tags), `note-order.json`, the layered-settings/pin
+    /// state files, and optionally the screenshot assets - into a single
+    /// portable archive file at `dest_path`. See [`FileSystem::import_vault`]
+    /// for the inverse.
+    ///
+    /// Emits `export-progress` (`{"current": usize, "total": usize}`) on
+    /// `app` as each file is packaged, so a large vault's frontend can show
+    /// a progress bar instead of appearing to hang.
+    ///
+    /// # Arguments
+    /// * `dest_path` - Where to write the archive file
+    /// * `include_assets` - If `true`, also packages every file under [`FileSystem::assets_dir`]
+    /// * `app` - Used to emit progress events
+    pub fn export_vault(&self, dest_path: &str, include_assets: bool, app: tauri::AppHandle) -> Result<(), String> {
+        use tauri::Emitter;
+
+        let mut note_paths: Vec<PathBuf> = Vec::new();
+        collect_files_recursive(&self.notes_dir, &mut note_paths)?;
+
+        let mut asset_paths: Vec<PathBuf> = Vec::new();
+        if include_assets && self.assets_dir.exists() {
+            collect_files_recursive(&self.assets_dir, &mut asset_paths)?;
+        }
+
+        let folders = self.list_folders()?.into_iter().map(|f| f.path).collect();
+        let manifest = crate::models::VaultManifest {
+            schema_version: VAULT_ARCHIVE_SCHEMA_VERSION,
+            exported_at: chrono::Utc::now(),
+            folders,
+            note_count: note_paths.len(),
+            includes_assets: include_assets,
+        };
+        let manifest_json = serde_json::to_vec(&manifest)
+            .map_err(|e| format!("Failed to serialize vault manifest: {}", e))?;
+
+        let side_files: Vec<(&PathBuf, &str)> = vec![
+            (&self.order_file, "note-order.json"),
+            (&self.config_file, "config.json"),
+            (&self.layers_file, "mded.layers"),
+        ];
+        let side_files: Vec<(&PathBuf, &str)> = side_files.into_iter().filter(|(path, _)| path.exists()).collect();
+
+        let total = 1 + note_paths.len() + side_files.len() + asset_paths.len();
+        let mut current = 0usize;
+        let mut emit_progress = |current: usize| {
+            let _ = app.emit("export-progress", serde_json::json!({ "current": current, "total": total }));
+        };
+
+        let file = fs::File::create(dest_path).map_err(|e| format!("Failed to create archive '{}': {}", dest_path, e))?;
+        let mut writer = std::io::BufWriter::new(file);
+        writer
+            .write_all(VAULT_ARCHIVE_MAGIC)
+            .map_err(|e| format!("Failed to write archive header: {}", e))?;
+
+        write_archive_entry(&mut writer, "manifest.json", &manifest_json)?;
+        current += 1;
+        emit_progress(current);
+
+        for path in &note_paths {
+            let relative = path.strip_prefix(&self.notes_dir).unwrap_or(path);
+            let archive_path = format!("notes/{}", relative.to_string_lossy().replace('\\', "/"));
+            let content = fs::read(path).map_err(|e| format!("Failed to read '{}': {}", path.display(), e))?;
+            write_archive_entry(&mut writer, &archive_path, &content)?;
+            current += 1;
+            emit_progress(current);
+        }
+
+        for (path, archive_path) in &side_files {
+            let content = fs::read(path).map_err(|e| format!("Failed to read '{}': {}", path.display(), e))?;
+            write_archive_entry(&mut writer, archive_path, &content)?;
+            current += 1;
+            emit_progress(current);
+        }
+
+        if include_assets {
+            for path in &asset_paths {
+                let relative = path.strip_prefix(&self.assets_dir).unwrap_or(path);
+                let archive_path = format!("assets/{}", relative.to_string_lossy().replace('\\', "/"));
+                let content = fs::read(path).map_err(|e| format!("Failed to read '{}': {}", path.display(), e))?;
+                write_archive_entry(&mut writer, &archive_path, &content)?;
+                current += 1;
+                emit_progress(current);
+            }
+        }
+
+        writer.flush().map_err(|e| format!("Failed to finalize archive: {}", e))?;
+        Ok(())
+    }
+
+    /// Reads back an archive written by [`FileSystem::export_vault`] into
+    /// the current vault.
+    ///
+    /// With `merge: false` ("replace"), an archived note or asset overwrites
+    /// any file at the same path in this vault. With `merge: true`, a
+    /// colliding note or asset is instead written under a suffixed name (see
+    /// [`FileSystem::unique_destination`]), and every reference to a renamed
+    /// asset inside an imported note's content is rewritten so embedded
+    /// screenshots saved by [`FileSystem::save_screenshot`] keep resolving.
+    ///
+    /// `config.json`/`mded.layers` travel with the archive but are never
+    /// applied on import - they describe *this install's* settings (window
+    /// state, shortcuts, layered pin/order overrides) as much as the vault's
+    /// content, so importing them wholesale would clobber the current
+    /// install rather than just bring in its notes.
+    ///
+    /// Emits `import-progress` (`{"current": usize, "total": usize}`) on
+    /// `app` as each file is written.
+    ///
+    /// # Arguments
+    /// * `archive_path` - Path to the archive file
+    /// * `merge` - If `false`, colliding paths are overwritten; if `true`, they're renamed instead
+    /// * `app` - Used to emit progress events
+    pub fn import_vault(&self, archive_path: &str, merge: bool, app: tauri::AppHandle) -> Result<(), String> {
+        use tauri::Emitter;
+
+        let bytes = fs::read(archive_path)
+            .map_err(|e| format!("Failed to read archive '{}': {}", archive_path, e))?;
+        let entries = read_archive_entries(&bytes)?;
+
+        let manifest_content = entries
+            .iter()
+            .find(|(path, _)| path == "manifest.json")
+            .map(|(_, content)| content)
+            .ok_or_else(|| "Archive is missing its manifest".to_string())?;
+        let manifest: crate::models::VaultManifest = serde_json::from_slice(manifest_content)
+            .map_err(|e| format!("Failed to parse vault manifest: {}", e))?;
+        if manifest.schema_version != VAULT_ARCHIVE_SCHEMA_VERSION {
+            return Err(format!(
+                "Archive schema version {} is not supported (expected {})",
+                manifest.schema_version, VAULT_ARCHIVE_SCHEMA_VERSION
+            ));
+        }
+
+        self.ensure_directories()?;
+
+        // Asset renames accumulated up front so every note's content can be
+        // rewritten before being written, keeping embedded references valid.
+        //
+        // Every archive-supplied path is run through the same containment
+        // check (`validate_notes_folder_path`/`validate_assets_path`) every
+        // other note/asset-writing method in this file uses before it's
+        // joined onto `notes_dir`/`assets_dir` - an archive is untrusted
+        // input, and a crafted entry like `assets/../../.ssh/authorized_keys`
+        // must not be able to write outside the vault.
+        let mut asset_renames: std::collections::HashMap<String, String> = std::collections::HashMap::new();
+        if merge {
+            for (archive_path_str, _) in &entries {
+                if let Some(asset_name) = archive_path_str.strip_prefix("assets/") {
+                    self.validate_assets_path(asset_name)
+                        .map_err(|e| format!("Archive entry '{}' is invalid: {}", archive_path_str, e))?;
+                    let destination = self.assets_dir.join(asset_name);
+                    if destination.exists() {
+                        let unique = Self::unique_destination(&self.assets_dir, Path::new(asset_name));
+                        if let Some(unique_name) = unique.file_name().and_then(|n| n.to_str()) {
+                            asset_renames.insert(asset_name.to_string(), unique_name.to_string());
+                        }
+                    }
+                }
+            }
+        }
+
+        let total = entries.len();
+        let mut current = 0usize;
+
+        for (archive_path_str, content) in &entries {
+            current += 1;
+
+            if archive_path_str == "manifest.json"
+                || archive_path_str == "config.json"
+                || archive_path_str == "mded.layers"
+            {
+                let _ = app.emit("import-progress", serde_json::json!({ "current": current, "total": total }));
+                continue;
+            }
+
+            if let Some(relative) = archive_path_str.strip_prefix("notes/") {
+                let validated = self
+                    .validate_notes_folder_path(relative)
+                    .map_err(|e| format!("Archive entry '{}' is invalid: {}", archive_path_str, e))?;
+
+                let mut note_content = String::from_utf8_lossy(content).to_string();
+                for (old_name, new_name) in &asset_renames {
+                    note_content = note_content.replace(old_name.as_str(), new_name.as_str());
+                }
+
+                let destination = if merge && validated.exists() {
+                    Self::unique_destination(
+                        validated.parent().unwrap_or(&self.notes_dir),
+                        Path::new(validated.file_name().unwrap_or_default()),
+                    )
+                } else {
+                    validated
+                };
+                if let Some(parent) = destination.parent() {
+                    fs::create_dir_all(parent)
+                        .map_err(|e| format!("Failed to create folder for '{}': {}", relative, e))?;
+                }
+                fs::write(&destination, note_content.as_bytes())
+                    .map_err(|e| format!("Failed to write '{}': {}", destination.display(), e))?;
+            } else if let Some(asset_name) = archive_path_str.strip_prefix("assets/") {
+                let final_name = asset_renames.get(asset_name).cloned().unwrap_or_else(|| asset_name.to_string());
+                let validated = self
+                    .validate_assets_path(&final_name)
+                    .map_err(|e| format!("Archive entry '{}' is invalid: {}", archive_path_str, e))?;
+                fs::create_dir_all(&self.assets_dir)
+                    .map_err(|e| format!("Failed to create assets directory: {}", e))?;
+                fs::write(&validated, content)
+                    .map_err(|e| format!("Failed to write '{}': {}", validated.display(), e))?;
+            } else if archive_path_str == "note-order.json" && (!merge || !self.order_file.exists()) {
+                fs::write(&self.order_file, content)
+                    .map_err(|e| format!("Failed to write note order: {}", e))?;
+            }
+
+            let _ = app.emit("import-progress", serde_json::json!({ "current": current, "total": total }));
+        }
+
+        self.rebuild_index()?;
+        let _ = self.rebuild_search_index();
+
+        Ok(())
+    }
+
+    /// Returns the absolute path to the assets directory.
+    ///
+    /// # Returns
+    /// The absolute path to the assets directory as a string
+    ///
+    /// # Requirements
+    /// Validates: Requirements 14.3
+    pub fn get_assets_path(&self) -> String {
+        self.assets_dir.to_string_lossy().to_string()
+    }
+
+    /// Saves a clipboard image captured as a raw RGBA buffer.
+    ///
+    /// Encodes the buffer to PNG and writes it to the assets directory with a
+    /// unique timestamp-based filename, alongside screenshots saved via
+    /// `save_screenshot`.
+    ///
+    /// # Arguments
+    /// * `rgba` - The raw RGBA8 pixel buffer
+    /// * `width` - The image width in pixels
+    /// * `height` - The image height in pixels
+    ///
+    /// # Returns
+    /// * `Ok((String, String))` - Tuple of (image_id, absolute_path)
+    /// * `Err(String)` - If the buffer doesn't match the given dimensions or saving fails
+    pub fn save_clipboard_image(&self, rgba: &[u8], width: u32, height: u32) -> Result<(String, String), String> {
+        use chrono::Utc;
+
+        let expected_len = (width as usize) * (height as usize) * 4;
+        if rgba.len() != expected_len {
+            return Err(format!(
+                "Clipboard image buffer length {} does not match {}x{} RGBA",
+                rgba.len(),
+                width,
+                height
+            ));
+        }
+
+        // Generate unique filename with timestamp
+        let timestamp = Utc::now().format("%Y%m%d%H%M%S%3f").to_string();
+        let image_id = format!("clipboard-{}", timestamp);
+        let file_name = format!("{}.png", image_id);
+
+        // Ensure assets directory exists
+        if !self.assets_dir.exists() {
+            fs::create_dir_all(&self.assets_dir)
+                .map_err(|e| format!("Failed to create assets directory: {}", e))?;
+        }
+
+        let file_path = self.assets_dir.join(&file_name);
+
+        let file = fs::File::create(&file_path)
+            .map_err(|e| format!("Failed to create image file: {}", e))?;
+        let mut encoder = png::Encoder::new(std::io::BufWriter::new(file), width, height);
+        encoder.set_color(png::ColorType::Rgba);
+        encoder.set_depth(png::BitDepth::Eight);
+        let mut writer = encoder
+            .write_header()
+            .map_err(|e| format!("Failed to write PNG header: {}", e))?;
+        writer
+            .write_image_data(rgba)
+            .map_err(|e| format!("Failed to encode clipboard image: {}", e))?;
+
+        Ok((image_id, file_path.to_string_lossy().to_string()))
+    }
+
+    /// Computes the relative path from a note's folder to a file in the
+    /// assets directory, for embedding as a Markdown image link.
+    ///
+    /// # Arguments
+    /// * `folder` - The note's folder, or `None` for the root notes directory
+    /// * `asset_file_name` - The file's name within the assets directory
+    ///
+    /// # Returns
+    /// A forward-slash-separated relative path, e.g. `"../assets/clipboard-123.png"`
+    pub fn relative_asset_path(&self, folder: Option<&str>, asset_file_name: &str) -> String {
+        let note_dir = self.get_folder_path(folder);
+        let depth = note_dir
+            .strip_prefix(&self.base_dir)
+            .map(|p| p.components().count())
+            .unwrap_or(0);
+
+        let mut relative = PathBuf::new();
+        for _ in 0..depth {
+            relative.push("..");
+        }
+        relative.push("assets");
+        relative.push(asset_file_name);
+
+        relative.to_string_lossy().replace('\\', "/")
+    }
+
+    // ==================== External File Operations ====================
+
+    /// Reads an external markdown file.
+    /// 
+    /// Validates that the file has a .md extension and reads its content.
+    /// 
+    /// # Arguments
+    /// * `file_path` - The absolute path to the file
+    /// 
+    /// # Returns
+    /// * `Ok((String, String, String))` - Tuple of (content, file_name, absolute_path)
+    /// * `Err(String)` - If validation fails or reading fails
+    /// 
+    /// # Requirements
+    /// Validates: Requirements 15.1, 15.2, 15.3
+    pub fn read_external_file(&self, file_path: &str) -> Result<(String, String, String), String> {
+        let path = std::path::Path::new(file_path);
+        
+        // Validate .md extension
+        match path.extension() {
+            Some(ext) if ext == "md" => {}
+            _ => return Err("File must have .md extension".to_string()),
+        }
+        
+        // Check if file exists
+        if !path.exists() {
+            return Err(format!("File does not exist: {}", file_path));
+        }
+        
+        // Check if it's a file (not a directory)
+        if !path.is_file() {
+            return Err(format!("Path is not a file: {}", file_path));
+        }
+        
+        // Read the file content
+        let content = fs::read_to_string(path)
+            .map_err(|e| format!("Failed to read file: {}", e))?;
+        
+        // Get the file name
+        let file_name = path
+            .file_name()
+            .map(|n| n.to_string_lossy().to_string())
+            .unwrap_or_else(|| "unknown.md".to_string());
+        
+        // Get the absolute path
+        let absolute_path = path
+            .canonicalize()
+            .map(|p| p.to_string_lossy().to_string())
+            .unwrap_or_else(|_| file_path.to_string());
+        
+        Ok((content, file_name, absolute_path))
+    }
+
+    /// Like [`FileSystem::read_external_file`], but a file whose extension
+    /// isn't Markdown-like (`.md`/`.markdown`/`.mdown`/`.mkd`) isn't
+    /// rejected outright - instead its first [`SNIFF_SAMPLE_BYTES`] are
+    /// content-sniffed via [`looks_like_markdown_text`], so an extensionless
+    /// `README` or `NOTES` file can still be opened, while a PNG/JPEG/PDF/ZIP/ELF
+    /// that happens to be named `notes.txt` is still firmly rejected.
+    ///
+    /// # Arguments
+    /// * `file_path` - The absolute path to the file
+    ///
+    /// # Returns
+    /// * `Ok((String, String, String))` - Tuple of (content, file_name, absolute_path)
+    /// * `Err(String)` - If the file doesn't exist, isn't a file, or doesn't look like Markdown
+    ///
+    /// # Requirements
+    /// Validates: Requirements 15.1, 15.2, 15.3
+    pub fn read_external_file_sniffed(&self, file_path: &str) -> Result<(String, String, String), String> {
+        let path = std::path::Path::new(file_path);
+
+        if !path.exists() {
+            return Err(format!("File does not exist: {}", file_path));
+        }
+
+        if !path.is_file() {
+            return Err(format!("Path is not a file: {}", file_path));
+        }
+
+        let has_markdown_extension = path
+            .extension()
+            .map(|ext| matches!(ext.to_string_lossy().to_lowercase().as_str(), "md" | "markdown" | "mdown" | "mkd"))
+            .unwrap_or(false);
+
+        if !has_markdown_extension {
+            let mut file = fs::File::open(path).map_err(|e| format!("Failed to open file: {}", e))?;
+            let mut sample = vec![0u8; SNIFF_SAMPLE_BYTES];
+            let bytes_read = file.read(&mut sample).map_err(|e| format!("Failed to read file: {}", e))?;
+            sample.truncate(bytes_read);
+
+            if !looks_like_markdown_text(&sample) {
+                return Err(format!("'{}' does not look like a Markdown file", file_path));
+            }
+        }
+
+        let content = fs::read_to_string(path)
+            .map_err(|e| format!("Failed to read file: {}", e))?;
+
+        let file_name = path
+            .file_name()
+            .map(|n| n.to_string_lossy().to_string())
+            .unwrap_or_else(|| "unknown".to_string());
+
+        let absolute_path = path
+            .canonicalize()
+            .map(|p| p.to_string_lossy().to_string())
+            .unwrap_or_else(|_| file_path.to_string());
+
+        Ok((content, file_name, absolute_path))
+    }
+
+    /// Like [`FileSystem::read_external_file`], but also returns a
+    /// [`Checksum`] of the file's bytes, computed with `algorithm` in the
+    /// same streaming pass that reads the content.
+    ///
+    /// The motivating use case is the editor warning "this file changed on
+    /// disk since you loaded it" before an overwrite: keep the returned
+    /// checksum alongside the loaded content, then re-check it with
+    /// [`FileSystem::verify_checksum`] right before saving.
+    ///
+    /// # Arguments
+    /// * `file_path` - The absolute path to the file
+    /// * `algorithm` - Which digest to compute
+    ///
+    /// # Returns
+    /// * `Ok((String, String, String, Checksum))` - Tuple of (content, file_name, absolute_path, checksum)
+    /// * `Err(String)` - If validation fails, reading fails, or the file isn't valid UTF-8
+    pub fn read_external_file_with_checksum(
+        &self,
+        file_path: &str,
+        algorithm: ChecksumAlgorithm,
+    ) -> Result<(String, String, String, Checksum), String> {
+        let path = std::path::Path::new(file_path);
+
+        match path.extension() {
+            Some(ext) if ext == "md" => {}
+            _ => return Err("File must have .md extension".to_string()),
+        }
+
+        if !path.exists() {
+            return Err(format!("File does not exist: {}", file_path));
+        }
+
+        if !path.is_file() {
+            return Err(format!("Path is not a file: {}", file_path));
+        }
+
+        let (bytes, checksum) = read_file_with_checksum(path, algorithm)?;
+        let content = String::from_utf8(bytes).map_err(|e| format!("File is not valid UTF-8: {}", e))?;
+
+        let file_name = path
+            .file_name()
+            .map(|n| n.to_string_lossy().to_string())
+            .unwrap_or_else(|| "unknown.md".to_string());
+
+        let absolute_path = path
+            .canonicalize()
+            .map(|p| p.to_string_lossy().to_string())
+            .unwrap_or_else(|_| file_path.to_string());
+
+        Ok((content, file_name, absolute_path, checksum))
+    }
+
+    /// Like [`FileSystem::read_external_file`], but with explicit handling
+    /// for a path that resolves through a symbolic link - a bare `.md`
+    /// extension check alone says nothing about a symlink pointing outside
+    /// the intended area, at a directory, or forming a loop.
+    ///
+    /// The input path is always fully canonicalized (resolving `..`/`.` and
+    /// every symlink in the chain) before anything is read, so a loop or a
+    /// broken symlink surfaces as a clear error here rather than an `ENOENT`
+    /// or `ELOOP` from deep inside `fs::read_to_string`. `policy` then
+    /// decides whether a symlink is allowed at all, and if so, whether its
+    /// resolved target must stay within a given base directory.
+    ///
+    /// # Arguments
+    /// * `file_path` - The absolute path to the file
+    /// * `policy` - How to treat a path that resolves through a symlink
+    ///
+    /// # Returns
+    /// * `Ok((String, String, String))` - Tuple of (content, file_name, canonicalized absolute_path)
+    /// * `Err(String)` - If validation fails, the policy rejects the symlink, or reading fails
+    pub fn read_external_file_with_symlink_policy(
+        &self,
+        file_path: &str,
+        policy: &SymlinkPolicy,
+    ) -> Result<(String, String, String), String> {
+        let path = std::path::Path::new(file_path);
+
+        match path.extension() {
+            Some(ext) if ext == "md" => {}
+            _ => return Err("File must have .md extension".to_string()),
+        }
+
+        let is_symlink = fs::symlink_metadata(path)
+            .map(|m| m.file_type().is_symlink())
+            .unwrap_or(false);
+
+        if is_symlink && matches!(policy, SymlinkPolicy::Reject) {
+            return Err(format!("'{}' is a symlink and the current policy rejects symlinks", file_path));
+        }
+
+        let canonical = path
+            .canonicalize()
+            .map_err(|e| format!("Failed to resolve '{}': {}", file_path, e))?;
+
+        if let SymlinkPolicy::FollowWithinBase(base) = policy {
+            let canonical_base = base
+                .canonicalize()
+                .map_err(|e| format!("Failed to resolve base directory '{}': {}", base.display(), e))?;
+            if !canonical.starts_with(&canonical_base) {
+                return Err(format!("'{}' resolves outside the allowed directory", file_path));
+            }
+        }
+
+        if !canonical.is_file() {
+            return Err(format!("Path is not a file: {}", file_path));
+        }
+
+        let content = fs::read_to_string(&canonical)
+            .map_err(|e| format!("Failed to read file: {}", e))?;
+
+        let file_name = canonical
+            .file_name()
+            .map(|n| n.to_string_lossy().to_string())
+            .unwrap_or_else(|| "unknown.md".to_string());
+
+        let absolute_path = canonical.to_string_lossy().to_string();
+
+        Ok((content, file_name, absolute_path))
+    }
+
+    /// Recomputes `path`'s checksum with `expected`'s algorithm and compares
+    /// digests, so a caller can tell whether a file changed on disk since it
+    /// was last read (an external edit) or detect silent corruption, without
+    /// re-reading and diffing the full content itself.
+    ///
+    /// # Arguments
+    /// * `path` - The absolute path to the file to re-check
+    /// * `expected` - The checksum previously returned by [`FileSystem::read_external_file_with_checksum`]
+    ///
+    /// # Returns
+    /// * `Ok(true)` - The file's current digest matches `expected`
+    /// * `Ok(false)` - The file's contents have changed
+    /// * `Err(String)` - If the file can't be read
+    pub fn verify_checksum(&self, path: &str, expected: &Checksum) -> Result<bool, String> {
+        let (_, actual) = read_file_with_checksum(Path::new(path), expected.algorithm)?;
+        Ok(actual.digest == expected.digest)
+    }
+
+    /// Recursively imports every Markdown file found under `root`.
+    ///
+    /// Walks the directory tree rooted at `root`, descending into
+    /// subdirectories and skipping anything that isn't recognized as
+    /// Markdown - reusing [`FileSystem::read_external_file_sniffed`]'s
+    /// extension/content-sniffing rules, so a binary asset or an unrelated
+    /// text file doesn't get pulled in alongside an extensionless note.
+    /// Entries at each level are visited in file-name order, so re-running
+    /// an import over the same tree produces the same order every time.
+    ///
+    /// # Arguments
+    /// * `root` - The absolute path to the directory to import
+    ///
+    /// # Returns
+    /// * `Ok(Vec<ImportedNote>)` - Every Markdown file found, in deterministic order
+    /// * `Err(String)` - If `root` doesn't exist or isn't a directory
+    pub fn import_directory(&self, root: &str) -> Result<Vec<crate::models::ImportedNote>, String> {
+        let root_path = std::path::Path::new(root);
+
+        if !root_path.exists() {
+            return Err(format!("Directory does not exist: {}", root));
+        }
+        if !root_path.is_dir() {
+            return Err(format!("Path is not a directory: {}", root));
+        }
+
+        let mut notes = Vec::new();
+        self.import_directory_into(root_path, root_path, &mut notes)?;
+        Ok(notes)
+    }
+
+    /// Recursion worker behind [`FileSystem::import_directory`]; `root` stays
+    /// fixed across calls so each note's `relative_path` is always relative
+    /// to the original import root, not the subdirectory being walked.
+    fn import_directory_into(
+        &self,
+        root: &Path,
+        dir: &Path,
+        notes: &mut Vec<crate::models::ImportedNote>,
+    ) -> Result<(), String> {
+        let mut entries: Vec<PathBuf> = fs::read_dir(dir)
+            .map_err(|e| format!("Failed to read directory '{}': {}", dir.display(), e))?
+            .filter_map(|e| e.ok())
+            .map(|e| e.path())
+            .collect();
+        entries.sort_by_key(|p| p.file_name().map(|n| n.to_os_string()));
+
+        for path in entries {
+            if path.is_dir() {
+                self.import_directory_into(root, &path, notes)?;
+                continue;
+            }
+
+            // Only extensionless files fall back to content sniffing; any
+            // other non-Markdown extension (`.txt`, `.png`, ...) is skipped.
+            let has_other_extension = path.extension().is_some_and(|ext| {
+                !matches!(ext.to_string_lossy().to_lowercase().as_str(), "md" | "markdown" | "mdown" | "mkd")
+            });
+            if has_other_extension {
+                continue;
+            }
+
+            let Ok((content, _file_name, absolute_path)) = self.read_external_file_sniffed(&path.to_string_lossy()) else {
+                continue;
+            };
+
+            let relative_path = path
+                .strip_prefix(root)
+                .unwrap_or(&path)
+                .to_string_lossy()
+                .replace('\\', "/");
+
+            notes.push(crate::models::ImportedNote {
+                relative_path,
+                content,
+                absolute_path,
+            });
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::storage::FileSystemLike;
+    use proptest::prelude::*;
+    use tempfile::tempdir;
+
+    #[test]
+    fn test_validate_path_rejects_double_dot() {
+        let temp_dir = tempdir().unwrap();
+        let base = temp_dir.path();
+        
+        let result = validate_path(base, "..");
+        assert!(result.is_err());
+        assert!(result.unwrap_err().contains(".."));
+    }
+
+    #[test]
+    fn test_validate_path_rejects_embedded_double_dot() {
+        let temp_dir = tempdir().unwrap();
+        let base = temp_dir.path();
+        
+        let result = validate_path(base, "foo..bar");
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_validate_path_rejects_forward_slash() {
+        let temp_dir = tempdir().unwrap();
+        let base = temp_dir.path();
+        
+        let result = validate_path(base, "foo/bar");
+        assert!(result.is_err());
+        assert!(result.unwrap_err().contains("/"));
+    }
+
+    #[test]
+    fn test_validate_path_rejects_backslash() {
+        let temp_dir = tempdir().unwrap();
+        let base = temp_dir.path();
+        
+        let result = validate_path(base, "foo\\bar");
+        assert!(result.is_err());
+        assert!(result.unwrap_err().contains("\\"));
+    }
+
+    #[test]
+    fn test_validate_path_accepts_valid_filename() {
+        let temp_dir = tempdir().unwrap();
+        let base = temp_dir.path();
+        
+        let result = validate_path(base, "valid-filename.md");
+        assert!(result.is_ok());
+    }
+
+    #[test]
+    fn test_validate_path_accepts_filename_with_dots() {
+        let temp_dir = tempdir().unwrap();
+        let base = temp_dir.path();
+        
+        // Single dots in filenames should be allowed (e.g., "file.name.md")
+        let result = validate_path(base, "file.name.md");
+        assert!(result.is_ok());
+    }
+
+    #[test]
+    fn test_validate_path_returns_correct_path() {
+        let temp_dir = tempdir().unwrap();
+        let base = temp_dir.path();
+        
+        let result = validate_path(base, "test.md").unwrap();
+        assert!(result.ends_with("test.md"));
+    }
+
+    #[test]
+    #[cfg(unix)]
+    fn test_validate_path_rejects_symlink_escaping_base_dir() {
+        let temp_dir = tempdir().unwrap();
+        let outside_dir = tempdir().unwrap();
+        let base = temp_dir.path();
+
+        std::os::unix::fs::symlink(outside_dir.path(), base.join("escape")).unwrap();
+
+        let result = validate_path(base, "escape");
+
+        assert!(result.is_err());
+        assert!(result.unwrap_err().contains("outside of base directory"));
+    }
+
+    #[test]
+    #[cfg(unix)]
+    fn test_validate_path_rejects_new_file_under_symlinked_parent() {
+        let temp_dir = tempdir().unwrap();
+        let outside_dir = tempdir().unwrap();
+        let base = temp_dir.path();
+
+        // "escape" looks like a folder inside base, but it's actually a
+        // symlink out; the file itself doesn't exist yet, so only checking
+        // the nearest existing ancestor (the symlink) catches this.
+        std::os::unix::fs::symlink(outside_dir.path(), base.join("escape")).unwrap();
+
+        let result = validate_folder_path(base, "escape/new-note.md", |_| false);
+
+        assert!(result.is_err());
+        assert!(result.unwrap_err().contains("outside of base directory"));
+    }
+
+    // Strategy for generating paths containing ".."
+    fn path_with_double_dot() -> impl Strategy<Value = String> {
+        prop_oneof![
+            Just("..".to_string()),
+            "[a-zA-Z0-9_-]{0,10}".prop_map(|prefix| format!("{}..{}", prefix, prefix)),
+            "[a-zA-Z0-9_-]{0,10}".prop_map(|s| format!("..{}", s)),
+            "[a-zA-Z0-9_-]{0,10}".prop_map(|s| format!("{}..", s)),
+        ]
+    }
+
+    // Strategy for generating paths containing "/"
+    fn path_with_forward_slash() -> impl Strategy<Value = String> {
+        prop_oneof![
+            "[a-zA-Z0-9_-]{1,10}".prop_map(|s| format!("{}/{}", s, s)),
+            "[a-zA-Z0-9_-]{1,10}".prop_map(|s| format!("/{}", s)),
+            "[a-zA-Z0-9_-]{1,10}".prop_map(|s| format!("{}/", s)),
+        ]
+    }
+
+    // Strategy for generating paths containing "\\"
+    fn path_with_backslash() -> impl Strategy<Value = String> {
+        prop_oneof![
+            "[a-zA-Z0-9_-]{1,10}".prop_map(|s| format!("{}\\{}", s, s)),
+            "[a-zA-Z0-9_-]{1,10}".prop_map(|s| format!("\\{}", s)),
+            "[a-zA-Z0-9_-]{1,10}".prop_map(|s| format!("{}\\", s)),
+        ]
+    }
+
+    proptest! {
+        #![proptest_config(ProptestConfig::with_cases(100))]
+
+        /// **Feature: mded-tauri-migration, Property 5: Path Traversal Rejection**
+        /// **Validates: Requirements 13.1, 13.2, 13.3**
+        /// 
+        /// For any path string containing "..", "/" or "\\" characters,
+        /// the path validator should reject it with an error.
+        #[test]
+        fn prop_path_traversal_rejection_double_dot(path in path_with_double_dot()) {
+            let temp_dir = tempdir().unwrap();
+            let base = temp_dir.path();
+            
+            let result = validate_path(base, &path);
+            prop_assert!(result.is_err(), "Path '{}' should be rejected but was accepted", path);
+            prop_assert!(
+                result.as_ref().unwrap_err().contains(".."),
+                "Error message should mention '..' for path '{}'",
+                path
+            );
+        }
+
+        /// **Feature: mded-tauri-migration, Property 5: Path Traversal Rejection**
+        /// **Validates: Requirements 13.1, 13.2, 13.3**
+        #[test]
+        fn prop_path_traversal_rejection_forward_slash(path in path_with_forward_slash()) {
+            let temp_dir = tempdir().unwrap();
+            let base = temp_dir.path();
+            
+            let result = validate_path(base, &path);
+            prop_assert!(result.is_err(), "Path '{}' should be rejected but was accepted", path);
+            prop_assert!(
+                result.as_ref().unwrap_err().contains("/"),
+                "Error message should mention '/' for path '{}'",
+                path
+            );
+        }
+
+        /// **Feature: mded-tauri-migration, Property 5: Path Traversal Rejection**
+        /// **Validates: Requirements 13.1, 13.2, 13.3**
+        #[test]
+        fn prop_path_traversal_rejection_backslash(path in path_with_backslash()) {
+            let temp_dir = tempdir().unwrap();
+            let base = temp_dir.path();
+            
+            let result = validate_path(base, &path);
+            prop_assert!(result.is_err(), "Path '{}' should be rejected but was accepted", path);
+            prop_assert!(
+                result.as_ref().unwrap_err().contains("\\"),
+                "Error message should mention '\\' for path '{}'",
+                path
+            );
+        }
+    }
+
+    // FileSystem tests
+    #[test]
+    fn test_filesystem_new_with_base() {
+        let temp_dir = tempdir().unwrap();
+        let fs = FileSystem::new_with_base(temp_dir.path()).unwrap();
+        
+        assert_eq!(fs.base_dir, temp_dir.path());
+        assert_eq!(fs.notes_dir, temp_dir.path().join("notes"));
+        assert_eq!(fs.assets_dir, temp_dir.path().join("assets"));
+        assert_eq!(fs.config_file, temp_dir.path().join("config.json"));
+        assert_eq!(fs.order_file, temp_dir.path().join("note-order.json"));
+    }
+
+    #[test]
+    fn test_filesystem_read_note_works_against_an_in_memory_backend() {
+        let base = PathBuf::from("/base");
+        let backend = std::sync::Arc::new(crate::storage::MemFileSystem::new(base.clone()));
+        let fs = FileSystem::new_with_backend(&base, backend.clone()).unwrap();
+
+        fs.ensure_directories().unwrap();
+        backend
+            .file_write(&fs.notes_dir.join("note-1.md"), b"hello from memory")
+            .unwrap();
+
+        assert_eq!(fs.read_note("note-1", None).unwrap(), "hello from memory");
+    }
+
+    #[test]
+    fn test_filesystem_ensure_directories() {
+        let temp_dir = tempdir().unwrap();
+        let fs = FileSystem::new_with_base(temp_dir.path()).unwrap();
         
-        // Construct the full path
-        let file_path = self.assets_dir.join(&file_name);
+        // Directories should not exist yet
+        assert!(!fs.notes_dir.exists());
+        assert!(!fs.assets_dir.exists());
+        
+        // Create directories
+        fs.ensure_directories().unwrap();
+        
+        // Directories should now exist
+        assert!(fs.notes_dir.exists());
+        assert!(fs.assets_dir.exists());
+        assert!(fs.notes_dir.is_dir());
+        assert!(fs.assets_dir.is_dir());
+    }
+
+    #[test]
+    fn test_filesystem_ensure_directories_idempotent() {
+        let temp_dir = tempdir().unwrap();
+        let fs = FileSystem::new_with_base(temp_dir.path()).unwrap();
+        
+        // Call ensure_directories multiple times
+        fs.ensure_directories().unwrap();
+        fs.ensure_directories().unwrap();
+        
+        // Should still work
+        assert!(fs.notes_dir.exists());
+        assert!(fs.assets_dir.exists());
+    }
+
+    #[test]
+    fn test_filesystem_validate_notes_path() {
+        let temp_dir = tempdir().unwrap();
+        let fs = FileSystem::new_with_base(temp_dir.path()).unwrap();
+        fs.ensure_directories().unwrap();
+        
+        // Valid path should work
+        let result = fs.validate_notes_path("test.md");
+        assert!(result.is_ok());
+        
+        // Invalid path should fail
+        let result = fs.validate_notes_path("../test.md");
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_filesystem_validate_assets_path() {
+        let temp_dir = tempdir().unwrap();
+        let fs = FileSystem::new_with_base(temp_dir.path()).unwrap();
+        fs.ensure_directories().unwrap();
+        
+        // Valid path should work
+        let result = fs.validate_assets_path("screenshot.png");
+        assert!(result.is_ok());
+        
+        // Invalid path should fail
+        let result = fs.validate_assets_path("../screenshot.png");
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_filesystem_get_folder_path() {
+        let temp_dir = tempdir().unwrap();
+        let fs = FileSystem::new_with_base(temp_dir.path()).unwrap();
+        
+        // None should return notes_dir
+        assert_eq!(fs.get_folder_path(None), fs.notes_dir);
+        
+        // Empty string should return notes_dir
+        assert_eq!(fs.get_folder_path(Some("")), fs.notes_dir);
+        
+        // Folder name should return subfolder
+        assert_eq!(fs.get_folder_path(Some("work")), fs.notes_dir.join("work"));
+    }
+
+    // Folder operations tests
+    #[test]
+    fn test_list_folders_empty() {
+        let temp_dir = tempdir().unwrap();
+        let fs = FileSystem::new_with_base(temp_dir.path()).unwrap();
+        fs.ensure_directories().unwrap();
+        
+        let folders = fs.list_folders().unwrap();
+        
+        // Should have exactly one folder: "All Notes"
+        assert_eq!(folders.len(), 1);
+        assert_eq!(folders[0].name, "All Notes");
+    }
+
+    #[test]
+    fn test_list_folders_with_subfolders() {
+        let temp_dir = tempdir().unwrap();
+        let fs = FileSystem::new_with_base(temp_dir.path()).unwrap();
+        fs.ensure_directories().unwrap();
+        
+        // Create some folders
+        std::fs::create_dir(fs.notes_dir.join("work")).unwrap();
+        std::fs::create_dir(fs.notes_dir.join("personal")).unwrap();
+        
+        let folders = fs.list_folders().unwrap();
+        
+        // Should have 3 folders: "All Notes" + 2 created
+        assert_eq!(folders.len(), 3);
+        assert_eq!(folders[0].name, "All Notes");
+        
+        // Other folders should be present (order may vary)
+        let folder_names: Vec<&str> = folders.iter().map(|f| f.name.as_str()).collect();
+        assert!(folder_names.contains(&"work"));
+        assert!(folder_names.contains(&"personal"));
+    }
+
+    #[test]
+    fn test_mdedignore_hides_matching_folder_and_its_notes() {
+        let temp_dir = tempdir().unwrap();
+        let fs = FileSystem::new_with_base(temp_dir.path()).unwrap();
+        fs.ensure_directories().unwrap();
+
+        fs.create_folder("drafts").unwrap();
+        fs.create_folder("work").unwrap();
+        std::fs::write(fs.notes_dir.join("drafts/scratch.md"), "scratch").unwrap();
+        std::fs::write(fs.notes_dir.join("work/todo.md"), "todo").unwrap();
+        std::fs::write(fs.notes_dir.join(".mdedignore"), "drafts/\n").unwrap();
+
+        let folder_names: Vec<String> = fs.list_folders().unwrap().into_iter().map(|f| f.name).collect();
+        assert!(!folder_names.contains(&"drafts".to_string()));
+        assert!(folder_names.contains(&"work".to_string()));
+
+        let notes = fs.list_notes(None).unwrap();
+        assert!(notes.iter().all(|n| n.folder != "drafts"));
+        assert!(notes.iter().any(|n| n.folder == "work"));
+    }
+
+    #[test]
+    fn test_mdedignore_hides_matching_file_pattern() {
+        let temp_dir = tempdir().unwrap();
+        let fs = FileSystem::new_with_base(temp_dir.path()).unwrap();
+        fs.ensure_directories().unwrap();
+
+        fs.save_note("keep", "keep", None).unwrap();
+        fs.save_note("backup", "backup", None).unwrap();
+        std::fs::rename(fs.notes_dir.join("backup.md"), fs.notes_dir.join("backup.bak.md")).unwrap();
+        std::fs::write(fs.notes_dir.join(".mdedignore"), "*.bak.md\n").unwrap();
+
+        let note_ids: Vec<String> = fs.list_notes(None).unwrap().into_iter().map(|n| n.id).collect();
+        assert!(note_ids.contains(&"keep".to_string()));
+        assert!(!note_ids.iter().any(|id| id.contains("backup")));
+    }
+
+    #[test]
+    fn test_mdedignore_negation_un_ignores_earlier_match() {
+        let temp_dir = tempdir().unwrap();
+        let fs = FileSystem::new_with_base(temp_dir.path()).unwrap();
+        fs.ensure_directories().unwrap();
+
+        fs.create_folder("drafts").unwrap();
+        fs.create_folder("drafts-keep").unwrap();
+        std::fs::write(fs.notes_dir.join(".mdedignore"), "drafts*/\n!drafts-keep/\n").unwrap();
+
+        let folder_names: Vec<String> = fs.list_folders().unwrap().into_iter().map(|f| f.name).collect();
+        assert!(!folder_names.contains(&"drafts".to_string()));
+        assert!(folder_names.contains(&"drafts-keep".to_string()));
+    }
+
+    #[test]
+    fn test_list_notes_serves_unchanged_note_title_from_cache() {
+        let temp_dir = tempdir().unwrap();
+        let fs = FileSystem::new_with_base(temp_dir.path()).unwrap();
+        fs.ensure_directories().unwrap();
+
+        fs.save_note("stable", "# Original Title\nbody", None).unwrap();
+
+        // Populate the index.
+        let notes = fs.list_notes(None).unwrap();
+        assert_eq!(notes.iter().find(|n| n.id == "stable").unwrap().title, "Original Title");
+
+        // Rewrite the cached entry's title without touching the file, so a
+        // cache hit is the only way the stale title could come back out.
+        let content = fs::read_to_string(&fs.index_file).unwrap();
+        let mut index: serde_json::Value = serde_json::from_str(&content).unwrap();
+        index["entries"]["stable.md"]["title"] = serde_json::Value::String("Cached Title".to_string());
+        fs::write(&fs.index_file, serde_json::to_string_pretty(&index).unwrap()).unwrap();
+
+        let notes = fs.list_notes(None).unwrap();
+        assert_eq!(notes.iter().find(|n| n.id == "stable").unwrap().title, "Cached Title");
+    }
+
+    #[test]
+    fn test_list_notes_invalidates_cache_entry_for_modified_note_only() {
+        let temp_dir = tempdir().unwrap();
+        let fs = FileSystem::new_with_base(temp_dir.path()).unwrap();
+        fs.ensure_directories().unwrap();
+
+        fs.save_note("changes", "# Before\nbody", None).unwrap();
+        fs.save_note("stays-put", "# Untouched\nbody", None).unwrap();
+        fs.list_notes(None).unwrap();
+
+        // Poison both cached titles; only "changes" should recover the real
+        // title below, since only its mtime/size actually differ now.
+        let content = fs::read_to_string(&fs.index_file).unwrap();
+        let mut index: serde_json::Value = serde_json::from_str(&content).unwrap();
+        index["entries"]["changes.md"]["title"] = serde_json::Value::String("Stale".to_string());
+        index["entries"]["stays-put.md"]["title"] = serde_json::Value::String("Stale".to_string());
+        fs::write(&fs.index_file, serde_json::to_string_pretty(&index).unwrap()).unwrap();
+
+        fs.save_note("changes", "# After, much longer than before\nbody", None).unwrap();
+
+        let notes = fs.list_notes(None).unwrap();
+        assert_eq!(notes.iter().find(|n| n.id == "changes").unwrap().title, "After, much longer than before");
+        assert_eq!(notes.iter().find(|n| n.id == "stays-put").unwrap().title, "Stale");
+    }
+
+    #[test]
+    fn test_list_notes_drops_index_entry_for_deleted_note() {
+        let temp_dir = tempdir().unwrap();
+        let fs = FileSystem::new_with_base(temp_dir.path()).unwrap();
+        fs.ensure_directories().unwrap();
+
+        fs.save_note("gone-soon", "content", None).unwrap();
+        fs.list_notes(None).unwrap();
+        assert!(fs.load_index().entries.contains_key("gone-soon.md"));
+
+        fs.delete_note("gone-soon", None, true).unwrap();
+        fs.list_notes(None).unwrap();
+
+        assert!(!fs.load_index().entries.contains_key("gone-soon.md"));
+    }
+
+    #[test]
+    fn test_rebuild_index_recomputes_every_cached_title() {
+        let temp_dir = tempdir().unwrap();
+        let fs = FileSystem::new_with_base(temp_dir.path()).unwrap();
+        fs.ensure_directories().unwrap();
+
+        fs.save_note("note-a", "# Title A\nbody", None).unwrap();
+        fs.list_notes(None).unwrap();
+
+        let content = fs::read_to_string(&fs.index_file).unwrap();
+        let mut index: serde_json::Value = serde_json::from_str(&content).unwrap();
+        index["entries"]["note-a.md"]["title"] = serde_json::Value::String("Corrupted".to_string());
+        fs::write(&fs.index_file, serde_json::to_string_pretty(&index).unwrap()).unwrap();
+
+        fs.rebuild_index().unwrap();
+
+        let notes = fs.list_notes(None).unwrap();
+        assert_eq!(notes.iter().find(|n| n.id == "note-a").unwrap().title, "Title A");
+    }
+
+    #[test]
+    fn test_list_notes_parallel_path_matches_serial_path_on_a_large_vault() {
+        let temp_dir = tempdir().unwrap();
+        let fs = FileSystem::new_with_base(temp_dir.path()).unwrap();
+        fs.ensure_directories().unwrap();
+
+        let note_count = FileSystem::PARALLEL_LIST_NOTES_THRESHOLD + 50;
+        for i in 0..note_count {
+            fs.save_note(&format!("note-{:04}", i), &format!("# Title {}\nbody", i), None).unwrap();
+        }
+
+        let serial = fs.list_notes_inner(None, true).unwrap();
+        let parallel = fs.list_notes_inner(None, false).unwrap();
+
+        assert_eq!(serial.len(), note_count);
+        assert_eq!(serial, parallel);
+    }
+
+    #[test]
+    fn test_create_folder_creates_nested_hierarchy() {
+        let temp_dir = tempdir().unwrap();
+        let fs = FileSystem::new_with_base(temp_dir.path()).unwrap();
+        fs.ensure_directories().unwrap();
+
+        fs.create_folder("Projects/2024/Research").unwrap();
+        assert!(fs.notes_dir.join("Projects/2024/Research").is_dir());
+
+        let folders = fs.list_folders().unwrap();
+        let by_path = |path: &str| folders.iter().find(|f| f.path == path).unwrap();
+
+        let projects = by_path("Projects");
+        assert_eq!(projects.name, "Projects");
+        assert_eq!(projects.parent, None);
+        assert_eq!(projects.depth, 0);
+
+        let year = by_path("Projects/2024");
+        assert_eq!(year.name, "2024");
+        assert_eq!(year.parent, Some("Projects".to_string()));
+        assert_eq!(year.depth, 1);
+
+        let research = by_path("Projects/2024/Research");
+        assert_eq!(research.name, "Research");
+        assert_eq!(research.parent, Some("Projects/2024".to_string()));
+        assert_eq!(research.depth, 2);
+    }
+
+    #[test]
+    fn test_create_folder_rejects_traversal_in_any_segment() {
+        let temp_dir = tempdir().unwrap();
+        let fs = FileSystem::new_with_base(temp_dir.path()).unwrap();
+        fs.ensure_directories().unwrap();
+
+        let result = fs.create_folder("Projects/../escape");
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_create_folder_rejects_protected_name_in_nested_segment() {
+        let temp_dir = tempdir().unwrap();
+        let fs = FileSystem::new_with_base(temp_dir.path()).unwrap();
+        fs.ensure_directories().unwrap();
+
+        let result = fs.create_folder("Projects/Trash");
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_rename_folder_moves_nested_folder_to_new_nested_location() {
+        let temp_dir = tempdir().unwrap();
+        let fs = FileSystem::new_with_base(temp_dir.path()).unwrap();
+        fs.ensure_directories().unwrap();
+
+        fs.create_folder("Projects/2024").unwrap();
+        fs.rename_folder("Projects/2024", "Archive/2024", false, false).unwrap();
+
+        assert!(!fs.notes_dir.join("Projects/2024").exists());
+        assert!(fs.notes_dir.join("Archive/2024").is_dir());
+    }
+
+    #[test]
+    fn test_delete_folder_trashes_nested_folder_and_restores_it() {
+        let temp_dir = tempdir().unwrap();
+        let fs = FileSystem::new_with_base(temp_dir.path()).unwrap();
+        fs.ensure_directories().unwrap();
+
+        fs.create_folder("Projects/2024").unwrap();
+        fs.delete_folder("Projects/2024", false, false).unwrap();
+        assert!(!fs.notes_dir.join("Projects/2024").exists());
+
+        let trash = fs.list_trash().unwrap();
+        assert_eq!(trash[0].original_name, "Projects/2024");
+
+        fs.restore_folder(&trash[0].trash_id).unwrap();
+        assert!(fs.notes_dir.join("Projects/2024").is_dir());
+    }
+
+    #[test]
+    fn test_create_folder() {
+        let temp_dir = tempdir().unwrap();
+        let fs = FileSystem::new_with_base(temp_dir.path()).unwrap();
+        fs.ensure_directories().unwrap();
+        
+        fs.create_folder("test-folder").unwrap();
+        
+        assert!(fs.notes_dir.join("test-folder").exists());
+        assert!(fs.notes_dir.join("test-folder").is_dir());
+    }
+
+    #[test]
+    fn test_create_folder_already_exists() {
+        let temp_dir = tempdir().unwrap();
+        let fs = FileSystem::new_with_base(temp_dir.path()).unwrap();
+        fs.ensure_directories().unwrap();
+        
+        fs.create_folder("test-folder").unwrap();
+        let result = fs.create_folder("test-folder");
+        
+        assert!(result.is_err());
+        assert!(result.unwrap_err().contains("already exists"));
+    }
+
+    #[test]
+    fn test_delete_folder() {
+        let temp_dir = tempdir().unwrap();
+        let fs = FileSystem::new_with_base(temp_dir.path()).unwrap();
+        fs.ensure_directories().unwrap();
         
-        // Write the image data
-        fs::write(&file_path, &image_data)
-            .map_err(|e| format!("Failed to save screenshot: {}", e))?;
+        fs.create_folder("to-delete").unwrap();
+        assert!(fs.notes_dir.join("to-delete").exists());
         
-        Ok((image_id, file_path.to_string_lossy().to_string()))
+        fs.delete_folder("to-delete", false, false).unwrap();
+        assert!(!fs.notes_dir.join("to-delete").exists());
+    }
+
+    #[test]
+    fn test_delete_folder_moves_to_trash_and_is_restorable() {
+        let temp_dir = tempdir().unwrap();
+        let fs = FileSystem::new_with_base(temp_dir.path()).unwrap();
+        fs.ensure_directories().unwrap();
+
+        fs.create_folder("to-trash").unwrap();
+        fs.delete_folder("to-trash", false, false).unwrap();
+        assert!(!fs.notes_dir.join("to-trash").exists());
+
+        let trash = fs.list_trash().unwrap();
+        assert_eq!(trash.len(), 1);
+        assert_eq!(trash[0].original_name, "to-trash");
+
+        let restored_name = fs.restore_folder(&trash[0].trash_id).unwrap();
+        assert_eq!(restored_name, "to-trash");
+        assert!(fs.notes_dir.join("to-trash").exists());
+        assert!(fs.list_trash().unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_delete_folder_permanent_skips_trash() {
+        let temp_dir = tempdir().unwrap();
+        let fs = FileSystem::new_with_base(temp_dir.path()).unwrap();
+        fs.ensure_directories().unwrap();
+
+        fs.create_folder("to-delete").unwrap();
+        fs.delete_folder("to-delete", true, false).unwrap();
+
+        assert!(!fs.notes_dir.join("to-delete").exists());
+        assert!(fs.list_trash().unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_empty_trash_permanently_removes_trashed_folders() {
+        let temp_dir = tempdir().unwrap();
+        let fs = FileSystem::new_with_base(temp_dir.path()).unwrap();
+        fs.ensure_directories().unwrap();
+
+        fs.create_folder("to-trash").unwrap();
+        fs.delete_folder("to-trash", false, false).unwrap();
+        let trash_id = fs.list_trash().unwrap()[0].trash_id.clone();
+        fs.empty_trash().unwrap();
+
+        assert!(fs.list_trash().unwrap().is_empty());
+        assert!(!fs.trash_dir.join(&trash_id).exists());
+    }
+
+    #[test]
+    fn test_delete_note_moves_to_trash_and_is_restorable() {
+        let temp_dir = tempdir().unwrap();
+        let fs = FileSystem::new_with_base(temp_dir.path()).unwrap();
+        fs.ensure_directories().unwrap();
+
+        fs.create_folder("folder").unwrap();
+        let (note_id, _) = fs.create_note(Some("folder")).unwrap();
+        fs.delete_note(&note_id, Some("folder"), false).unwrap();
+        assert!(!fs.notes_dir.join("folder").join(format!("{}.md", note_id)).exists());
+
+        let trash = fs.list_trash().unwrap();
+        assert_eq!(trash.len(), 1);
+        assert_eq!(trash[0].original_name, note_id);
+        assert_eq!(trash[0].original_folder.as_deref(), Some("folder"));
+        assert_eq!(trash[0].kind, crate::models::TrashItemKind::Note);
+
+        let (restored_id, restored_folder) = fs.restore_note(&trash[0].trash_id).unwrap();
+        assert_eq!(restored_id, note_id);
+        assert_eq!(restored_folder.as_deref(), Some("folder"));
+        assert!(fs.notes_dir.join("folder").join(format!("{}.md", note_id)).exists());
+        assert!(fs.list_trash().unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_delete_note_permanent_skips_trash() {
+        let temp_dir = tempdir().unwrap();
+        let fs = FileSystem::new_with_base(temp_dir.path()).unwrap();
+        fs.ensure_directories().unwrap();
+
+        let (note_id, _) = fs.create_note(None).unwrap();
+        fs.delete_note(&note_id, None, true).unwrap();
+
+        assert!(!fs.notes_dir.join(format!("{}.md", note_id)).exists());
+        assert!(fs.list_trash().unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_restore_folder_rejects_trashed_note_entry() {
+        let temp_dir = tempdir().unwrap();
+        let fs = FileSystem::new_with_base(temp_dir.path()).unwrap();
+        fs.ensure_directories().unwrap();
+
+        let (note_id, _) = fs.create_note(None).unwrap();
+        fs.delete_note(&note_id, None, false).unwrap();
+        let trash_id = fs.list_trash().unwrap()[0].trash_id.clone();
+
+        let result = fs.restore_folder(&trash_id);
+        assert!(result.is_err());
+        assert!(result.unwrap_err().contains("not a folder"));
+    }
+
+    #[test]
+    fn test_empty_trash_removes_mixed_folder_and_note_entries() {
+        let temp_dir = tempdir().unwrap();
+        let fs = FileSystem::new_with_base(temp_dir.path()).unwrap();
+        fs.ensure_directories().unwrap();
+
+        fs.create_folder("to-trash").unwrap();
+        fs.delete_folder("to-trash", false, false).unwrap();
+        let (note_id, _) = fs.create_note(None).unwrap();
+        fs.delete_note(&note_id, None, false).unwrap();
+
+        assert_eq!(fs.list_trash().unwrap().len(), 2);
+        fs.empty_trash().unwrap();
+        assert!(fs.list_trash().unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_search_notes_finds_match_count_and_snippet() {
+        let temp_dir = tempdir().unwrap();
+        let fs = FileSystem::new_with_base(temp_dir.path()).unwrap();
+        fs.ensure_directories().unwrap();
+
+        let (note_id, path) = fs.create_note(None).unwrap();
+        std::fs::write(&path, "# Title\n\nThe quick brown fox jumps over the lazy fox.\n").unwrap();
+
+        let hits = fs.search_notes("fox", None, false).unwrap();
+        assert_eq!(hits.len(), 1);
+        assert_eq!(hits[0].id, note_id);
+        assert_eq!(hits[0].match_count, 2);
+        assert!(hits[0].snippet.contains("fox"));
+    }
+
+    #[test]
+    fn test_search_notes_is_case_insensitive() {
+        let temp_dir = tempdir().unwrap();
+        let fs = FileSystem::new_with_base(temp_dir.path()).unwrap();
+        fs.ensure_directories().unwrap();
+
+        let (_, path) = fs.create_note(None).unwrap();
+        std::fs::write(&path, "# Title\n\nHello World\n").unwrap();
+
+        let hits = fs.search_notes("WORLD", None, false).unwrap();
+        assert_eq!(hits.len(), 1);
+    }
+
+    #[test]
+    fn test_search_notes_whole_word_excludes_partial_matches() {
+        let temp_dir = tempdir().unwrap();
+        let fs = FileSystem::new_with_base(temp_dir.path()).unwrap();
+        fs.ensure_directories().unwrap();
+
+        let (_, path) = fs.create_note(None).unwrap();
+        std::fs::write(&path, "# Title\n\ncatalog cat\n").unwrap();
+
+        let hits = fs.search_notes("cat", None, true).unwrap();
+        assert_eq!(hits.len(), 1);
+        assert_eq!(hits[0].match_count, 1);
+    }
+
+    #[test]
+    fn test_search_notes_ranks_higher_match_count_first() {
+        let temp_dir = tempdir().unwrap();
+        let fs = FileSystem::new_with_base(temp_dir.path()).unwrap();
+        fs.ensure_directories().unwrap();
+
+        let (few_id, few_path) = fs.create_note(None).unwrap();
+        std::fs::write(&few_path, "# Few\n\nneedle\n").unwrap();
+
+        let (many_id, many_path) = fs.create_note(None).unwrap();
+        std::fs::write(&many_path, "# Many\n\nneedle needle needle\n").unwrap();
+
+        let hits = fs.search_notes("needle", None, false).unwrap();
+        assert_eq!(hits.len(), 2);
+        assert_eq!(hits[0].id, many_id);
+        assert_eq!(hits[1].id, few_id);
+    }
+
+    #[test]
+    fn test_search_notes_rejects_empty_query() {
+        let temp_dir = tempdir().unwrap();
+        let fs = FileSystem::new_with_base(temp_dir.path()).unwrap();
+        fs.ensure_directories().unwrap();
+
+        let result = fs.search_notes("   ", None, false);
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_search_notes_ranked_scores_rare_term_match_above_common_term_spam() {
+        let temp_dir = tempdir().unwrap();
+        let fs = FileSystem::new_with_base(temp_dir.path()).unwrap();
+        fs.ensure_directories().unwrap();
+
+        let (relevant_id, _) = fs.create_note(None).unwrap();
+        fs.save_note(&relevant_id, "quasar observation data", None).unwrap();
+
+        let (spammy_id, _) = fs.create_note(None).unwrap();
+        fs.save_note(&spammy_id, &"filler ".repeat(50), None).unwrap();
+
+        let hits = fs.search_notes_ranked("quasar", None).unwrap();
+        assert_eq!(hits.len(), 1);
+        assert_eq!(hits[0].id, relevant_id);
+        assert!(hits[0].score > 0.0);
+    }
+
+    #[test]
+    fn test_search_notes_ranked_reflects_incremental_updates() {
+        let temp_dir = tempdir().unwrap();
+        let fs = FileSystem::new_with_base(temp_dir.path()).unwrap();
+        fs.ensure_directories().unwrap();
+
+        let (note_id, _) = fs.create_note(None).unwrap();
+        fs.save_note(&note_id, "nothing relevant here", None).unwrap();
+
+        assert!(fs.search_notes_ranked("zephyr", None).unwrap().is_empty());
+
+        fs.save_note(&note_id, "a note about zephyr winds", None).unwrap();
+        let hits = fs.search_notes_ranked("zephyr", None).unwrap();
+        assert_eq!(hits.len(), 1);
+        assert_eq!(hits[0].id, note_id);
+
+        fs.delete_note(&note_id, None, true).unwrap();
+        assert!(fs.search_notes_ranked("zephyr", None).unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_search_notes_ranked_follows_note_through_rename() {
+        let temp_dir = tempdir().unwrap();
+        let fs = FileSystem::new_with_base(temp_dir.path()).unwrap();
+        fs.ensure_directories().unwrap();
+
+        let (note_id, _) = fs.create_note(None).unwrap();
+        fs.save_note(&note_id, "topic: narwhal migration patterns", None).unwrap();
+
+        let new_id = fs.rename_note(&note_id, "narwhal-notes", None).unwrap();
+
+        let hits = fs.search_notes_ranked("narwhal", None).unwrap();
+        assert_eq!(hits.len(), 1);
+        assert_eq!(hits[0].id, new_id);
+    }
+
+    #[test]
+    fn test_search_notes_ranked_rejects_empty_query() {
+        let temp_dir = tempdir().unwrap();
+        let fs = FileSystem::new_with_base(temp_dir.path()).unwrap();
+        fs.ensure_directories().unwrap();
+
+        let result = fs.search_notes_ranked("   ", None);
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_note_id_and_folder_from_path_splits_relative_folder_and_id() {
+        let notes_dir = Path::new("/vault/notes");
+        let (note_id, folder) = note_id_and_folder_from_path(notes_dir, Path::new("/vault/notes/Work/my-note.md")).unwrap();
+        assert_eq!(note_id, "my-note");
+        assert_eq!(folder, "Work");
+
+        let (root_note_id, root_folder) = note_id_and_folder_from_path(notes_dir, Path::new("/vault/notes/root-note.md")).unwrap();
+        assert_eq!(root_note_id, "root-note");
+        assert_eq!(root_folder, "");
+    }
+
+    #[test]
+    fn test_note_id_and_folder_from_path_rejects_non_markdown_files() {
+        let notes_dir = Path::new("/vault/notes");
+        assert!(note_id_and_folder_from_path(notes_dir, Path::new("/vault/notes/index.json")).is_none());
+    }
+
+    #[test]
+    fn test_title_from_content_skips_leading_frontmatter() {
+        let content = "---\ntags: [work, idea]\n---\n# Real Title\n\nbody";
+        assert_eq!(title_from_content(content), Some("Real Title".to_string()));
+    }
+
+    #[test]
+    fn test_set_note_tags_round_trips_through_list_notes_and_preserves_body() {
+        let temp_dir = tempdir().unwrap();
+        let fs = FileSystem::new_with_base(temp_dir.path()).unwrap();
+        fs.ensure_directories().unwrap();
+
+        let (note_id, _) = fs.create_note(None).unwrap();
+        fs.save_note(&note_id, "# My Note\n\nsome body text", None).unwrap();
+
+        fs.set_note_tags(&note_id, vec!["work".to_string(), "idea".to_string()], None).unwrap();
+
+        let content = fs.read_note(&note_id, None).unwrap();
+        assert!(content.contains("some body text"));
+
+        let notes = fs.list_notes(None).unwrap();
+        let note = notes.iter().find(|n| n.id == note_id).unwrap();
+        assert_eq!(note.tags, vec!["work".to_string(), "idea".to_string()]);
+        assert_eq!(note.title, "My Note");
+    }
+
+    #[test]
+    fn test_set_note_tags_with_empty_list_strips_frontmatter() {
+        let temp_dir = tempdir().unwrap();
+        let fs = FileSystem::new_with_base(temp_dir.path()).unwrap();
+        fs.ensure_directories().unwrap();
+
+        let (note_id, _) = fs.create_note(None).unwrap();
+        fs.set_note_tags(&note_id, vec!["temp".to_string()], None).unwrap();
+        fs.set_note_tags(&note_id, Vec::new(), None).unwrap();
+
+        let content = fs.read_note(&note_id, None).unwrap();
+        assert!(!content.starts_with("---"));
+
+        let notes = fs.list_notes(None).unwrap();
+        let note = notes.iter().find(|n| n.id == note_id).unwrap();
+        assert!(note.tags.is_empty());
+    }
+
+    #[test]
+    fn test_list_tags_counts_across_vault_and_sorts_by_descending_count() {
+        let temp_dir = tempdir().unwrap();
+        let fs = FileSystem::new_with_base(temp_dir.path()).unwrap();
+        fs.ensure_directories().unwrap();
+
+        let (a, _) = fs.create_note(None).unwrap();
+        fs.set_note_tags(&a, vec!["work".to_string(), "urgent".to_string()], None).unwrap();
+        let (b, _) = fs.create_note(None).unwrap();
+        fs.set_note_tags(&b, vec!["work".to_string()], None).unwrap();
+
+        let tags = fs.list_tags().unwrap();
+        assert_eq!(tags[0], ("work".to_string(), 2));
+        assert!(tags.contains(&("urgent".to_string(), 1)));
+    }
+
+    #[test]
+    fn test_list_notes_by_tag_filters_to_matching_notes_only() {
+        let temp_dir = tempdir().unwrap();
+        let fs = FileSystem::new_with_base(temp_dir.path()).unwrap();
+        fs.ensure_directories().unwrap();
+
+        let (tagged_id, _) = fs.create_note(None).unwrap();
+        fs.set_note_tags(&tagged_id, vec!["project".to_string()], None).unwrap();
+        let (untagged_id, _) = fs.create_note(None).unwrap();
+
+        let matches = fs.list_notes_by_tag("project").unwrap();
+        assert_eq!(matches.len(), 1);
+        assert_eq!(matches[0].id, tagged_id);
+        assert!(!matches.iter().any(|n| n.id == untagged_id));
+    }
+
+    #[test]
+    fn test_save_note_snapshots_previous_content_as_a_version() {
+        let temp_dir = tempdir().unwrap();
+        let fs = FileSystem::new_with_base(temp_dir.path()).unwrap();
+        fs.ensure_directories().unwrap();
+
+        let (note_id, _) = fs.create_note(None).unwrap();
+        fs.save_note(&note_id, "first revision", None).unwrap();
+        fs.save_note(&note_id, "second revision", None).unwrap();
+
+        let versions = fs.list_note_versions(&note_id, None).unwrap();
+        assert_eq!(versions.len(), 2);
+        assert!(versions.iter().all(|v| v.note_id == note_id));
+
+        let newest_content = fs.read_note_version(&note_id, &versions[0].version_id).unwrap();
+        assert_eq!(newest_content, "first revision");
+    }
+
+    #[test]
+    fn test_restore_note_version_rolls_back_and_snapshots_current_state() {
+        let temp_dir = tempdir().unwrap();
+        let fs = FileSystem::new_with_base(temp_dir.path()).unwrap();
+        fs.ensure_directories().unwrap();
+
+        let (note_id, _) = fs.create_note(None).unwrap();
+        fs.save_note(&note_id, "original content", None).unwrap();
+        fs.save_note(&note_id, "edited content", None).unwrap();
+
+        // The version captured by the second save is what the note held
+        // right before it became "edited content" - "original content".
+        let original_version_id = fs.list_note_versions(&note_id, None).unwrap()[0].version_id.clone();
+
+        fs.restore_note_version(&note_id, &original_version_id, None).unwrap();
+        assert_eq!(fs.read_note(&note_id, None).unwrap(), "original content");
+
+        // Rolling back itself counted as a save, so "edited content" is
+        // recoverable too.
+        let versions = fs.list_note_versions(&note_id, None).unwrap();
+        assert!(versions
+            .iter()
+            .any(|v| fs.read_note_version(&note_id, &v.version_id).unwrap() == "edited content"));
+    }
+
+    #[test]
+    fn test_rename_note_moves_version_history_to_new_id() {
+        let temp_dir = tempdir().unwrap();
+        let fs = FileSystem::new_with_base(temp_dir.path()).unwrap();
+        fs.ensure_directories().unwrap();
+
+        let (note_id, _) = fs.create_note(None).unwrap();
+        fs.save_note(&note_id, "v1", None).unwrap();
+        fs.save_note(&note_id, "v2", None).unwrap();
+
+        let new_id = fs.rename_note(&note_id, "renamed-note", None).unwrap();
+
+        assert!(fs.list_note_versions(&note_id, None).unwrap().is_empty());
+        assert_eq!(fs.list_note_versions(&new_id, None).unwrap().len(), 2);
+    }
+
+    #[test]
+    fn test_permanent_delete_snapshots_final_state_before_removing() {
+        let temp_dir = tempdir().unwrap();
+        let fs = FileSystem::new_with_base(temp_dir.path()).unwrap();
+        fs.ensure_directories().unwrap();
+
+        let (note_id, _) = fs.create_note(None).unwrap();
+        fs.save_note(&note_id, "final words", None).unwrap();
+        fs.delete_note(&note_id, None, true).unwrap();
+
+        let versions = fs.list_note_versions(&note_id, None).unwrap();
+        assert!(versions
+            .iter()
+            .any(|v| fs.read_note_version(&note_id, &v.version_id).unwrap() == "final words"));
+    }
+
+    #[test]
+    fn test_archive_entries_round_trip_through_write_and_read() {
+        let mut buffer = Vec::new();
+        buffer.extend_from_slice(VAULT_ARCHIVE_MAGIC);
+        write_archive_entry(&mut buffer, "notes/a.md", b"hello world").unwrap();
+        write_archive_entry(&mut buffer, "notes/sub/b.md", b"").unwrap();
+
+        let entries = read_archive_entries(&buffer).unwrap();
+        assert_eq!(
+            entries,
+            vec![
+                ("notes/a.md".to_string(), b"hello world".to_vec()),
+                ("notes/sub/b.md".to_string(), Vec::new()),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_read_archive_entries_rejects_missing_or_wrong_magic() {
+        assert!(read_archive_entries(b"not an archive").is_err());
+        assert!(read_archive_entries(b"MDEDVLT0").is_err());
+    }
+
+    #[test]
+    fn test_read_archive_entries_rejects_truncated_archive() {
+        let mut buffer = Vec::new();
+        buffer.extend_from_slice(VAULT_ARCHIVE_MAGIC);
+        write_archive_entry(&mut buffer, "notes/a.md", b"hello world").unwrap();
+        buffer.truncate(buffer.len() - 4);
+
+        assert!(read_archive_entries(&buffer).is_err());
+    }
+
+    #[test]
+    fn test_read_archive_entries_rejects_corrupted_content() {
+        let mut buffer = Vec::new();
+        buffer.extend_from_slice(VAULT_ARCHIVE_MAGIC);
+        write_archive_entry(&mut buffer, "notes/a.md", b"hello world").unwrap();
+        let last = buffer.len() - 1;
+        buffer[last] ^= 0xFF;
+
+        let err = read_archive_entries(&buffer).unwrap_err();
+        assert!(err.contains("integrity check"));
+    }
+
+    #[test]
+    fn test_collect_files_recursive_walks_nested_folders_in_sorted_order() {
+        let temp_dir = tempdir().unwrap();
+        fs::write(temp_dir.path().join("b.md"), "b").unwrap();
+        fs::create_dir(temp_dir.path().join("sub")).unwrap();
+        fs::write(temp_dir.path().join("sub/c.md"), "c").unwrap();
+        fs::write(temp_dir.path().join("a.md"), "a").unwrap();
+
+        let mut files = Vec::new();
+        collect_files_recursive(temp_dir.path(), &mut files).unwrap();
+        let names: Vec<String> = files
+            .iter()
+            .map(|p| p.strip_prefix(temp_dir.path()).unwrap().to_string_lossy().replace('\\', "/"))
+            .collect();
+        assert_eq!(names, vec!["a.md".to_string(), "b.md".to_string(), "sub/c.md".to_string()]);
+    }
+
+    // import_vault itself takes a tauri::AppHandle, which this crate has no
+    // mock harness for, so these two pin the containment checks it's meant
+    // to route every archive entry through - not an end-to-end run of
+    // import_vault - against the specific traversal strings the fixed code
+    // path was built to reject (the `notes/` and `assets/` prefixed
+    // counterparts of the already-covered validate_assets_path case above).
+    #[test]
+    fn test_validate_notes_folder_path_rejects_the_traversal_string_a_malicious_vault_archive_notes_entry_would_use() {
+        let temp_dir = tempdir().unwrap();
+        let fs = FileSystem::new_with_base(temp_dir.path()).unwrap();
+        fs.ensure_directories().unwrap();
+
+        assert!(fs.validate_notes_folder_path("../../../../.ssh/authorized_keys").is_err());
     }
 
-    /// Returns the absolute path to the assets directory.
-    /// 
-    /// # Returns
-    /// The absolute path to the assets directory as a string
-    /// 
-    /// # Requirements
-    /// Validates: Requirements 14.3
-    pub fn get_assets_path(&self) -> String {
-        self.assets_dir.to_string_lossy().to_string()
+    #[test]
+    fn test_validate_assets_path_rejects_the_traversal_string_a_malicious_vault_archive_assets_entry_would_use() {
+        let temp_dir = tempdir().unwrap();
+        let fs = FileSystem::new_with_base(temp_dir.path()).unwrap();
+        fs.ensure_directories().unwrap();
+
+        assert!(fs.validate_assets_path("../notes/some-note.md").is_err());
     }
 
-    // ==================== External File Operations ====================
+    #[test]
+    fn test_save_note_order_writes_file_without_leftover_tmp() {
+        let temp_dir = tempdir().unwrap();
+        let fs = FileSystem::new_with_base(temp_dir.path()).unwrap();
+        fs.ensure_directories().unwrap();
 
-    /// Reads an external markdown file.
-    /// 
-    /// Validates that the file has a .md extension and reads its content.
-    /// 
-    /// # Arguments
-    /// * `file_path` - The absolute path to the file
-    /// 
-    /// # Returns
-    /// * `Ok((String, String, String))` - Tuple of (content, file_name, absolute_path)
-    /// * `Err(String)` - If validation fails or reading fails
-    /// 
-    /// # Requirements
-    /// Validates: Requirements 15.1, 15.2, 15.3
-    pub fn read_external_file(&self, file_path: &str) -> Result<(String, String, String), String> {
-        let path = std::path::Path::new(file_path);
-        
-        // Validate .md extension
-        match path.extension() {
-            Some(ext) if ext == "md" => {}
-            _ => return Err("File must have .md extension".to_string()),
-        }
-        
-        // Check if file exists
-        if !path.exists() {
-            return Err(format!("File does not exist: {}", file_path));
-        }
-        
-        // Check if it's a file (not a directory)
-        if !path.is_file() {
-            return Err(format!("Path is not a file: {}", file_path));
-        }
-        
-        // Read the file content
-        let content = fs::read_to_string(path)
-            .map_err(|e| format!("Failed to read file: {}", e))?;
-        
-        // Get the file name
-        let file_name = path
-            .file_name()
-            .map(|n| n.to_string_lossy().to_string())
-            .unwrap_or_else(|| "unknown.md".to_string());
-        
-        // Get the absolute path
-        let absolute_path = path
-            .canonicalize()
-            .map(|p| p.to_string_lossy().to_string())
-            .unwrap_or_else(|_| file_path.to_string());
-        
-        Ok((content, file_name, absolute_path))
+        let mut order = std::collections::HashMap::new();
+        order.insert("folder".to_string(), vec!["note-1".to_string(), "note-2".to_string()]);
+        fs.save_note_order(order.clone()).unwrap();
+
+        assert_eq!(fs.get_note_order().unwrap(), order);
+        let leftover_tmp = fs::read_dir(temp_dir.path())
+            .unwrap()
+            .filter_map(|e| e.ok())
+            .any(|e| e.file_name().to_string_lossy().contains(".tmp-"));
+        assert!(!leftover_tmp, "atomic write should not leave a temp file behind");
     }
-}
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-    use proptest::prelude::*;
-    use tempfile::tempdir;
+    #[test]
+    fn test_save_pinned_notes_round_trips_through_toggle() {
+        let temp_dir = tempdir().unwrap();
+        let fs = FileSystem::new_with_base(temp_dir.path()).unwrap();
+        fs.ensure_directories().unwrap();
+
+        let pinned = fs.toggle_pin_note("note-1").unwrap();
+        assert!(pinned);
+        assert_eq!(fs.load_pinned_notes().unwrap(), vec!["note-1".to_string()]);
+
+        let pinned = fs.toggle_pin_note("note-1").unwrap();
+        assert!(!pinned);
+        assert!(fs.load_pinned_notes().unwrap().is_empty());
+    }
 
     #[test]
-    fn test_validate_path_rejects_double_dot() {
+    fn test_layered_settings_local_include_overrides_base_layer() {
         let temp_dir = tempdir().unwrap();
-        let base = temp_dir.path();
-        
-        let result = validate_path(base, "..");
-        assert!(result.is_err());
-        assert!(result.unwrap_err().contains(".."));
+        let fs = FileSystem::new_with_base(temp_dir.path()).unwrap();
+        fs.ensure_directories().unwrap();
+
+        fs::write(fs.base_dir.join("base.layers"), "pinned_notes = a,b\n").unwrap();
+        fs::write(&fs.layers_file, "%include base.layers\npinned_notes = c,d\n").unwrap();
+
+        assert_eq!(fs.load_pinned_notes().unwrap(), vec!["c".to_string(), "d".to_string()]);
     }
 
     #[test]
-    fn test_validate_path_rejects_embedded_double_dot() {
+    fn test_layered_settings_unset_removes_inherited_key() {
         let temp_dir = tempdir().unwrap();
-        let base = temp_dir.path();
-        
-        let result = validate_path(base, "foo..bar");
-        assert!(result.is_err());
+        let fs = FileSystem::new_with_base(temp_dir.path()).unwrap();
+        fs.ensure_directories().unwrap();
+
+        fs::write(fs.base_dir.join("base.layers"), "pinned_notes = a,b\n").unwrap();
+        fs::write(&fs.layers_file, "%include base.layers\n%unset pinned_notes\n").unwrap();
+
+        assert!(fs.load_pinned_notes().unwrap().is_empty());
     }
 
     #[test]
-    fn test_validate_path_rejects_forward_slash() {
+    fn test_layered_settings_detects_include_cycle() {
         let temp_dir = tempdir().unwrap();
-        let base = temp_dir.path();
-        
-        let result = validate_path(base, "foo/bar");
-        assert!(result.is_err());
-        assert!(result.unwrap_err().contains("/"));
+        let fs = FileSystem::new_with_base(temp_dir.path()).unwrap();
+        fs.ensure_directories().unwrap();
+
+        fs::write(fs.base_dir.join("a.layers"), "%include b.layers\n").unwrap();
+        fs::write(fs.base_dir.join("b.layers"), "%include a.layers\n").unwrap();
+        fs::write(&fs.layers_file, "%include a.layers\n").unwrap();
+
+        let err = fs.load_pinned_notes().unwrap_err();
+        assert!(err.contains("Circular"), "expected a circular include error, got: {}", err);
     }
 
     #[test]
-    fn test_validate_path_rejects_backslash() {
+    fn test_layered_settings_rejects_include_chain_past_max_depth() {
         let temp_dir = tempdir().unwrap();
-        let base = temp_dir.path();
-        
-        let result = validate_path(base, "foo\\bar");
-        assert!(result.is_err());
-        assert!(result.unwrap_err().contains("\\"));
+        let fs = FileSystem::new_with_base(temp_dir.path()).unwrap();
+        fs.ensure_directories().unwrap();
+
+        for i in 0..20 {
+            fs::write(fs.base_dir.join(format!("layer-{}.layers", i)), format!("%include layer-{}.layers\n", i + 1)).unwrap();
+        }
+        fs::write(fs.base_dir.join("layer-20.layers"), "pinned_notes = a\n").unwrap();
+        fs::write(&fs.layers_file, "%include layer-0.layers\n").unwrap();
+
+        let err = fs.load_pinned_notes().unwrap_err();
+        assert!(err.contains("include depth"), "expected a max-depth error, got: {}", err);
     }
 
     #[test]
-    fn test_validate_path_accepts_valid_filename() {
+    fn test_get_note_order_merges_layered_override_with_json_fallback() {
         let temp_dir = tempdir().unwrap();
-        let base = temp_dir.path();
-        
-        let result = validate_path(base, "valid-filename.md");
-        assert!(result.is_ok());
+        let fs = FileSystem::new_with_base(temp_dir.path()).unwrap();
+        fs.ensure_directories().unwrap();
+
+        let mut json_order = std::collections::HashMap::new();
+        json_order.insert("work".to_string(), vec!["w1".to_string(), "w2".to_string()]);
+        json_order.insert("personal".to_string(), vec!["p1".to_string()]);
+        fs.save_note_order(json_order).unwrap();
+
+        fs::write(&fs.layers_file, "order.work = w2,w1\n").unwrap();
+
+        let order = fs.get_note_order().unwrap();
+        assert_eq!(order.get("work").unwrap(), &vec!["w2".to_string(), "w1".to_string()]);
+        assert_eq!(order.get("personal").unwrap(), &vec!["p1".to_string()]);
     }
 
     #[test]
-    fn test_validate_path_accepts_filename_with_dots() {
+    fn test_save_note_order_with_layers_file_writes_local_layer_only() {
         let temp_dir = tempdir().unwrap();
-        let base = temp_dir.path();
-        
-        // Single dots in filenames should be allowed (e.g., "file.name.md")
-        let result = validate_path(base, "file.name.md");
-        assert!(result.is_ok());
+        let fs = FileSystem::new_with_base(temp_dir.path()).unwrap();
+        fs.ensure_directories().unwrap();
+
+        fs::write(fs.base_dir.join("base.layers"), "pinned_notes = a\n").unwrap();
+        fs::write(&fs.layers_file, "%include base.layers\n").unwrap();
+
+        let mut order = std::collections::HashMap::new();
+        order.insert(String::new(), vec!["root-1".to_string()]);
+        fs.save_note_order(order).unwrap();
+
+        assert!(!fs.order_file.exists(), "save_note_order should target the local layer, not note-order.json");
+        assert_eq!(fs.get_note_order().unwrap().get("").unwrap(), &vec!["root-1".to_string()]);
+
+        let local_content = fs::read_to_string(&fs.layers_file).unwrap();
+        assert!(local_content.contains("%include base.layers"));
+        assert!(local_content.contains("order.__root__ = root-1"));
     }
 
     #[test]
-    fn test_validate_path_returns_correct_path() {
+    fn test_with_lock_releases_the_flock_after_use() {
         let temp_dir = tempdir().unwrap();
-        let base = temp_dir.path();
-        
-        let result = validate_path(base, "test.md").unwrap();
-        assert!(result.ends_with("test.md"));
+        let fs = FileSystem::new_with_base(temp_dir.path()).unwrap();
+        fs.ensure_directories().unwrap();
+
+        fs.toggle_pin_note("note-1").unwrap();
+
+        // The lock file itself is left in place (a real flock, unlike the
+        // old create_new marker file, doesn't need to be deleted to be
+        // released) - but a fresh with_lock call must succeed, proving the
+        // flock from the first call was released rather than leaked.
+        assert!(fs.base_dir.join("lock").exists());
+        let result: Result<(), String> = fs.with_lock(|| Ok(()));
+        assert!(result.is_ok());
     }
 
-    // Strategy for generating paths containing ".."
-    fn path_with_double_dot() -> impl Strategy<Value = String> {
-        prop_oneof![
-            Just("..".to_string()),
-            "[a-zA-Z0-9_-]{0,10}".prop_map(|prefix| format!("{}..{}", prefix, prefix)),
-            "[a-zA-Z0-9_-]{0,10}".prop_map(|s| format!("..{}", s)),
-            "[a-zA-Z0-9_-]{0,10}".prop_map(|s| format!("{}..", s)),
-        ]
+    #[test]
+    fn test_with_lock_survives_a_stale_lock_file_from_a_killed_process() {
+        let temp_dir = tempdir().unwrap();
+        let fs = FileSystem::new_with_base(temp_dir.path()).unwrap();
+        fs.ensure_directories().unwrap();
+
+        // Simulate a lock file left behind by a process that crashed while
+        // holding it: the file exists on disk, but nothing is flock-ing it.
+        fs::write(fs.base_dir.join("lock"), b"").unwrap();
+
+        let result: Result<(), String> = fs.with_lock(|| Ok(()));
+        assert!(result.is_ok());
     }
 
-    // Strategy for generating paths containing "/"
-    fn path_with_forward_slash() -> impl Strategy<Value = String> {
-        prop_oneof![
-            "[a-zA-Z0-9_-]{1,10}".prop_map(|s| format!("{}/{}", s, s)),
-            "[a-zA-Z0-9_-]{1,10}".prop_map(|s| format!("/{}", s)),
-            "[a-zA-Z0-9_-]{1,10}".prop_map(|s| format!("{}/", s)),
-        ]
+    #[test]
+    fn test_with_lock_rejects_reentry_while_already_held() {
+        let temp_dir = tempdir().unwrap();
+        let fs = FileSystem::new_with_base(temp_dir.path()).unwrap();
+        fs.ensure_directories().unwrap();
+
+        let result: Result<(), String> = fs.with_lock(|| fs.with_lock(|| Ok(())));
+
+        assert!(result.is_err());
+        assert!(result.unwrap_err().contains("Another mded instance"));
     }
 
-    // Strategy for generating paths containing "\\"
-    fn path_with_backslash() -> impl Strategy<Value = String> {
-        prop_oneof![
-            "[a-zA-Z0-9_-]{1,10}".prop_map(|s| format!("{}\\{}", s, s)),
-            "[a-zA-Z0-9_-]{1,10}".prop_map(|s| format!("\\{}", s)),
-            "[a-zA-Z0-9_-]{1,10}".prop_map(|s| format!("{}\\", s)),
-        ]
+    #[test]
+    fn test_new_honors_mded_data_dir_override() {
+        let temp_dir = tempdir().unwrap();
+
+        // SAFETY: this test owns the env var it sets and clears it before
+        // returning, and no other test reads MDED_DATA_DIR.
+        std::env::set_var("MDED_DATA_DIR", temp_dir.path());
+        let fs = FileSystem::new().unwrap();
+        std::env::remove_var("MDED_DATA_DIR");
+
+        assert_eq!(fs.base_dir, temp_dir.path());
     }
 
-    proptest! {
-        #![proptest_config(ProptestConfig::with_cases(100))]
+    #[test]
+    fn test_new_rejects_relative_mded_data_dir() {
+        // SAFETY: see test_new_honors_mded_data_dir_override.
+        std::env::set_var("MDED_DATA_DIR", "relative/path");
+        let result = FileSystem::new();
+        std::env::remove_var("MDED_DATA_DIR");
 
-        /// **Feature: mded-tauri-migration, Property 5: Path Traversal Rejection**
-        /// **Validates: Requirements 13.1, 13.2, 13.3**
-        /// 
-        /// For any path string containing "..", "/" or "\\" characters,
-        /// the path validator should reject it with an error.
-        #[test]
-        fn prop_path_traversal_rejection_double_dot(path in path_with_double_dot()) {
-            let temp_dir = tempdir().unwrap();
-            let base = temp_dir.path();
-            
-            let result = validate_path(base, &path);
-            prop_assert!(result.is_err(), "Path '{}' should be rejected but was accepted", path);
-            prop_assert!(
-                result.as_ref().unwrap_err().contains(".."),
-                "Error message should mention '..' for path '{}'",
-                path
-            );
-        }
+        assert!(result.is_err());
+        assert!(result.unwrap_err().contains("absolute path"));
+    }
 
-        /// **Feature: mded-tauri-migration, Property 5: Path Traversal Rejection**
-        /// **Validates: Requirements 13.1, 13.2, 13.3**
-        #[test]
-        fn prop_path_traversal_rejection_forward_slash(path in path_with_forward_slash()) {
-            let temp_dir = tempdir().unwrap();
-            let base = temp_dir.path();
-            
-            let result = validate_path(base, &path);
-            prop_assert!(result.is_err(), "Path '{}' should be rejected but was accepted", path);
-            prop_assert!(
-                result.as_ref().unwrap_err().contains("/"),
-                "Error message should mention '/' for path '{}'",
-                path
-            );
-        }
+    #[test]
+    fn test_ensure_directories_writes_requirements_manifest() {
+        let temp_dir = tempdir().unwrap();
+        let fs = FileSystem::new_with_base(temp_dir.path()).unwrap();
+        fs.ensure_directories().unwrap();
 
-        /// **Feature: mded-tauri-migration, Property 5: Path Traversal Rejection**
-        /// **Validates: Requirements 13.1, 13.2, 13.3**
-        #[test]
-        fn prop_path_traversal_rejection_backslash(path in path_with_backslash()) {
-            let temp_dir = tempdir().unwrap();
-            let base = temp_dir.path();
-            
-            let result = validate_path(base, &path);
-            prop_assert!(result.is_err(), "Path '{}' should be rejected but was accepted", path);
-            prop_assert!(
-                result.as_ref().unwrap_err().contains("\\"),
-                "Error message should mention '\\' for path '{}'",
-                path
-            );
-        }
+        assert!(fs.requirements_file.exists());
+        fs.check_requirements().unwrap();
     }
 
-    // FileSystem tests
     #[test]
-    fn test_filesystem_new_with_base() {
+    fn test_check_requirements_rejects_newer_version() {
         let temp_dir = tempdir().unwrap();
         let fs = FileSystem::new_with_base(temp_dir.path()).unwrap();
-        
-        assert_eq!(fs.base_dir, temp_dir.path());
-        assert_eq!(fs.notes_dir, temp_dir.path().join("notes"));
-        assert_eq!(fs.assets_dir, temp_dir.path().join("assets"));
-        assert_eq!(fs.config_file, temp_dir.path().join("config.json"));
-        assert_eq!(fs.order_file, temp_dir.path().join("note-order.json"));
+        fs.ensure_directories().unwrap();
+
+        let future = crate::models::DataDirRequirements { version: crate::models::DATA_DIR_VERSION + 1, features: vec![] };
+        fs.write_requirements(&future).unwrap();
+
+        let err = fs.check_requirements().unwrap_err();
+        assert!(err.contains("newer"));
     }
 
     #[test]
-    fn test_filesystem_ensure_directories() {
+    fn test_check_requirements_rejects_unknown_feature() {
         let temp_dir = tempdir().unwrap();
         let fs = FileSystem::new_with_base(temp_dir.path()).unwrap();
-        
-        // Directories should not exist yet
-        assert!(!fs.notes_dir.exists());
-        assert!(!fs.assets_dir.exists());
-        
-        // Create directories
         fs.ensure_directories().unwrap();
-        
-        // Directories should now exist
-        assert!(fs.notes_dir.exists());
-        assert!(fs.assets_dir.exists());
-        assert!(fs.notes_dir.is_dir());
-        assert!(fs.assets_dir.is_dir());
+
+        let with_unknown_feature = crate::models::DataDirRequirements {
+            version: crate::models::DATA_DIR_VERSION,
+            features: vec!["time-travel".to_string()],
+        };
+        fs.write_requirements(&with_unknown_feature).unwrap();
+
+        let err = fs.check_requirements().unwrap_err();
+        assert!(err.contains("time-travel"));
     }
 
     #[test]
-    fn test_filesystem_ensure_directories_idempotent() {
+    fn test_migrate_if_needed_adopts_directory_missing_requirements_file() {
         let temp_dir = tempdir().unwrap();
         let fs = FileSystem::new_with_base(temp_dir.path()).unwrap();
-        
-        // Call ensure_directories multiple times
-        fs.ensure_directories().unwrap();
-        fs.ensure_directories().unwrap();
-        
-        // Should still work
-        assert!(fs.notes_dir.exists());
-        assert!(fs.assets_dir.exists());
+        fs::create_dir_all(&fs.notes_dir).unwrap();
+        assert!(!fs.requirements_file.exists());
+
+        fs.migrate_if_needed().unwrap();
+
+        assert!(fs.requirements_file.exists());
+        fs.check_requirements().unwrap();
     }
 
     #[test]
-    fn test_filesystem_validate_notes_path() {
+    fn test_delete_folder_not_exists() {
         let temp_dir = tempdir().unwrap();
         let fs = FileSystem::new_with_base(temp_dir.path()).unwrap();
         fs.ensure_directories().unwrap();
-        
-        // Valid path should work
-        let result = fs.validate_notes_path("test.md");
-        assert!(result.is_ok());
-        
-        // Invalid path should fail
-        let result = fs.validate_notes_path("../test.md");
+
+        let result = fs.delete_folder("nonexistent", false, false);
+
         assert!(result.is_err());
+        assert!(result.unwrap_err().contains("does not exist"));
     }
 
     #[test]
-    fn test_filesystem_validate_assets_path() {
+    fn test_delete_folder_not_exists_suggests_close_match() {
         let temp_dir = tempdir().unwrap();
         let fs = FileSystem::new_with_base(temp_dir.path()).unwrap();
         fs.ensure_directories().unwrap();
-        
-        // Valid path should work
-        let result = fs.validate_assets_path("screenshot.png");
-        assert!(result.is_ok());
-        
-        // Invalid path should fail
-        let result = fs.validate_assets_path("../screenshot.png");
-        assert!(result.is_err());
+
+        fs.create_folder("Projects").unwrap();
+        let err = fs.delete_folder("Project", false, false).unwrap_err();
+
+        assert!(err.contains("does not exist"));
+        assert!(err.contains("did you mean 'Projects'?"), "unexpected error: {}", err);
     }
 
     #[test]
-    fn test_filesystem_get_folder_path() {
+    fn test_read_note_not_found_suggests_close_match() {
         let temp_dir = tempdir().unwrap();
         let fs = FileSystem::new_with_base(temp_dir.path()).unwrap();
-        
-        // None should return notes_dir
-        assert_eq!(fs.get_folder_path(None), fs.notes_dir);
-        
-        // Empty string should return notes_dir
-        assert_eq!(fs.get_folder_path(Some("")), fs.notes_dir);
-        
-        // Folder name should return subfolder
-        assert_eq!(fs.get_folder_path(Some("work")), fs.notes_dir.join("work"));
+        fs.ensure_directories().unwrap();
+
+        let (note_id, _) = fs.create_note(None).unwrap();
+        let typo = format!("{}x", &note_id[..note_id.len() - 1]);
+        let err = fs.read_note(&typo, None).unwrap_err();
+
+        assert!(err.contains("does not exist"));
+        assert!(err.contains(&format!("did you mean '{}'?", note_id)), "unexpected error: {}", err);
     }
 
-    // Folder operations tests
     #[test]
-    fn test_list_folders_empty() {
+    fn test_levenshtein_distance_basic_cases() {
+        assert_eq!(levenshtein_distance("kitten", "sitting"), 3);
+        assert_eq!(levenshtein_distance("same", "same"), 0);
+        assert_eq!(levenshtein_distance("", "abc"), 3);
+    }
+
+    #[test]
+    fn test_did_you_mean_ignores_distant_candidates() {
+        assert_eq!(did_you_mean("Projects", vec!["Archive", "Personal"]), None);
+        assert_eq!(did_you_mean("Projects", vec!["Project", "Archive"]), Some("Project"));
+    }
+
+    #[test]
+    fn test_is_folder_empty_ignores_hidden_entries() {
         let temp_dir = tempdir().unwrap();
         let fs = FileSystem::new_with_base(temp_dir.path()).unwrap();
         fs.ensure_directories().unwrap();
-        
-        let folders = fs.list_folders().unwrap();
-        
-        // Should have exactly one folder: "All Notes"
-        assert_eq!(folders.len(), 1);
-        assert_eq!(folders[0].name, "All Notes");
+
+        fs.create_folder("folder").unwrap();
+        assert!(fs.is_folder_empty("folder").unwrap());
+
+        std::fs::write(fs.notes_dir.join("folder/.DS_Store"), "").unwrap();
+        assert!(fs.is_folder_empty("folder").unwrap());
+
+        std::fs::write(fs.notes_dir.join("folder/note.md"), "content").unwrap();
+        assert!(!fs.is_folder_empty("folder").unwrap());
     }
 
     #[test]
-    fn test_list_folders_with_subfolders() {
+    fn test_delete_folder_refuses_non_empty_folder_without_recursive() {
         let temp_dir = tempdir().unwrap();
         let fs = FileSystem::new_with_base(temp_dir.path()).unwrap();
         fs.ensure_directories().unwrap();
-        
-        // Create some folders
-        std::fs::create_dir(fs.notes_dir.join("work")).unwrap();
-        std::fs::create_dir(fs.notes_dir.join("personal")).unwrap();
-        
-        let folders = fs.list_folders().unwrap();
-        
-        // Should have 3 folders: "All Notes" + 2 created
-        assert_eq!(folders.len(), 3);
-        assert_eq!(folders[0].name, "All Notes");
-        
-        // Other folders should be present (order may vary)
-        let folder_names: Vec<&str> = folders.iter().map(|f| f.name.as_str()).collect();
-        assert!(folder_names.contains(&"work"));
-        assert!(folder_names.contains(&"personal"));
+
+        fs.create_folder("folder").unwrap();
+        std::fs::write(fs.notes_dir.join("folder/note.md"), "content").unwrap();
+
+        let result = fs.delete_folder("folder", false, false);
+        assert!(result.is_err());
+        let message = result.unwrap_err();
+        assert!(message.contains("is not empty"));
+        assert!(message.contains("recursive=true"));
+        assert!(fs.notes_dir.join("folder").exists());
     }
 
     #[test]
-    fn test_create_folder() {
+    fn test_delete_folder_recursive_removes_non_empty_folder() {
         let temp_dir = tempdir().unwrap();
         let fs = FileSystem::new_with_base(temp_dir.path()).unwrap();
         fs.ensure_directories().unwrap();
-        
-        fs.create_folder("test-folder").unwrap();
-        
-        assert!(fs.notes_dir.join("test-folder").exists());
-        assert!(fs.notes_dir.join("test-folder").is_dir());
+
+        fs.create_folder("folder").unwrap();
+        std::fs::write(fs.notes_dir.join("folder/note.md"), "content").unwrap();
+
+        fs.delete_folder("folder", false, true).unwrap();
+        assert!(!fs.notes_dir.join("folder").exists());
     }
 
     #[test]
-    fn test_create_folder_already_exists() {
+    fn test_find_empty_folders_is_bottom_up_and_skips_non_empty_branches() {
         let temp_dir = tempdir().unwrap();
         let fs = FileSystem::new_with_base(temp_dir.path()).unwrap();
         fs.ensure_directories().unwrap();
-        
-        fs.create_folder("test-folder").unwrap();
-        let result = fs.create_folder("test-folder");
-        
-        assert!(result.is_err());
-        assert!(result.unwrap_err().contains("already exists"));
+
+        // Wholly empty nested chain.
+        fs.create_folder("Empty/Nested").unwrap();
+        // Has a note at the leaf, so neither it nor its parent are empty.
+        fs.create_folder("HasNote/Nested").unwrap();
+        std::fs::write(fs.notes_dir.join("HasNote/Nested/note.md"), "content").unwrap();
+
+        let found = fs.find_empty_folders().unwrap();
+
+        assert!(found.contains(&"Empty/Nested".to_string()));
+        assert!(found.contains(&"Empty".to_string()));
+        assert!(!found.contains(&"HasNote".to_string()));
+        assert!(!found.contains(&"HasNote/Nested".to_string()));
+
+        // Deepest-first: a folder's children appear before the folder itself.
+        let empty_index = found.iter().position(|p| p == "Empty").unwrap();
+        let nested_index = found.iter().position(|p| p == "Empty/Nested").unwrap();
+        assert!(nested_index < empty_index);
     }
 
     #[test]
-    fn test_delete_folder() {
+    fn test_remove_empty_folders_sweeps_the_whole_tree() {
         let temp_dir = tempdir().unwrap();
         let fs = FileSystem::new_with_base(temp_dir.path()).unwrap();
         fs.ensure_directories().unwrap();
-        
-        fs.create_folder("to-delete").unwrap();
-        assert!(fs.notes_dir.join("to-delete").exists());
-        
-        fs.delete_folder("to-delete").unwrap();
-        assert!(!fs.notes_dir.join("to-delete").exists());
+
+        fs.create_folder("Empty/Nested").unwrap();
+        fs.create_folder("HasNote").unwrap();
+        std::fs::write(fs.notes_dir.join("HasNote/note.md"), "content").unwrap();
+
+        let removed = fs.remove_empty_folders().unwrap();
+
+        assert!(!fs.notes_dir.join("Empty").exists());
+        assert!(fs.notes_dir.join("HasNote").exists());
+        assert_eq!(removed.len(), 2);
     }
 
     #[test]
-    fn test_delete_folder_not_exists() {
+    fn test_matches_wildcard() {
+        assert!(matches_wildcard("*", "anything"));
+        assert!(matches_wildcard("Project-*", "Project-Alpha"));
+        assert!(!matches_wildcard("Project-*", "Archive-Alpha"));
+        assert!(matches_wildcard("note?.md", "note1.md"));
+        assert!(!matches_wildcard("note?.md", "note10.md"));
+        assert!(matches_wildcard("*2024*", "Projects-2024-Archive"));
+    }
+
+    #[test]
+    fn test_delete_folders_applies_to_every_top_level_match_independently() {
         let temp_dir = tempdir().unwrap();
         let fs = FileSystem::new_with_base(temp_dir.path()).unwrap();
         fs.ensure_directories().unwrap();
-        
-        let result = fs.delete_folder("nonexistent");
-        
-        assert!(result.is_err());
-        assert!(result.unwrap_err().contains("does not exist"));
+
+        fs.create_folder("Project-Alpha").unwrap();
+        fs.create_folder("Project-Beta").unwrap();
+        fs.create_folder("Archive").unwrap();
+        std::fs::write(fs.notes_dir.join("Project-Beta/note.md"), "content").unwrap();
+
+        let result = fs.delete_folders("Project-*", false).unwrap();
+
+        assert!(result.succeeded.contains(&"Project-Alpha".to_string()));
+        assert_eq!(result.failed.len(), 1);
+        assert_eq!(result.failed[0].0, "Project-Beta");
+        assert!(!fs.notes_dir.join("Project-Alpha").exists());
+        assert!(fs.notes_dir.join("Project-Beta").exists());
+        assert!(fs.notes_dir.join("Archive").exists());
+    }
+
+    #[test]
+    fn test_rename_folders_applies_template_to_every_top_level_match() {
+        let temp_dir = tempdir().unwrap();
+        let fs = FileSystem::new_with_base(temp_dir.path()).unwrap();
+        fs.ensure_directories().unwrap();
+
+        fs.create_folder("Alpha").unwrap();
+        fs.create_folder("Beta").unwrap();
+        fs.create_folder("Other").unwrap();
+
+        let result = fs.rename_folders("A*", "Archived-{}").unwrap();
+
+        assert_eq!(result.succeeded, vec!["Alpha".to_string()]);
+        assert!(fs.notes_dir.join("Archived-Alpha").is_dir());
+        assert!(fs.notes_dir.join("Beta").is_dir());
+        assert!(fs.notes_dir.join("Other").is_dir());
     }
 
     #[test]
@@ -1359,7 +6081,7 @@ mod tests {
         fs.ensure_directories().unwrap();
         
         fs.create_folder("old-name").unwrap();
-        fs.rename_folder("old-name", "new-name").unwrap();
+        fs.rename_folder("old-name", "new-name", false, false).unwrap();
         
         assert!(!fs.notes_dir.join("old-name").exists());
         assert!(fs.notes_dir.join("new-name").exists());
@@ -1377,7 +6099,7 @@ mod tests {
         let file_path = fs.notes_dir.join("old-name").join("test.md");
         std::fs::write(&file_path, "test content").unwrap();
         
-        fs.rename_folder("old-name", "new-name").unwrap();
+        fs.rename_folder("old-name", "new-name", false, false).unwrap();
         
         // File should exist in new location
         let new_file_path = fs.notes_dir.join("new-name").join("test.md");
@@ -1385,6 +6107,149 @@ mod tests {
         assert_eq!(std::fs::read_to_string(new_file_path).unwrap(), "test content");
     }
 
+    #[test]
+    fn test_rename_folder_without_flags_rejects_existing_destination() {
+        let temp_dir = tempdir().unwrap();
+        let fs = FileSystem::new_with_base(temp_dir.path()).unwrap();
+        fs.ensure_directories().unwrap();
+
+        fs.create_folder("old-name").unwrap();
+        fs.create_folder("new-name").unwrap();
+
+        let result = fs.rename_folder("old-name", "new-name", false, false);
+        assert!(result.is_err());
+        assert!(result.unwrap_err().contains("already exists"));
+    }
+
+    #[test]
+    fn test_rename_folder_overwrite_replaces_existing_destination() {
+        let temp_dir = tempdir().unwrap();
+        let fs = FileSystem::new_with_base(temp_dir.path()).unwrap();
+        fs.ensure_directories().unwrap();
+
+        fs.create_folder("old-name").unwrap();
+        std::fs::write(fs.notes_dir.join("old-name/keep.md"), "keep").unwrap();
+        fs.create_folder("new-name").unwrap();
+        std::fs::write(fs.notes_dir.join("new-name/discard.md"), "discard").unwrap();
+
+        fs.rename_folder("old-name", "new-name", true, false).unwrap();
+
+        assert!(!fs.notes_dir.join("old-name").exists());
+        assert!(fs.notes_dir.join("new-name/keep.md").exists());
+        assert!(!fs.notes_dir.join("new-name/discard.md").exists());
+    }
+
+    #[test]
+    fn test_rename_folder_merge_moves_notes_and_suffixes_name_collisions() {
+        let temp_dir = tempdir().unwrap();
+        let fs = FileSystem::new_with_base(temp_dir.path()).unwrap();
+        fs.ensure_directories().unwrap();
+
+        fs.create_folder("old-name").unwrap();
+        std::fs::write(fs.notes_dir.join("old-name/unique.md"), "unique").unwrap();
+        std::fs::write(fs.notes_dir.join("old-name/note.md"), "from old").unwrap();
+
+        fs.create_folder("new-name").unwrap();
+        std::fs::write(fs.notes_dir.join("new-name/note.md"), "from new").unwrap();
+
+        fs.rename_folder("old-name", "new-name", false, true).unwrap();
+
+        assert!(!fs.notes_dir.join("old-name").exists());
+        assert!(fs.notes_dir.join("new-name/unique.md").exists());
+        // The original destination file is untouched...
+        assert_eq!(
+            std::fs::read_to_string(fs.notes_dir.join("new-name/note.md")).unwrap(),
+            "from new"
+        );
+        // ...and the incoming colliding file was suffixed instead of overwriting it.
+        assert_eq!(
+            std::fs::read_to_string(fs.notes_dir.join("new-name/note (1).md")).unwrap(),
+            "from old"
+        );
+    }
+
+    #[test]
+    fn test_copy_folder_recreates_tree_and_leaves_source_intact() {
+        let temp_dir = tempdir().unwrap();
+        let fs = FileSystem::new_with_base(temp_dir.path()).unwrap();
+        fs.ensure_directories().unwrap();
+
+        fs.create_folder("source/nested").unwrap();
+        std::fs::write(fs.notes_dir.join("source/top.md"), "top").unwrap();
+        std::fs::write(fs.notes_dir.join("source/nested/deep.md"), "deep").unwrap();
+
+        let count = fs.copy_folder("source", "dest", false).unwrap();
+
+        assert_eq!(count, 2);
+        assert!(fs.notes_dir.join("source/top.md").exists());
+        assert_eq!(std::fs::read_to_string(fs.notes_dir.join("dest/top.md")).unwrap(), "top");
+        assert_eq!(std::fs::read_to_string(fs.notes_dir.join("dest/nested/deep.md")).unwrap(), "deep");
+    }
+
+    #[test]
+    fn test_copy_folder_without_overwrite_rejects_colliding_file() {
+        let temp_dir = tempdir().unwrap();
+        let fs = FileSystem::new_with_base(temp_dir.path()).unwrap();
+        fs.ensure_directories().unwrap();
+
+        fs.create_folder("source").unwrap();
+        std::fs::write(fs.notes_dir.join("source/note.md"), "new").unwrap();
+        fs.create_folder("dest").unwrap();
+        std::fs::write(fs.notes_dir.join("dest/note.md"), "old").unwrap();
+
+        let result = fs.copy_folder("source", "dest", false);
+
+        assert!(result.is_err());
+        assert_eq!(std::fs::read_to_string(fs.notes_dir.join("dest/note.md")).unwrap(), "old");
+    }
+
+    #[test]
+    fn test_copy_folder_rejects_copy_into_own_descendant() {
+        let temp_dir = tempdir().unwrap();
+        let fs = FileSystem::new_with_base(temp_dir.path()).unwrap();
+        fs.ensure_directories().unwrap();
+
+        fs.create_folder("source").unwrap();
+
+        let result = fs.copy_folder("source", "source/nested", false);
+
+        assert!(result.is_err());
+        assert!(result.unwrap_err().contains("itself"));
+    }
+
+    #[test]
+    fn test_move_folder_uses_rename_fast_path_when_destination_is_free() {
+        let temp_dir = tempdir().unwrap();
+        let fs = FileSystem::new_with_base(temp_dir.path()).unwrap();
+        fs.ensure_directories().unwrap();
+
+        fs.create_folder("source").unwrap();
+        std::fs::write(fs.notes_dir.join("source/note.md"), "hi").unwrap();
+
+        let count = fs.move_folder("source", "dest", false).unwrap();
+
+        assert_eq!(count, 1);
+        assert!(!fs.notes_dir.join("source").exists());
+        assert_eq!(std::fs::read_to_string(fs.notes_dir.join("dest/note.md")).unwrap(), "hi");
+    }
+
+    #[test]
+    fn test_move_folder_falls_back_to_copy_when_destination_exists() {
+        let temp_dir = tempdir().unwrap();
+        let fs = FileSystem::new_with_base(temp_dir.path()).unwrap();
+        fs.ensure_directories().unwrap();
+
+        fs.create_folder("source").unwrap();
+        std::fs::write(fs.notes_dir.join("source/note.md"), "hi").unwrap();
+        fs.create_folder("dest").unwrap();
+
+        let count = fs.move_folder("source", "dest", false).unwrap();
+
+        assert_eq!(count, 1);
+        assert!(!fs.notes_dir.join("source").exists());
+        assert_eq!(std::fs::read_to_string(fs.notes_dir.join("dest/note.md")).unwrap(), "hi");
+    }
+
     // Strategy for generating valid folder names (no path separators or traversal)
     fn valid_folder_name() -> impl Strategy<Value = String> {
         "[a-zA-Z][a-zA-Z0-9_-]{0,20}".prop_filter("Must not be empty", |s| !s.is_empty())
@@ -1450,8 +6315,52 @@ mod tests {
                 folders.iter().map(|f| f.name.as_str()).collect();
             for name in &folder_names {
                 prop_assert!(
-                    folder_name_set.contains(name.as_str()),
-                    "Folder '{}' should be in the list",
+                    folder_name_set.contains(name.as_str()),
+                    "Folder '{}' should be in the list",
+                    name
+                );
+            }
+        }
+
+        /// **Feature: mded-tauri-migration, Property: .mdedignore Hides Matching Folders**
+        /// **Validates: Requirements 10.1**
+        ///
+        /// For any set of folders, marking one of them ignored via
+        /// `.mdedignore` should remove it (and its notes) from
+        /// `list_folders`/`list_notes`, while every other folder and its
+        /// notes remain visible.
+        #[test]
+        fn prop_mdedignore_hides_only_the_ignored_folder(folder_names in unique_folder_names(5)) {
+            prop_assume!(!folder_names.is_empty());
+
+            let temp_dir = tempdir().unwrap();
+            let fs = FileSystem::new_with_base(temp_dir.path()).unwrap();
+            fs.ensure_directories().unwrap();
+
+            for name in &folder_names {
+                fs.create_folder(name).unwrap();
+                fs.save_note(&format!("{}-note", name), "content", Some(name)).unwrap();
+            }
+
+            let ignored = &folder_names[0];
+            std::fs::write(fs.notes_dir.join(".mdedignore"), format!("{}/\n", ignored)).unwrap();
+
+            let folder_names_after: std::collections::HashSet<String> =
+                fs.list_folders().unwrap().into_iter().map(|f| f.name).collect();
+            let notes_after = fs.list_notes(None).unwrap();
+
+            prop_assert!(!folder_names_after.contains(ignored));
+            prop_assert!(!notes_after.iter().any(|n| &n.folder == ignored));
+
+            for name in folder_names.iter().skip(1) {
+                prop_assert!(
+                    folder_names_after.contains(name),
+                    "Non-ignored folder '{}' should still be listed",
+                    name
+                );
+                prop_assert!(
+                    notes_after.iter().any(|n| &n.folder == name),
+                    "Notes in non-ignored folder '{}' should still be listed",
                     name
                 );
             }
@@ -1490,7 +6399,7 @@ mod tests {
             }
             
             // Delete the folder
-            fs.delete_folder(&folder_name).unwrap();
+            fs.delete_folder(&folder_name, true, true).unwrap();
             
             // Verify folder no longer exists
             prop_assert!(!folder_path.exists(), "Folder should not exist after deletion");
@@ -2082,8 +6991,473 @@ mod tests {
         std::fs::create_dir(&dir_path).unwrap();
         
         let result = fs.read_external_file(dir_path.to_str().unwrap());
-        
+
         assert!(result.is_err());
         assert!(result.unwrap_err().contains("not a file"));
     }
+
+    #[test]
+    fn test_read_external_file_sniffed_accepts_extensionless_markdown() {
+        let temp_dir = tempdir().unwrap();
+        let fs = FileSystem::new_with_base(temp_dir.path()).unwrap();
+
+        let file_path = temp_dir.path().join("README");
+        std::fs::write(&file_path, "# Hello\nplain text notes").unwrap();
+
+        let (content, name, _) = fs.read_external_file_sniffed(file_path.to_str().unwrap()).unwrap();
+        assert_eq!(content, "# Hello\nplain text notes");
+        assert_eq!(name, "README");
+    }
+
+    #[test]
+    fn test_read_external_file_sniffed_accepts_markdown_extension_variants() {
+        let temp_dir = tempdir().unwrap();
+        let fs = FileSystem::new_with_base(temp_dir.path()).unwrap();
+
+        for ext in ["markdown", "mdown", "mkd"] {
+            let file_path = temp_dir.path().join(format!("notes.{}", ext));
+            std::fs::write(&file_path, "content").unwrap();
+            assert!(fs.read_external_file_sniffed(file_path.to_str().unwrap()).is_ok());
+        }
+    }
+
+    #[test]
+    fn test_read_external_file_sniffed_rejects_png_signature() {
+        let temp_dir = tempdir().unwrap();
+        let fs = FileSystem::new_with_base(temp_dir.path()).unwrap();
+
+        let file_path = temp_dir.path().join("screenshot");
+        let mut png_bytes = vec![0x89, b'P', b'N', b'G', 0x0D, 0x0A, 0x1A, 0x0A];
+        png_bytes.extend_from_slice(&[0u8; 32]);
+        std::fs::write(&file_path, &png_bytes).unwrap();
+
+        let result = fs.read_external_file_sniffed(file_path.to_str().unwrap());
+        assert!(result.is_err());
+        assert!(result.unwrap_err().contains("does not look like a Markdown file"));
+    }
+
+    #[test]
+    fn test_read_external_file_sniffed_rejects_binary_without_signature() {
+        let temp_dir = tempdir().unwrap();
+        let fs = FileSystem::new_with_base(temp_dir.path()).unwrap();
+
+        let file_path = temp_dir.path().join("data");
+        std::fs::write(&file_path, [0u8, 1, 2, 3, 255, 254]).unwrap();
+
+        let result = fs.read_external_file_sniffed(file_path.to_str().unwrap());
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_read_external_file_with_checksum_crc32_matches_same_content() {
+        let temp_dir = tempdir().unwrap();
+        let fs = FileSystem::new_with_base(temp_dir.path()).unwrap();
+
+        let file_path = temp_dir.path().join("note.md");
+        std::fs::write(&file_path, "# Hello\nworld").unwrap();
+
+        let (content, _name, _path, checksum) = fs
+            .read_external_file_with_checksum(file_path.to_str().unwrap(), ChecksumAlgorithm::Crc32)
+            .unwrap();
+        assert_eq!(content, "# Hello\nworld");
+        assert_eq!(checksum.algorithm, ChecksumAlgorithm::Crc32);
+
+        assert!(fs.verify_checksum(file_path.to_str().unwrap(), &checksum).unwrap());
+    }
+
+    #[test]
+    fn test_read_external_file_with_checksum_sha256_detects_external_modification() {
+        let temp_dir = tempdir().unwrap();
+        let fs = FileSystem::new_with_base(temp_dir.path()).unwrap();
+
+        let file_path = temp_dir.path().join("note.md");
+        std::fs::write(&file_path, "original").unwrap();
+
+        let (_content, _name, _path, checksum) = fs
+            .read_external_file_with_checksum(file_path.to_str().unwrap(), ChecksumAlgorithm::Sha256)
+            .unwrap();
+
+        std::fs::write(&file_path, "changed on disk").unwrap();
+
+        assert!(!fs.verify_checksum(file_path.to_str().unwrap(), &checksum).unwrap());
+    }
+
+    #[test]
+    fn test_crc32_and_sha256_checksums_differ_for_same_content() {
+        let temp_dir = tempdir().unwrap();
+        let fs = FileSystem::new_with_base(temp_dir.path()).unwrap();
+
+        let file_path = temp_dir.path().join("note.md");
+        std::fs::write(&file_path, "same content").unwrap();
+
+        let (_, _, _, crc) = fs
+            .read_external_file_with_checksum(file_path.to_str().unwrap(), ChecksumAlgorithm::Crc32)
+            .unwrap();
+        let (_, _, _, sha) = fs
+            .read_external_file_with_checksum(file_path.to_str().unwrap(), ChecksumAlgorithm::Sha256)
+            .unwrap();
+
+        assert_ne!(crc.digest, sha.digest);
+    }
+
+    #[test]
+    #[cfg(unix)]
+    fn test_read_external_file_with_symlink_policy_reject_refuses_symlink() {
+        let temp_dir = tempdir().unwrap();
+        let fs = FileSystem::new_with_base(temp_dir.path()).unwrap();
+
+        let real_path = temp_dir.path().join("real.md");
+        std::fs::write(&real_path, "content").unwrap();
+        let link_path = temp_dir.path().join("link.md");
+        std::os::unix::fs::symlink(&real_path, &link_path).unwrap();
+
+        let result = fs.read_external_file_with_symlink_policy(link_path.to_str().unwrap(), &SymlinkPolicy::Reject);
+        assert!(result.is_err());
+        assert!(result.unwrap_err().contains("symlink"));
+    }
+
+    #[test]
+    #[cfg(unix)]
+    fn test_read_external_file_with_symlink_policy_follow_reads_through_symlink() {
+        let temp_dir = tempdir().unwrap();
+        let fs = FileSystem::new_with_base(temp_dir.path()).unwrap();
+
+        let real_path = temp_dir.path().join("real.md");
+        std::fs::write(&real_path, "content").unwrap();
+        let link_path = temp_dir.path().join("link.md");
+        std::os::unix::fs::symlink(&real_path, &link_path).unwrap();
+
+        let (content, _name, absolute_path) = fs
+            .read_external_file_with_symlink_policy(link_path.to_str().unwrap(), &SymlinkPolicy::Follow)
+            .unwrap();
+
+        assert_eq!(content, "content");
+        assert_eq!(Path::new(&absolute_path), real_path.canonicalize().unwrap());
+    }
+
+    #[test]
+    #[cfg(unix)]
+    fn test_read_external_file_with_symlink_policy_follow_within_base_rejects_escape() {
+        let temp_dir = tempdir().unwrap();
+        let outside_dir = tempdir().unwrap();
+        let fs = FileSystem::new_with_base(temp_dir.path()).unwrap();
+
+        let real_path = outside_dir.path().join("real.md");
+        std::fs::write(&real_path, "content").unwrap();
+        let link_path = temp_dir.path().join("link.md");
+        std::os::unix::fs::symlink(&real_path, &link_path).unwrap();
+
+        let policy = SymlinkPolicy::FollowWithinBase(temp_dir.path().to_path_buf());
+        let result = fs.read_external_file_with_symlink_policy(link_path.to_str().unwrap(), &policy);
+
+        assert!(result.is_err());
+        assert!(result.unwrap_err().contains("outside the allowed directory"));
+    }
+
+    #[test]
+    #[cfg(unix)]
+    fn test_read_external_file_with_symlink_policy_rejects_symlink_loop_with_clear_message() {
+        let temp_dir = tempdir().unwrap();
+        let fs = FileSystem::new_with_base(temp_dir.path()).unwrap();
+
+        let loop_path = temp_dir.path().join("loop.md");
+        std::os::unix::fs::symlink(&loop_path, &loop_path).unwrap();
+
+        let result = fs.read_external_file_with_symlink_policy(loop_path.to_str().unwrap(), &SymlinkPolicy::Follow);
+
+        assert!(result.is_err());
+        let message = result.unwrap_err();
+        assert!(message.contains("Failed to resolve"), "unexpected error message: {}", message);
+    }
+
+    #[test]
+    #[cfg(unix)]
+    fn test_read_external_file_with_symlink_policy_rejects_broken_symlink_with_clear_message() {
+        let temp_dir = tempdir().unwrap();
+        let fs = FileSystem::new_with_base(temp_dir.path()).unwrap();
+
+        let missing_target = temp_dir.path().join("does-not-exist.md");
+        let link_path = temp_dir.path().join("broken.md");
+        std::os::unix::fs::symlink(&missing_target, &link_path).unwrap();
+
+        let result = fs.read_external_file_with_symlink_policy(link_path.to_str().unwrap(), &SymlinkPolicy::Follow);
+
+        assert!(result.is_err());
+        let message = result.unwrap_err();
+        assert!(message.contains("Failed to resolve"), "unexpected error message: {}", message);
+    }
+
+    #[test]
+    fn test_import_directory_finds_every_markdown_file_in_nested_tree() {
+        let temp_dir = tempdir().unwrap();
+        let fs = FileSystem::new_with_base(temp_dir.path()).unwrap();
+
+        let import_root = temp_dir.path().join("vault");
+        std::fs::create_dir_all(import_root.join("Projects/2024")).unwrap();
+        std::fs::write(import_root.join("top.md"), "# Top").unwrap();
+        std::fs::write(import_root.join("Projects/plan.md"), "# Plan").unwrap();
+        std::fs::write(import_root.join("Projects/2024/research.md"), "# Research").unwrap();
+        std::fs::write(import_root.join("Projects/notes.txt"), "not markdown").unwrap();
+        std::fs::write(import_root.join("Projects/image.png"), [0x89, b'P', b'N', b'G', 0, 0, 0, 0]).unwrap();
+
+        let imported = fs.import_directory(import_root.to_str().unwrap()).unwrap();
+
+        let mut relative_paths: Vec<&str> = imported.iter().map(|n| n.relative_path.as_str()).collect();
+        relative_paths.sort();
+        assert_eq!(relative_paths, vec!["Projects/2024/research.md", "Projects/plan.md", "top.md"]);
+
+        let plan = imported.iter().find(|n| n.relative_path == "Projects/plan.md").unwrap();
+        assert_eq!(plan.content, "# Plan");
+        assert!(Path::new(&plan.absolute_path).is_absolute());
+    }
+
+    #[test]
+    fn test_import_directory_is_deterministic_across_runs() {
+        let temp_dir = tempdir().unwrap();
+        let fs = FileSystem::new_with_base(temp_dir.path()).unwrap();
+
+        let import_root = temp_dir.path().join("vault");
+        std::fs::create_dir_all(&import_root).unwrap();
+        std::fs::write(import_root.join("b.md"), "b").unwrap();
+        std::fs::write(import_root.join("a.md"), "a").unwrap();
+
+        let first = fs.import_directory(import_root.to_str().unwrap()).unwrap();
+        let second = fs.import_directory(import_root.to_str().unwrap()).unwrap();
+
+        let first_paths: Vec<&str> = first.iter().map(|n| n.relative_path.as_str()).collect();
+        let second_paths: Vec<&str> = second.iter().map(|n| n.relative_path.as_str()).collect();
+        assert_eq!(first_paths, second_paths);
+        assert_eq!(first_paths, vec!["a.md", "b.md"]);
+    }
+
+    #[test]
+    fn test_import_directory_rejects_non_directory_root() {
+        let temp_dir = tempdir().unwrap();
+        let fs = FileSystem::new_with_base(temp_dir.path()).unwrap();
+
+        let file_path = temp_dir.path().join("not-a-dir.md");
+        std::fs::write(&file_path, "content").unwrap();
+
+        let result = fs.import_directory(file_path.to_str().unwrap());
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_save_note_leaves_no_leftover_tmp_file() {
+        let temp_dir = tempdir().unwrap();
+        let fs = FileSystem::new_with_base(temp_dir.path()).unwrap();
+        fs.ensure_directories().unwrap();
+
+        fs.save_note("note-1", "hello", None).unwrap();
+
+        assert_eq!(fs.read_note("note-1", None).unwrap(), "hello");
+        let leftover_tmp = fs::read_dir(&fs.notes_dir)
+            .unwrap()
+            .filter_map(|e| e.ok())
+            .any(|e| e.file_name().to_string_lossy().contains(".tmp"));
+        assert!(!leftover_tmp, "atomic write should not leave a temp file behind");
+    }
+
+    #[test]
+    fn test_create_note_writes_default_content_atomically() {
+        let temp_dir = tempdir().unwrap();
+        let fs = FileSystem::new_with_base(temp_dir.path()).unwrap();
+        fs.ensure_directories().unwrap();
+
+        let (note_id, _path) = fs.create_note(None).unwrap();
+
+        assert_eq!(fs.read_note(&note_id, None).unwrap(), "# New Note\n\n");
+    }
+
+    #[test]
+    fn test_save_screenshot_writes_decoded_bytes_atomically() {
+        use base64::Engine;
+
+        let temp_dir = tempdir().unwrap();
+        let fs = FileSystem::new_with_base(temp_dir.path()).unwrap();
+        fs.ensure_directories().unwrap();
+
+        let encoded = base64::engine::general_purpose::STANDARD.encode(b"fake-png-bytes");
+        let (_image_id, path) = fs.save_screenshot(&encoded).unwrap();
+
+        assert_eq!(fs::read(path).unwrap(), b"fake-png-bytes");
+    }
+
+    #[test]
+    fn test_save_screenshot_dedup_skips_write_for_matching_hash() {
+        use base64::Engine;
+
+        let temp_dir = tempdir().unwrap();
+        let fs = FileSystem::new_with_base(temp_dir.path()).unwrap();
+        fs.ensure_directories().unwrap();
+
+        let encoded = base64::engine::general_purpose::STANDARD.encode(b"same-bytes");
+        let (id_a, path_a, was_new_a) = fs.save_screenshot_dedup(&encoded).unwrap();
+        let (id_b, path_b, was_new_b) = fs.save_screenshot_dedup(&encoded).unwrap();
+
+        assert_eq!(id_a, id_b);
+        assert_eq!(path_a, path_b);
+        assert!(was_new_a);
+        assert!(!was_new_b);
+    }
+
+    #[test]
+    fn test_save_screenshot_dedup_different_content_gets_different_id() {
+        use base64::Engine;
+
+        let temp_dir = tempdir().unwrap();
+        let fs = FileSystem::new_with_base(temp_dir.path()).unwrap();
+        fs.ensure_directories().unwrap();
+
+        let (id_a, _, _) = fs.save_screenshot_dedup(&base64::engine::general_purpose::STANDARD.encode(b"bytes-a")).unwrap();
+        let (id_b, _, _) = fs.save_screenshot_dedup(&base64::engine::general_purpose::STANDARD.encode(b"bytes-b")).unwrap();
+
+        assert_ne!(id_a, id_b);
+    }
+
+    #[test]
+    fn test_gc_assets_removes_unreferenced_and_keeps_referenced() {
+        use base64::Engine;
+
+        let temp_dir = tempdir().unwrap();
+        let fs = FileSystem::new_with_base(temp_dir.path()).unwrap();
+        fs.ensure_directories().unwrap();
+
+        let (kept_id, _, _) = fs.save_screenshot_dedup(&base64::engine::general_purpose::STANDARD.encode(b"kept")).unwrap();
+        let (_, removed_path, _) = fs.save_screenshot_dedup(&base64::engine::general_purpose::STANDARD.encode(b"orphaned")).unwrap();
+
+        fs.save_note("note-1", &format!("![screenshot]({}.png)", kept_id), None).unwrap();
+
+        let removed = fs.gc_assets().unwrap();
+
+        assert_eq!(removed, 1);
+        assert!(!Path::new(&removed_path).exists());
+        assert!(fs.assets_dir.join(format!("{}.png", kept_id)).exists());
+    }
+
+    #[test]
+    fn test_gc_assets_keeps_an_asset_still_referenced_by_an_old_version() {
+        use base64::Engine;
+
+        let temp_dir = tempdir().unwrap();
+        let fs = FileSystem::new_with_base(temp_dir.path()).unwrap();
+        fs.ensure_directories().unwrap();
+
+        let (image_id, image_path, _) = fs
+            .save_screenshot_dedup(&base64::engine::general_purpose::STANDARD.encode(b"screenshot"))
+            .unwrap();
+
+        // The first save embeds the screenshot; the second removes it from
+        // the live note but snapshots the first version on the way out.
+        fs.save_note("note-1", &format!("![screenshot]({}.png)", image_id), None).unwrap();
+        fs.save_note("note-1", "no screenshot anymore", None).unwrap();
+
+        let removed = fs.gc_assets().unwrap();
+
+        assert_eq!(removed, 0);
+        assert!(Path::new(&image_path).exists());
+    }
+
+    #[test]
+    fn test_gc_assets_keeps_an_asset_still_referenced_by_a_trashed_note() {
+        use base64::Engine;
+
+        let temp_dir = tempdir().unwrap();
+        let fs = FileSystem::new_with_base(temp_dir.path()).unwrap();
+        fs.ensure_directories().unwrap();
+
+        let (image_id, image_path, _) = fs
+            .save_screenshot_dedup(&base64::engine::general_purpose::STANDARD.encode(b"screenshot"))
+            .unwrap();
+
+        fs.save_note("note-1", &format!("![screenshot]({}.png)", image_id), None).unwrap();
+        fs.delete_note("note-1", None, false).unwrap();
+
+        let removed = fs.gc_assets().unwrap();
+
+        assert_eq!(removed, 0);
+        assert!(Path::new(&image_path).exists());
+    }
+
+    #[test]
+    fn test_atomic_write_creates_missing_parent_directory() {
+        let temp_dir = tempdir().unwrap();
+        let fs = FileSystem::new_with_base(temp_dir.path()).unwrap();
+        fs.ensure_directories().unwrap();
+
+        let nested_path = fs.notes_dir.join("new-folder").join("note.md");
+        assert!(!nested_path.parent().unwrap().exists());
+
+        fs.atomic_write(&nested_path, b"content").unwrap();
+
+        assert_eq!(fs::read_to_string(&nested_path).unwrap(), "content");
+    }
+
+    #[test]
+    fn test_write_file_atomic_replaces_content_and_leaves_no_tmp_file() {
+        let temp_dir = tempdir().unwrap();
+        let fs = FileSystem::new_with_base(temp_dir.path()).unwrap();
+
+        let path = temp_dir.path().join("manifest.json");
+        fs::write(&path, "old").unwrap();
+
+        fs.write_file_atomic(&path, "new").unwrap();
+
+        assert_eq!(fs::read_to_string(&path).unwrap(), "new");
+        let leftover_tmp = fs::read_dir(temp_dir.path())
+            .unwrap()
+            .filter_map(|e| e.ok())
+            .any(|e| e.file_name().to_string_lossy().contains(".tmp"));
+        assert!(!leftover_tmp, "write_file_atomic should not leave a temp file behind");
+    }
+
+    #[test]
+    #[cfg(unix)]
+    fn test_write_file_atomic_preserves_existing_permission_mode() {
+        use std::os::unix::fs::PermissionsExt;
+
+        let temp_dir = tempdir().unwrap();
+        let fs = FileSystem::new_with_base(temp_dir.path()).unwrap();
+
+        let path = temp_dir.path().join("manifest.json");
+        fs::write(&path, "old").unwrap();
+        fs::set_permissions(&path, fs::Permissions::from_mode(0o640)).unwrap();
+
+        fs.write_file_atomic(&path, "new").unwrap();
+
+        let mode = fs::metadata(&path).unwrap().permissions().mode() & 0o777;
+        assert_eq!(mode, 0o640);
+    }
+
+    #[test]
+    #[cfg(unix)]
+    fn test_save_note_interrupted_write_leaves_prior_content_intact() {
+        use std::os::unix::fs::PermissionsExt;
+
+        let temp_dir = tempdir().unwrap();
+        let fs = FileSystem::new_with_base(temp_dir.path()).unwrap();
+        fs.ensure_directories().unwrap();
+
+        fs.save_note("note-1", "original", None).unwrap();
+
+        // Make the notes directory read-only so the atomic write's temp file
+        // can't even be created - simulating a write that's interrupted
+        // before it gets anywhere near the rename.
+        let original_perms = fs.notes_dir.metadata().unwrap().permissions();
+        let mut readonly_perms = original_perms.clone();
+        readonly_perms.set_mode(0o555);
+        fs::set_permissions(&fs.notes_dir, readonly_perms).unwrap();
+
+        let result = fs.save_note("note-1", "corrupted", None);
+
+        fs::set_permissions(&fs.notes_dir, original_perms).unwrap();
+
+        assert!(result.is_err());
+        assert_eq!(fs.read_note("note-1", None).unwrap(), "original");
+        let leftover_tmp = fs::read_dir(&fs.notes_dir)
+            .unwrap()
+            .filter_map(|e| e.ok())
+            .any(|e| e.file_name().to_string_lossy().contains(".tmp"));
+        assert!(!leftover_tmp, "a failed atomic write should not leave a temp file behind");
+    }
 }
@@ -0,0 +1,173 @@
+use tauri::{AppHandle, Emitter, Manager, Runtime};
+
+use crate::window::WindowManager;
+
+/// A command a relaunched instance asks the already-running instance to
+/// perform, parsed from its argv. Replaces the old "only a bare `.md` path
+/// is understood" handling so the installed app is scriptable from the
+/// shell (`mded new-note work`, `mded open-folder work`, `mded toggle`, ...)
+/// without needing a separate daemon.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SecondInstanceCommand {
+    /// Open (or focus, if already open) the given markdown file.
+    OpenFile(String),
+    /// Create a new note, optionally inside `folder`.
+    NewNote { folder: Option<String> },
+    /// Switch the note list to the folder named `name`.
+    OpenFolder { name: String },
+    /// Show the main window if it's hidden, hide it if it's visible.
+    Toggle,
+    /// An argument that didn't match any known subcommand.
+    Unknown(String),
+}
+
+/// Parses a relaunched instance's argv (`args[0]` is the executable path, so
+/// the subcommand starts at `args[1]`) into a [`SecondInstanceCommand`].
+/// Returns `None` if no subcommand was passed at all. A bare `.md` path is
+/// still accepted directly, for backward compatibility with "open with"
+/// style launches that don't know about the subcommands below.
+pub fn parse_second_instance_command(args: &[String]) -> Option<SecondInstanceCommand> {
+    let rest = &args[1..];
+    let first = rest.first()?;
+
+    Some(match first.as_str() {
+        "toggle" => SecondInstanceCommand::Toggle,
+        "new-note" => SecondInstanceCommand::NewNote { folder: rest.get(1).cloned() },
+        "open-folder" => match rest.get(1) {
+            Some(name) => SecondInstanceCommand::OpenFolder { name: name.clone() },
+            None => SecondInstanceCommand::Unknown(first.clone()),
+        },
+        _ if !first.starts_with('-') && first.ends_with(".md") => {
+            SecondInstanceCommand::OpenFile(first.clone())
+        }
+        _ => SecondInstanceCommand::Unknown(first.clone()),
+    })
+}
+
+/// Shows and focuses the main window, unminimizing it first only if it's
+/// actually minimized - a window that's merely hidden (not minimized)
+/// doesn't need unminimizing, just showing and focusing.
+fn focus_main_window<R: Runtime>(app: &AppHandle<R>) {
+    if let Some(window) = app.get_webview_window("main") {
+        let _ = window.show();
+        if matches!(window.is_minimized(), Ok(true)) {
+            let _ = window.unminimize();
+        }
+        let _ = window.set_focus();
+    }
+}
+
+/// Carries out a parsed [`SecondInstanceCommand`]: focuses the right window
+/// and emits the structured event the frontend listens for. Unrecognized
+/// commands focus nothing and instead emit `second-instance-error`, so a
+/// shell script relaunching the app with a bad subcommand gets feedback
+/// instead of a silent no-op.
+pub fn dispatch_second_instance_command<R: Runtime>(app: &AppHandle<R>, command: SecondInstanceCommand) {
+    match command {
+        SecondInstanceCommand::Toggle => {
+            if let Some(window) = app.get_webview_window("main") {
+                if window.is_visible().unwrap_or(false) {
+                    let _ = window.hide();
+                } else {
+                    focus_main_window(app);
+                }
+            }
+        }
+        SecondInstanceCommand::OpenFile(path) => {
+            focus_main_window(app);
+            let window_manager = app.state::<WindowManager>();
+            let target_label =
+                window_manager.find_note_window(&path, None).unwrap_or_else(|| "main".to_string());
+            let _ = app.emit_to(&target_label, "open-file", path);
+        }
+        SecondInstanceCommand::NewNote { folder } => {
+            focus_main_window(app);
+            let _ = app.emit("new-note-requested", serde_json::json!({ "folder": folder }));
+        }
+        SecondInstanceCommand::OpenFolder { name } => {
+            focus_main_window(app);
+            let _ = app.emit("open-folder-requested", serde_json::json!({ "name": name }));
+        }
+        SecondInstanceCommand::Unknown(token) => {
+            let _ = app.emit(
+                "second-instance-error",
+                serde_json::json!({ "error": format!("Unknown command '{}'", token) }),
+            );
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn args(values: &[&str]) -> Vec<String> {
+        values.iter().map(|s| s.to_string()).collect()
+    }
+
+    #[test]
+    fn test_parse_no_subcommand_returns_none() {
+        assert_eq!(parse_second_instance_command(&args(&["mded"])), None);
+    }
+
+    #[test]
+    fn test_parse_toggle() {
+        assert_eq!(parse_second_instance_command(&args(&["mded", "toggle"])), Some(SecondInstanceCommand::Toggle));
+    }
+
+    #[test]
+    fn test_parse_new_note_with_folder() {
+        assert_eq!(
+            parse_second_instance_command(&args(&["mded", "new-note", "work"])),
+            Some(SecondInstanceCommand::NewNote { folder: Some("work".to_string()) })
+        );
+    }
+
+    #[test]
+    fn test_parse_new_note_without_folder() {
+        assert_eq!(
+            parse_second_instance_command(&args(&["mded", "new-note"])),
+            Some(SecondInstanceCommand::NewNote { folder: None })
+        );
+    }
+
+    #[test]
+    fn test_parse_open_folder() {
+        assert_eq!(
+            parse_second_instance_command(&args(&["mded", "open-folder", "journal"])),
+            Some(SecondInstanceCommand::OpenFolder { name: "journal".to_string() })
+        );
+    }
+
+    #[test]
+    fn test_parse_open_folder_missing_name_is_unknown() {
+        assert_eq!(
+            parse_second_instance_command(&args(&["mded", "open-folder"])),
+            Some(SecondInstanceCommand::Unknown("open-folder".to_string()))
+        );
+    }
+
+    #[test]
+    fn test_parse_bare_markdown_path_is_open_file() {
+        assert_eq!(
+            parse_second_instance_command(&args(&["mded", "/home/user/notes/todo.md"])),
+            Some(SecondInstanceCommand::OpenFile("/home/user/notes/todo.md".to_string()))
+        );
+    }
+
+    #[test]
+    fn test_parse_flag_like_argument_is_unknown() {
+        assert_eq!(
+            parse_second_instance_command(&args(&["mded", "--hidden"])),
+            Some(SecondInstanceCommand::Unknown("--hidden".to_string()))
+        );
+    }
+
+    #[test]
+    fn test_parse_unrecognized_subcommand_is_unknown() {
+        assert_eq!(
+            parse_second_instance_command(&args(&["mded", "frobnicate"])),
+            Some(SecondInstanceCommand::Unknown("frobnicate".to_string()))
+        );
+    }
+}
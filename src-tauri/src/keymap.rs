@@ -0,0 +1,172 @@
+use std::collections::HashMap;
+
+use serde::{Deserialize, Serialize};
+
+use crate::shortcuts::ShortcutManager;
+
+/// Shortcuts organized into named sections/contexts (e.g. "editor",
+/// "preview", "global"), so the same parsed accelerator can map to
+/// different actions depending on which section is currently active.
+///
+/// Lookups are always safe: [`Keymap::resolve`] returns `None` for a
+/// missing section or an unbound shortcut rather than indexing and
+/// panicking, mirroring the nested `get(section).and_then(|s| s.get(key))`
+/// pattern used elsewhere in this codebase for optional config lookups.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct Keymap {
+    sections: HashMap<String, HashMap<String, String>>,
+}
+
+impl Keymap {
+    /// Creates an empty keymap with no sections.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Binds `shortcut` to `action_id` within `section`, creating the
+    /// section if it doesn't exist yet. Replaces any existing binding for
+    /// the same shortcut in that section.
+    pub fn bind(&mut self, section: impl Into<String>, shortcut: impl Into<String>, action_id: impl Into<String>) {
+        self.sections
+            .entry(section.into())
+            .or_default()
+            .insert(shortcut.into(), action_id.into());
+    }
+
+    /// Looks up the action bound to `shortcut` within `section`.
+    ///
+    /// # Returns
+    /// `None` (never panics) if `section` doesn't exist or `shortcut` is
+    /// unbound within it.
+    pub fn resolve(&self, section: &str, shortcut: &str) -> Option<&str> {
+        self.sections.get(section).and_then(|bindings| bindings.get(shortcut)).map(String::as_str)
+    }
+
+    /// Validates every binding's shortcut string with
+    /// [`ShortcutManager::is_valid_shortcut`].
+    ///
+    /// # Returns
+    /// A `(section, shortcut)` pair for every binding whose shortcut string
+    /// fails to parse.
+    pub fn validate(&self) -> Vec<(String, String)> {
+        let mut invalid = Vec::new();
+        for (section, bindings) in &self.sections {
+            for shortcut in bindings.keys() {
+                if !ShortcutManager::is_valid_shortcut(shortcut) {
+                    invalid.push((section.clone(), shortcut.clone()));
+                }
+            }
+        }
+        invalid
+    }
+
+    /// Parses a keymap from its JSON representation, as loaded from a
+    /// config file. Does not validate the shortcut strings - call
+    /// [`Keymap::validate`] afterward to report invalid bindings.
+    ///
+    /// # Returns
+    /// * `Ok(Keymap)` - The parsed keymap
+    /// * `Err(String)` - If the JSON is malformed
+    pub fn from_json(json: &str) -> Result<Self, String> {
+        serde_json::from_str(json).map_err(|e| format!("Failed to parse keymap: {}", e))
+    }
+
+    /// Serializes this keymap to its JSON representation, for saving to a
+    /// config file.
+    ///
+    /// # Returns
+    /// * `Ok(String)` - The serialized keymap
+    /// * `Err(String)` - If serialization fails
+    pub fn to_json(&self) -> Result<String, String> {
+        serde_json::to_string_pretty(self).map_err(|e| format!("Failed to serialize keymap: {}", e))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_resolve_missing_section_returns_none() {
+        let keymap = Keymap::new();
+        assert_eq!(keymap.resolve("editor", "Ctrl+S"), None);
+    }
+
+    #[test]
+    fn test_resolve_unbound_shortcut_returns_none() {
+        let mut keymap = Keymap::new();
+        keymap.bind("editor", "Ctrl+S", "save");
+        assert_eq!(keymap.resolve("editor", "Ctrl+P"), None);
+    }
+
+    #[test]
+    fn test_resolve_finds_bound_action() {
+        let mut keymap = Keymap::new();
+        keymap.bind("editor", "Ctrl+S", "save");
+        assert_eq!(keymap.resolve("editor", "Ctrl+S"), Some("save"));
+    }
+
+    #[test]
+    fn test_same_shortcut_resolves_differently_per_section() {
+        let mut keymap = Keymap::new();
+        keymap.bind("editor", "Escape", "exit_insert_mode");
+        keymap.bind("preview", "Escape", "close_preview");
+
+        assert_eq!(keymap.resolve("editor", "Escape"), Some("exit_insert_mode"));
+        assert_eq!(keymap.resolve("preview", "Escape"), Some("close_preview"));
+    }
+
+    #[test]
+    fn test_bind_replaces_existing_binding_in_same_section() {
+        let mut keymap = Keymap::new();
+        keymap.bind("editor", "Ctrl+S", "save");
+        keymap.bind("editor", "Ctrl+S", "save_as");
+        assert_eq!(keymap.resolve("editor", "Ctrl+S"), Some("save_as"));
+    }
+
+    #[test]
+    fn test_validate_reports_invalid_bindings_with_section_and_key() {
+        let mut keymap = Keymap::new();
+        keymap.bind("editor", "Ctrl+S", "save");
+        keymap.bind("editor", "NotAShortcut", "bogus");
+        keymap.bind("preview", "+N", "also_bogus");
+
+        let mut invalid = keymap.validate();
+        invalid.sort();
+
+        assert_eq!(
+            invalid,
+            vec![
+                ("editor".to_string(), "NotAShortcut".to_string()),
+                ("preview".to_string(), "+N".to_string()),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_validate_reports_nothing_for_all_valid_bindings() {
+        let mut keymap = Keymap::new();
+        keymap.bind("editor", "Ctrl+S", "save");
+        keymap.bind("global", "CommandOrControl+Shift+N", "toggle");
+
+        assert!(keymap.validate().is_empty());
+    }
+
+    #[test]
+    fn test_keymap_json_round_trip() {
+        let mut keymap = Keymap::new();
+        keymap.bind("editor", "Ctrl+S", "save");
+        keymap.bind("global", "CommandOrControl+Shift+N", "toggle");
+
+        let json = keymap.to_json().expect("serialization should succeed");
+        let round_tripped = Keymap::from_json(&json).expect("deserialization should succeed");
+
+        assert_eq!(keymap, round_tripped);
+    }
+
+    #[test]
+    fn test_from_json_rejects_malformed_input() {
+        assert!(Keymap::from_json("{ not valid json").is_err());
+    }
+}
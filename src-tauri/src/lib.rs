@@ -1,18 +1,32 @@
+use notify::{RecursiveMode, Watcher};
 use tauri::{Emitter, Manager};
 
 pub mod commands;
 pub mod config;
 pub mod filesystem;
+pub mod ipc;
+pub mod keymap;
+pub mod logging;
 pub mod models;
 pub mod shortcuts;
+pub mod storage;
 pub mod tray;
 pub mod window;
+pub mod window_state;
 
 use config::ConfigManager;
 use filesystem::FileSystem;
+use ipc::{dispatch_second_instance_command, parse_second_instance_command};
+use models::{StartupMode, WindowBounds};
 use shortcuts::ShortcutManager;
 use window::WindowManager;
 
+/// How long the config file watcher waits for a burst of writes to the same
+/// file to go quiet before reloading, so an editor's write-then-rename save
+/// (or a manual save right after an autosave) triggers one reload instead of
+/// several redundant ones.
+const CONFIG_WATCH_DEBOUNCE: std::time::Duration = std::time::Duration::from_millis(200);
+
 #[cfg_attr(mobile, tauri::mobile_entry_point)]
 pub fn run() {
     tauri::Builder::default()
@@ -25,25 +39,12 @@ pub fn run() {
         .plugin(tauri_plugin_notification::init())
         .plugin(tauri_plugin_clipboard_manager::init())
         .plugin(tauri_plugin_single_instance::init(|app, args, _cwd| {
-            // Focus the main window when a second instance is launched
-            // Requirements: 3.1, 3.3
-            if let Some(window) = app.get_webview_window("main") {
-                // First show the window if it's hidden
-                let _ = window.show();
-                // Restore if minimized (Requirement 3.3)
-                let _ = window.unminimize();
-                // Then focus it (Requirement 3.1)
-                let _ = window.set_focus();
-            }
-            
-            // Handle file argument from second instance (Requirement 3.2)
-            // args[0] is typically the executable path, so we check args[1] onwards
-            if args.len() > 1 {
-                let file_path = &args[1];
-                // Only emit if it looks like a file path (not a flag)
-                if !file_path.starts_with('-') && file_path.ends_with(".md") {
-                    let _ = app.emit("open-file", file_path.clone());
-                }
+            // A relaunched instance's argv is parsed into a structured
+            // command (Requirements 3.1, 3.2, 3.3) rather than only ever
+            // supporting a bare file path, so the installed app is
+            // scriptable from the shell without a separate daemon.
+            if let Some(command) = parse_second_instance_command(&args) {
+                dispatch_second_instance_command(app, command);
             }
         }))
         .setup(|app| {
@@ -52,7 +53,17 @@ pub fn run() {
                 .expect("Failed to initialize filesystem");
             filesystem.ensure_directories()
                 .expect("Failed to create application directories");
-            
+            filesystem.migrate_if_needed()
+                .expect("Data directory is not compatible with this version of mded");
+
+            // Wire up a rotating file-backed logger so the `log::error!`/`log::warn!`
+            // calls throughout the command layer leave behind diagnosable output -
+            // there's no console most users will ever see, so logging to
+            // stdout/stderr alone is as good as discarding the message.
+            if let Err(e) = logging::init(&filesystem.base_dir.join("logs")) {
+                eprintln!("Failed to initialize file logger: {}", e);
+            }
+
             // Initialize config manager
             let config_manager = ConfigManager::new(filesystem.config_file.clone())
                 .expect("Failed to initialize config manager");
@@ -67,17 +78,118 @@ pub fn run() {
             app.manage(config_manager);
             app.manage(window_manager);
             app.manage(shortcut_manager);
-            
+
+            // Watch config.json for external edits (a hand edit, or a write
+            // from another running instance) and live-reload them into the
+            // running app, so the config doesn't go stale until restart.
+            {
+                let config_path = app.state::<ConfigManager>().config_path().clone();
+                let handle = app.handle().clone();
+                std::thread::spawn(move || {
+                    let (tx, rx) = std::sync::mpsc::channel();
+                    let mut watcher = match notify::recommended_watcher(tx) {
+                        Ok(watcher) => watcher,
+                        Err(e) => {
+                            log::warn!("Failed to start config file watcher: {}", e);
+                            return;
+                        }
+                    };
+
+                    let watch_dir = config_path
+                        .parent()
+                        .map(|p| p.to_path_buf())
+                        .unwrap_or_else(|| config_path.clone());
+                    if let Err(e) = watcher.watch(&watch_dir, RecursiveMode::NonRecursive) {
+                        log::warn!("Failed to watch config directory: {}", e);
+                        return;
+                    }
+
+                    let is_config_event = |event: &notify::Event| event.paths.iter().any(|p| p == &config_path);
+
+                    for event in rx {
+                        match event {
+                            Ok(event) if is_config_event(&event) => {
+                                // Editors often write a file twice in quick succession (a
+                                // temp-file-then-rename save, or an autosave right after a
+                                // manual one) - keep draining config events that land within
+                                // the debounce window so a burst triggers one reload instead
+                                // of several redundant ones.
+                                while let Ok(Ok(next)) = rx.recv_timeout(CONFIG_WATCH_DEBOUNCE) {
+                                    if !is_config_event(&next) {
+                                        break;
+                                    }
+                                }
+                                handle.state::<ConfigManager>().reload_from_disk();
+                            }
+                            Ok(_) => {}
+                            Err(e) => log::warn!("Config file watcher error: {}", e),
+                        }
+                    }
+                });
+            }
+
             // Set up system tray
             tray::setup_tray(app.handle())
                 .expect("Failed to setup system tray");
             
             // Register global shortcuts
             let shortcut_mgr = app.state::<ShortcutManager>();
-            if let Err(e) = shortcut_mgr.register_all(app.handle()) {
-                log::warn!("Failed to register some shortcuts: {}", e);
+            match shortcut_mgr.register_all(app.handle()) {
+                Ok(summary) => log::info!("Registered shortcuts: {:?}", summary.registered),
+                Err(e) => log::warn!("Failed to register shortcuts, none are bound: {}", e),
             }
-            
+
+            // Restore the main window's persisted position/size/mode, gated
+            // per field so a config file predating this subsystem (or a
+            // partial capture) doesn't force the rest to a default.
+            if let Some(window) = app.get_webview_window("main") {
+                let config_manager = app.state::<ConfigManager>();
+                if let Some(state) = config_manager.get_window_state("main") {
+                    window_state::apply_window_state(&window, &state);
+                }
+
+                // Apply the persisted opacity to the live window too - it's
+                // otherwise only ever written to config.
+                let opacity = config_manager.get_window_opacity();
+                if window::apply_window_opacity(&window, opacity).is_ok() {
+                    app.state::<WindowManager>().set_live_opacity(opacity);
+                }
+
+                // Apply the configured startup presentation on top of the
+                // restored window state.
+                let cfg = config_manager.get();
+                match cfg.startup_mode {
+                    StartupMode::Windowed => {}
+                    StartupMode::Maximized => {
+                        let _ = window.maximize();
+                    }
+                    StartupMode::Minimal => {
+                        let window_manager = app.state::<WindowManager>();
+                        if let (Ok(position), Ok(size)) = (window.outer_position(), window.outer_size()) {
+                            window_manager.save_normal_bounds(WindowBounds {
+                                width: size.width,
+                                height: size.height,
+                                x: Some(position.x),
+                                y: Some(position.y),
+                                scale_factor: window.scale_factor().unwrap_or(1.0),
+                            });
+                        }
+
+                        let minimal_bounds = cfg.minimal_mode_bounds;
+                        let _ = window.set_size(tauri::Size::Physical(tauri::PhysicalSize {
+                            width: minimal_bounds.width,
+                            height: minimal_bounds.height,
+                        }));
+                        if let (Some(x), Some(y)) = (minimal_bounds.x, minimal_bounds.y) {
+                            let _ = window
+                                .set_position(tauri::Position::Physical(tauri::PhysicalPosition { x, y }));
+                        }
+                        let _ = window.set_always_on_top(true);
+                        window_manager.set_minimal_mode(true);
+                    }
+                }
+            }
+
             // Handle file argument on initial startup (Requirement 3.2)
             // This handles the case when the app is launched with a file argument
             let args: Vec<String> = std::env::args().collect();
@@ -90,7 +202,11 @@ pub fn run() {
                     // Emit after a short delay to ensure frontend is ready
                     std::thread::spawn(move || {
                         std::thread::sleep(std::time::Duration::from_millis(500));
-                        let _ = handle.emit("open-file", path);
+                        let window_manager = handle.state::<WindowManager>();
+                        let target_label = window_manager
+                            .find_note_window(&path, None)
+                            .unwrap_or_else(|| "main".to_string());
+                        let _ = handle.emit_to(&target_label, "open-file", path);
                     });
                 }
             }
@@ -106,40 +222,80 @@ pub fn run() {
             commands::list_folders,
             commands::create_folder,
             commands::delete_folder,
+            commands::list_trash,
+            commands::restore_folder,
+            commands::empty_trash,
+            commands::find_empty_folders,
+            commands::remove_empty_folders,
+            commands::delete_folders,
+            commands::rename_folders,
             commands::rename_folder,
+            commands::copy_folder,
+            commands::move_folder,
             commands::list_notes,
+            commands::search_notes,
+            commands::search_notes_ranked,
+            commands::list_tags,
+            commands::list_notes_by_tag,
+            commands::set_note_tags,
             commands::read_note,
             commands::save_note,
             commands::create_note,
             commands::delete_note,
+            commands::restore_note,
             commands::rename_note,
+            commands::list_note_versions,
+            commands::read_note_version,
+            commands::restore_note_version,
             commands::move_note,
             commands::toggle_pin_note,
             commands::get_note_order,
             commands::save_note_order,
             commands::save_quick_note,
+            commands::start_notes_watcher,
+            commands::stop_notes_watcher,
             commands::get_last_note,
             commands::save_last_note,
             commands::get_global_shortcut,
             commands::set_global_shortcut,
+            commands::get_quick_capture_shortcut,
+            commands::set_quick_capture_shortcut,
+            commands::list_shortcut_actions,
             // Window commands
             commands::minimize_window,
             commands::maximize_window,
             commands::close_window,
+            commands::hide_quick_note_window,
             commands::set_always_on_top,
             commands::enter_minimal_mode,
             commands::exit_minimal_mode,
+            commands::set_visible_on_all_workspaces,
             commands::save_minimal_bounds,
             commands::get_window_opacity,
             commands::set_window_opacity,
             commands::get_display_info,
             commands::save_window_bounds,
+            commands::save_full_window_state,
+            commands::open_note_window,
+            commands::list_note_windows,
+            commands::focus_note_window,
             // System integration commands
             commands::save_screenshot,
+            commands::save_screenshot_dedup,
+            commands::gc_assets,
             commands::get_assets_path,
+            commands::export_vault,
+            commands::import_vault,
             commands::read_external_file,
+            commands::read_external_file_sniffed,
+            commands::read_external_file_with_checksum,
+            commands::read_external_file_with_symlink_policy,
+            commands::import_directory,
             commands::get_auto_start,
             commands::set_auto_start,
+            commands::update_tray_status,
+            commands::get_recent_logs,
+            commands::open_log_directory,
         ])
         .run(tauri::generate_context!())
         .expect("error while running tauri application");
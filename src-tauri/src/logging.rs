@@ -0,0 +1,169 @@
+//! A minimal file-backed [`log`] sink, so command handlers that log failures
+//! (rather than only returning a stringly-typed `Err` to the frontend) leave
+//! behind something a user can attach to a bug report. There's no console
+//! most users will ever see, so logging to stdout/stderr alone is as good as
+//! discarding the message.
+
+use std::fs::{self, File, OpenOptions};
+use std::io::{BufRead, BufReader, Write};
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+
+/// The active log file rotates to `mded.log.1` once it exceeds this size,
+/// keeping one previous file around rather than growing unboundedly across
+/// a long-running session.
+const MAX_LOG_FILE_BYTES: u64 = 5 * 1024 * 1024;
+
+/// The log level recorded by default. Can be raised for a debugging session
+/// via `RUST_LOG`, the conventional env var for the `log` crate's consumers.
+const DEFAULT_LEVEL: log::LevelFilter = log::LevelFilter::Info;
+
+fn log_file_name() -> &'static str {
+    "mded.log"
+}
+
+fn rotated_file_name() -> &'static str {
+    "mded.log.1"
+}
+
+/// The active log file's path within `log_dir`.
+pub fn log_file_path(log_dir: &Path) -> PathBuf {
+    log_dir.join(log_file_name())
+}
+
+struct FileLogger {
+    file: Mutex<File>,
+    path: PathBuf,
+}
+
+impl FileLogger {
+    fn rotate_if_needed(&self, file: &mut File) {
+        let Ok(metadata) = file.metadata() else { return };
+        if metadata.len() < MAX_LOG_FILE_BYTES {
+            return;
+        }
+
+        let rotated_path = self.path.with_file_name(rotated_file_name());
+        if fs::rename(&self.path, &rotated_path).is_err() {
+            return;
+        }
+        if let Ok(reopened) = OpenOptions::new().create(true).append(true).open(&self.path) {
+            *file = reopened;
+        }
+    }
+}
+
+impl log::Log for FileLogger {
+    fn enabled(&self, metadata: &log::Metadata) -> bool {
+        metadata.level() <= log::max_level()
+    }
+
+    fn log(&self, record: &log::Record) {
+        if !self.enabled(record.metadata()) {
+            return;
+        }
+
+        let line = format!(
+            "{} [{}] {}: {}\n",
+            chrono::Utc::now().to_rfc3339(),
+            record.level(),
+            record.target(),
+            record.args()
+        );
+
+        // A logger that can panic or poison its own mutex would take down
+        // the very error path it exists to record, so every failure here is
+        // swallowed rather than propagated.
+        if let Ok(mut file) = self.file.lock() {
+            self.rotate_if_needed(&mut file);
+            let _ = file.write_all(line.as_bytes());
+        }
+    }
+
+    fn flush(&self) {
+        if let Ok(mut file) = self.file.lock() {
+            let _ = file.flush();
+        }
+    }
+}
+
+/// Installs the global file-backed logger, creating `log_dir` if needed.
+///
+/// Safe to call at most once per process - a second call fails because
+/// [`log::set_boxed_logger`] only ever accepts the first logger a process
+/// installs.
+///
+/// # Arguments
+/// * `log_dir` - Directory the rotating log file(s) live in
+pub fn init(log_dir: &Path) -> Result<(), String> {
+    fs::create_dir_all(log_dir).map_err(|e| format!("Failed to create log directory: {}", e))?;
+
+    let path = log_file_path(log_dir);
+    let file = OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(&path)
+        .map_err(|e| format!("Failed to open log file '{}': {}", path.display(), e))?;
+
+    log::set_boxed_logger(Box::new(FileLogger { file: Mutex::new(file), path }))
+        .map_err(|e| format!("Failed to install logger: {}", e))?;
+
+    let level = std::env::var("RUST_LOG")
+        .ok()
+        .and_then(|raw| raw.parse().ok())
+        .unwrap_or(DEFAULT_LEVEL);
+    log::set_max_level(level);
+
+    Ok(())
+}
+
+/// Returns up to the last `max_lines` lines logged, oldest first, reading
+/// the rotated file before the active one so a reader gets the most
+/// continuous window of recent history available.
+///
+/// # Arguments
+/// * `log_dir` - Directory passed to [`init`]
+/// * `max_lines` - The maximum number of trailing lines to return
+pub fn recent_lines(log_dir: &Path, max_lines: usize) -> Vec<String> {
+    let mut lines = Vec::new();
+    for name in [rotated_file_name(), log_file_name()] {
+        let path = log_dir.join(name);
+        let Ok(file) = File::open(&path) else { continue };
+        lines.extend(BufReader::new(file).lines().map_while(Result::ok));
+    }
+
+    let skip = lines.len().saturating_sub(max_lines);
+    lines.split_off(skip)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::tempdir;
+
+    #[test]
+    fn test_recent_lines_returns_only_the_trailing_lines() {
+        let temp_dir = tempdir().unwrap();
+        fs::write(log_file_path(temp_dir.path()), "one\ntwo\nthree\nfour\n").unwrap();
+
+        assert_eq!(recent_lines(temp_dir.path(), 2), vec!["three".to_string(), "four".to_string()]);
+    }
+
+    #[test]
+    fn test_recent_lines_reads_rotated_file_before_the_active_one() {
+        let temp_dir = tempdir().unwrap();
+        fs::write(temp_dir.path().join(rotated_file_name()), "old-one\nold-two\n").unwrap();
+        fs::write(log_file_path(temp_dir.path()), "new-one\n").unwrap();
+
+        assert_eq!(
+            recent_lines(temp_dir.path(), 10),
+            vec!["old-one".to_string(), "old-two".to_string(), "new-one".to_string()]
+        );
+    }
+
+    #[test]
+    fn test_recent_lines_returns_empty_when_no_log_file_exists_yet() {
+        let temp_dir = tempdir().unwrap();
+        assert!(recent_lines(temp_dir.path(), 10).is_empty());
+    }
+}
@@ -1,11 +1,114 @@
+use std::collections::HashMap;
+
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 
-/// Information about a folder in the notes directory
+use crate::window_state::WindowState;
+
+/// Information about a folder in the notes directory.
+///
+/// `path` is the full `/`-separated path relative to the notes root (e.g.
+/// `"Projects/2024/Research"`), `name` is just its last segment, `parent`
+/// is the path of the containing folder (`None` at the root), and `depth`
+/// is the number of segments in `path` (`0` for top-level folders).
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
 pub struct FolderInfo {
     pub name: String,
     pub path: String,
+    pub parent: Option<String>,
+    pub depth: usize,
+}
+
+/// Whether a [`TrashEntry`] is a trashed folder or a trashed individual note.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum TrashItemKind {
+    Folder,
+    Note,
+}
+
+/// A folder or note moved to the trash by
+/// [`crate::filesystem::FileSystem::delete_folder`] or
+/// [`crate::filesystem::FileSystem::delete_note`], recording enough to
+/// restore it to its original location via
+/// [`crate::filesystem::FileSystem::restore_folder`] or
+/// [`crate::filesystem::FileSystem::restore_note`].
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+pub struct TrashEntry {
+    pub trash_id: String,
+    pub kind: TrashItemKind,
+    /// For a folder, its `/`-separated relative path; for a note, its id (filename without `.md`).
+    pub original_name: String,
+    /// The folder a trashed note lived in (`None` for the root or for folder entries).
+    pub original_folder: Option<String>,
+    pub deleted_at: DateTime<Utc>,
+}
+
+/// One snapshot in a note's version history, as recorded by
+/// [`crate::filesystem::FileSystem::snapshot_note_version`] and listed by
+/// [`crate::filesystem::FileSystem::list_note_versions`].
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+pub struct VersionInfo {
+    pub version_id: String,
+    pub note_id: String,
+    pub created_at: DateTime<Utc>,
+    pub size: u64,
+}
+
+/// Describes a vault archive written by [`crate::filesystem::FileSystem::export_vault`],
+/// read back by [`crate::filesystem::FileSystem::import_vault`] to check
+/// compatibility before importing.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+pub struct VaultManifest {
+    pub schema_version: u32,
+    pub exported_at: DateTime<Utc>,
+    pub folders: Vec<String>,
+    pub note_count: usize,
+    pub includes_assets: bool,
+}
+
+/// The outcome of a glob/wildcard batch folder operation (e.g.
+/// [`crate::commands::delete_folders`], [`crate::commands::rename_folders`]),
+/// reporting each matched folder's individual success or failure rather than
+/// failing the whole batch on the first error.
+#[derive(Serialize, Deserialize, Clone, Debug, Default, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct BatchFolderResult {
+    /// Relative paths of folders the operation applied to successfully.
+    pub succeeded: Vec<String>,
+    /// Relative paths that matched the pattern but failed, paired with the error.
+    pub failed: Vec<(String, String)>,
+}
+
+/// The current on-disk data directory format version, written to
+/// `{base_dir}/requirements` by [`crate::filesystem::FileSystem::ensure_directories`]
+/// and checked by [`crate::filesystem::FileSystem::check_requirements`] on
+/// startup, so an older build opening a newer data directory fails with a
+/// clear error instead of misreading it. Bump this (and teach
+/// [`crate::filesystem::FileSystem::migrate_if_needed`] the upgrade step)
+/// whenever the on-disk layout changes in a way an older build can't read.
+pub const DATA_DIR_VERSION: u32 = 1;
+
+/// Optional on-disk features this build understands, written to the current
+/// data directory's `requirements` file and checked the same way as
+/// [`DATA_DIR_VERSION`].
+pub const DATA_DIR_FEATURES: &[&str] = &["trash", "nested-folders"];
+
+/// The data directory's schema/feature manifest, read and written as
+/// `{base_dir}/requirements`.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+pub struct DataDirRequirements {
+    pub version: u32,
+    pub features: Vec<String>,
+}
+
+impl Default for DataDirRequirements {
+    fn default() -> Self {
+        Self {
+            version: DATA_DIR_VERSION,
+            features: DATA_DIR_FEATURES.iter().map(|s| s.to_string()).collect(),
+        }
+    }
 }
 
 /// Information about a note file
@@ -17,6 +120,35 @@ pub struct NoteInfo {
     pub created: DateTime<Utc>,
     pub folder: String,
     pub pinned: bool,
+    /// Tags parsed from the note's leading YAML frontmatter block, if any.
+    pub tags: Vec<String>,
+}
+
+/// A note matching a [`crate::filesystem::FileSystem::search_notes`] query.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+pub struct SearchHit {
+    pub id: String,
+    pub title: String,
+    pub folder: String,
+    /// Number of matches found in the note's content.
+    pub match_count: usize,
+    /// The line around the first match, trimmed to a few surrounding characters.
+    pub snippet: String,
+    pub modified: DateTime<Utc>,
+    pub pinned: bool,
+    /// Relevance score. [`crate::filesystem::FileSystem::search_notes`]
+    /// sets this to `match_count` as a float; [`crate::filesystem::FileSystem::search_notes_ranked`]
+    /// sets it to the note's BM25 score.
+    pub score: f64,
+}
+
+/// A Markdown file discovered by [`crate::filesystem::FileSystem::import_directory`].
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+pub struct ImportedNote {
+    /// The file's path relative to the imported root, using `/` separators.
+    pub relative_path: String,
+    pub content: String,
+    pub absolute_path: String,
 }
 
 /// Generic API result for IPC commands
@@ -44,6 +176,18 @@ pub struct ApiResult {
     pub pinned: Option<bool>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub opacity: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub invalid_token: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub valid_values: Option<Vec<String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub moved_count: Option<usize>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub was_new: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub checksum: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub checksum_algorithm: Option<String>,
 }
 
 
@@ -92,15 +236,55 @@ impl ApiResult {
             ..Default::default()
         }
     }
+
+    /// Create a success result carrying the number of files a
+    /// [`crate::filesystem::FileSystem::copy_folder`] or
+    /// [`crate::filesystem::FileSystem::move_folder`] moved or copied.
+    pub fn with_moved_count(count: usize) -> Self {
+        Self {
+            success: true,
+            moved_count: Some(count),
+            ..Default::default()
+        }
+    }
+
+    /// Create an error result from a [`crate::shortcuts::ShortcutParseError`],
+    /// carrying the offending token and valid-value list alongside the
+    /// human-readable message so a settings UI can render a specific
+    /// "did you mean" suggestion.
+    pub fn from_shortcut_error(err: crate::shortcuts::ShortcutParseError) -> Self {
+        Self {
+            success: false,
+            error: Some(err.to_string()),
+            invalid_token: err.invalid_value,
+            valid_values: if err.valid_values.is_empty() { None } else { Some(err.valid_values) },
+            ..Default::default()
+        }
+    }
 }
 
-/// Window bounds for position and size persistence
+/// The display scale factor a fresh [`WindowBounds`] is assumed to have been
+/// captured at, when a saved config predates the `scale_factor` field.
+fn default_scale_factor() -> f64 {
+    1.0
+}
+
+/// Window bounds for position and size persistence.
+///
+/// `width`/`height`/`x`/`y` are physical pixels, as reported by
+/// `Window::outer_size`/`outer_position`. `scale_factor` records the display
+/// scale they were captured at, so [`WindowBounds::to_logical`] and
+/// [`WindowBounds::to_physical`] can convert between physical and
+/// DPI-independent logical pixels when restoring on a monitor with a
+/// different scale.
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
 pub struct WindowBounds {
     pub width: u32,
     pub height: u32,
     pub x: Option<i32>,
     pub y: Option<i32>,
+    #[serde(default = "default_scale_factor")]
+    pub scale_factor: f64,
 }
 
 impl Default for WindowBounds {
@@ -110,6 +294,33 @@ impl Default for WindowBounds {
             height: 800,
             x: None,
             y: None,
+            scale_factor: default_scale_factor(),
+        }
+    }
+}
+
+impl WindowBounds {
+    /// Converts these bounds, assumed to be in logical pixels at
+    /// `self.scale_factor`, to physical pixels.
+    pub fn to_physical(&self) -> WindowBounds {
+        WindowBounds {
+            width: (self.width as f64 * self.scale_factor).round() as u32,
+            height: (self.height as f64 * self.scale_factor).round() as u32,
+            x: self.x.map(|x| (x as f64 * self.scale_factor).round() as i32),
+            y: self.y.map(|y| (y as f64 * self.scale_factor).round() as i32),
+            scale_factor: self.scale_factor,
+        }
+    }
+
+    /// Converts these bounds, assumed to be in physical pixels at
+    /// `self.scale_factor`, to logical pixels.
+    pub fn to_logical(&self) -> WindowBounds {
+        WindowBounds {
+            width: (self.width as f64 / self.scale_factor).round() as u32,
+            height: (self.height as f64 / self.scale_factor).round() as u32,
+            x: self.x.map(|x| (x as f64 / self.scale_factor).round() as i32),
+            y: self.y.map(|y| (y as f64 / self.scale_factor).round() as i32),
+            scale_factor: self.scale_factor,
         }
     }
 }
@@ -131,6 +342,50 @@ pub struct LastNote {
     pub folder: Option<String>,
 }
 
+/// An entry in the recently-opened notes list, surfaced in the tray's
+/// "Recent Files" submenu.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+pub struct RecentFile {
+    pub note_id: String,
+    pub folder: Option<String>,
+    pub title: String,
+}
+
+/// A detached note window currently open, surfaced to the frontend so it can
+/// e.g. list or focus them.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct NoteWindowInfo {
+    pub label: String,
+    pub note_id: String,
+    pub folder: Option<String>,
+}
+
+/// A single row of a keybinding table: an action's id, description, and its
+/// currently bound key (if any).
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct ShortcutActionInfo {
+    pub id: String,
+    pub description: String,
+    pub current_key: Option<String>,
+}
+
+/// How the main window should present itself on launch.
+///
+/// Mirrors Alacritty's `window.startup_mode`.
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, Default, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum StartupMode {
+    /// Restore `window_bounds` as normal - the existing default behavior.
+    #[default]
+    Windowed,
+    /// Maximize the window, ignoring the saved `window_bounds` width/height.
+    Maximized,
+    /// Come up directly in minimal mode using `minimal_mode_bounds`.
+    Minimal,
+}
+
 /// Application configuration
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
 pub struct Config {
@@ -143,7 +398,47 @@ pub struct Config {
     pub pinned_notes: Vec<String>,
     pub minimal_mode_bounds: WindowBounds,
     pub window_opacity: f64,
+    /// Opacity applied while in normal (non-minimal) mode. Separate from
+    /// [`Config::minimal_opacity`] so toggling minimal mode can fade between
+    /// the two instead of jumping straight to `window_opacity`.
+    #[serde(default = "default_opacity")]
+    pub normal_opacity: f64,
+    /// Opacity applied while in minimal mode. See [`Config::normal_opacity`].
+    #[serde(default = "default_opacity")]
+    pub minimal_opacity: f64,
     pub auto_start_on_boot: bool,
+    pub recent_files: Vec<RecentFile>,
+    /// When enabled, the tray left-click toggle repositions the window
+    /// directly under the tray icon instead of leaving it where it last was.
+    pub menubar_mode: bool,
+    /// Persisted position/size/mode per window label, restored on startup.
+    pub window_states: HashMap<String, WindowState>,
+    /// Whether the main window should stay visible across all virtual
+    /// desktops/workspaces (macOS Spaces), independent of minimal mode.
+    pub visible_on_all_workspaces: bool,
+    /// How the main window should present itself on launch.
+    #[serde(default)]
+    pub startup_mode: StartupMode,
+    /// Schema version of this config, used by [`Config::migrate`] to decide
+    /// which upgrade steps a loaded config still needs. Absent on disk (and
+    /// on `#[serde(default)]`) means v0 - the shape before this field
+    /// existed.
+    #[serde(default = "default_config_version")]
+    pub version: u32,
+}
+
+/// The current config schema version. Bump this and add a migration step in
+/// [`Config::migrate`] whenever a change needs more than `#[serde(default)]`
+/// to load gracefully (a rename, a type change, a value that depends on
+/// other fields).
+pub const CONFIG_VERSION: u32 = 3;
+
+fn default_config_version() -> u32 {
+    CONFIG_VERSION
+}
+
+fn default_opacity() -> f64 {
+    1.0
 }
 
 impl Default for Config {
@@ -161,9 +456,108 @@ impl Default for Config {
                 height: 300,
                 x: None,
                 y: None,
+                scale_factor: default_scale_factor(),
             },
             window_opacity: 1.0,
+            normal_opacity: default_opacity(),
+            minimal_opacity: default_opacity(),
             auto_start_on_boot: false,
+            recent_files: vec![],
+            menubar_mode: false,
+            window_states: HashMap::new(),
+            visible_on_all_workspaces: false,
+            startup_mode: StartupMode::default(),
+            version: CONFIG_VERSION,
+        }
+    }
+}
+
+impl Config {
+    /// Upgrades a raw, possibly-outdated config JSON value to the current
+    /// `Config` shape, reading its schema version from the `version` key
+    /// (absent means v0, the shape before that key existed). Ordered
+    /// transform steps are applied below, each bumping the version by one,
+    /// so running this on an already-current config is a no-op. Fields
+    /// missing from `raw` (because it predates them, or a step renamed them
+    /// away) fall back to [`Config::default`]'s values rather than failing
+    /// to load; fields `raw` has that the current `Config` doesn't recognize
+    /// are silently dropped during the final deserialize.
+    ///
+    /// This is the mechanism for bulk version-to-version upgrades (e.g.
+    /// importing a config believed to be from an older release);
+    /// [`crate::config::ConfigManager`]'s own loader routes through here too
+    /// (see `ConfigManager::load_from_file`), so an on-disk config is always
+    /// brought up to `CONFIG_VERSION` before being merged with defaults.
+    pub fn migrate(mut raw: serde_json::Value) -> Config {
+        let mut version = raw.get("version").and_then(|v| v.as_u64()).unwrap_or(0) as u32;
+
+        for (from_version, step) in MIGRATIONS {
+            if version == *from_version {
+                step(&mut raw);
+                version += 1;
+            }
+        }
+
+        // Merge the (possibly still-partial) migrated value onto a full set
+        // of current defaults, so missing fields deserialize instead of
+        // failing the whole config.
+        let mut merged = serde_json::to_value(Config::default()).expect("Config::default always serializes");
+        if let (Some(defaults), Some(overrides)) = (merged.as_object_mut(), raw.as_object()) {
+            for (key, value) in overrides {
+                defaults.insert(key.clone(), value.clone());
+            }
+        }
+        merged["version"] = serde_json::json!(version);
+
+        serde_json::from_value(merged).unwrap_or_default()
+    }
+}
+
+/// Ordered upgrade steps, keyed by the version a config must already be at
+/// for the step to apply. `Config::migrate` walks this table top to bottom,
+/// so it relies on entries appearing in ascending `from_version` order -
+/// bumping `CONFIG_VERSION` means appending one more `(CONFIG_VERSION - 1,
+/// migrate_vN_to_vN1)` entry here, never reordering or removing existing
+/// ones (a user's config on disk may still be sitting at any older version).
+const MIGRATIONS: &[(u32, fn(&mut serde_json::Value))] = &[
+    (0, migrate_v0_to_v1),
+    (1, migrate_v1_to_v2),
+    (2, migrate_v2_to_v3),
+];
+
+/// v0 (no `version` key at all) to v1 (the `startup_mode` field): fills in
+/// the new field's default rather than relying solely on `#[serde(default)]`,
+/// so this step stays self-contained even if that attribute is ever removed.
+fn migrate_v0_to_v1(raw: &mut serde_json::Value) {
+    if let Some(obj) = raw.as_object_mut() {
+        obj.entry("startup_mode").or_insert_with(|| serde_json::json!("windowed"));
+    }
+}
+
+/// v1 to v2 (split `window_opacity` into `normal_opacity`/`minimal_opacity`):
+/// seeds both new fields from the old single opacity value, so a user who
+/// had dimmed their window doesn't see it snap back to fully opaque.
+fn migrate_v1_to_v2(raw: &mut serde_json::Value) {
+    if let Some(obj) = raw.as_object_mut() {
+        let previous_opacity = obj.get("window_opacity").and_then(|v| v.as_f64()).unwrap_or(1.0);
+        obj.entry("normal_opacity").or_insert_with(|| serde_json::json!(previous_opacity));
+        obj.entry("minimal_opacity").or_insert_with(|| serde_json::json!(previous_opacity));
+    }
+}
+
+/// v2 to v3 (rescale a legacy 0-100 opacity scale to the 0.0-1.0 scale
+/// every opacity field has used since): a config written before opacity was
+/// stored as a fraction has `window_opacity`/`normal_opacity`/`minimal_opacity`
+/// in whole percent, so dividing by 100 keeps it visually the same instead
+/// of rendering the window 100x more opaque than before.
+fn migrate_v2_to_v3(raw: &mut serde_json::Value) {
+    if let Some(obj) = raw.as_object_mut() {
+        for field in ["window_opacity", "normal_opacity", "minimal_opacity"] {
+            if let Some(value) = obj.get(field).and_then(|v| v.as_f64()) {
+                if value > 1.0 {
+                    obj.insert(field.to_string(), serde_json::json!(value / 100.0));
+                }
+            }
         }
     }
 }
@@ -195,6 +589,102 @@ mod tests {
         assert!(config.pinned_notes.is_empty());
     }
 
+    #[test]
+    fn test_config_default_startup_mode_is_windowed() {
+        assert_eq!(Config::default().startup_mode, StartupMode::Windowed);
+    }
+
+    #[test]
+    fn test_config_without_startup_mode_field_deserializes_to_windowed() {
+        // A config saved before this field existed has no "startup_mode" key
+        // at all - it must still deserialize, defaulting to Windowed.
+        let mut value = serde_json::to_value(Config::default()).expect("Failed to serialize Config");
+        value.as_object_mut().unwrap().remove("startup_mode");
+
+        let config: Config = serde_json::from_value(value).expect("Failed to deserialize Config missing startup_mode");
+        assert_eq!(config.startup_mode, StartupMode::Windowed);
+    }
+
+    #[test]
+    fn test_migrate_v0_config_fills_defaults_and_sets_version() {
+        // A v0 config: no "version" key, no "startup_mode" key.
+        let v0 = serde_json::json!({ "global_shortcut": "Ctrl+Alt+Z" });
+
+        let config = Config::migrate(v0);
+        assert_eq!(config.version, CONFIG_VERSION);
+        assert_eq!(config.global_shortcut, "Ctrl+Alt+Z");
+        assert_eq!(config.startup_mode, StartupMode::Windowed);
+        // Unspecified fields fall back to defaults rather than failing.
+        assert_eq!(config.window_opacity, 1.0);
+    }
+
+    #[test]
+    fn test_migrate_is_idempotent_on_an_already_current_config() {
+        let mut config = Config::default();
+        config.global_shortcut = "Custom".to_string();
+
+        let raw = serde_json::to_value(&config).unwrap();
+        let migrated = Config::migrate(raw);
+        assert_eq!(migrated, config);
+
+        let migrated_again = Config::migrate(serde_json::to_value(&migrated).unwrap());
+        assert_eq!(migrated_again, migrated);
+    }
+
+    #[test]
+    fn test_migrate_drops_unknown_fields() {
+        let mut raw = serde_json::to_value(Config::default()).unwrap();
+        raw.as_object_mut().unwrap().insert("totally_unknown_field".to_string(), serde_json::json!(42));
+
+        assert_eq!(Config::migrate(raw), Config::default());
+    }
+
+    // Strategy producing a JSON value shaped like one of this config
+    // format's historical versions.
+    fn historical_config_value_strategy() -> impl Strategy<Value = serde_json::Value> {
+        prop_oneof![
+            // v0: nothing but an empty object.
+            Just(serde_json::json!({})),
+            // v0: a partial config with only a couple of fields set.
+            Just(serde_json::json!({ "window_opacity": 0.5, "auto_start_on_boot": true })),
+            // v2: opacity still on the old 0-100 scale.
+            Just(serde_json::json!({ "version": 2, "window_opacity": 80.0 })),
+            // v3 (current): a fully-populated, already-versioned config.
+            Just(serde_json::to_value(Config::default()).unwrap()),
+        ]
+    }
+
+    #[test]
+    fn test_migrate_v2_config_rescales_legacy_percent_opacity() {
+        let v2 = serde_json::json!({ "version": 2, "window_opacity": 50.0 });
+
+        let config = Config::migrate(v2);
+        assert_eq!(config.version, CONFIG_VERSION);
+        assert_eq!(config.window_opacity, 0.5);
+    }
+
+    proptest! {
+        #![proptest_config(ProptestConfig::with_cases(50))]
+
+        /// For any historically-shaped config JSON, migrating it should
+        /// always produce a valid, current-version `Config` whose fields
+        /// the input didn't set fall back to `Config::default`.
+        #[test]
+        fn prop_migrate_produces_current_version_config(raw in historical_config_value_strategy()) {
+            let config = Config::migrate(raw.clone());
+            prop_assert_eq!(config.version, CONFIG_VERSION);
+
+            let defaults = Config::default();
+            let set_fields = raw.as_object().map(|o| o.keys().cloned().collect()).unwrap_or_else(std::collections::HashSet::new);
+            if !set_fields.contains("global_shortcut") {
+                prop_assert_eq!(&config.global_shortcut, &defaults.global_shortcut);
+            }
+            if !set_fields.contains("auto_start_on_boot") {
+                prop_assert_eq!(config.auto_start_on_boot, defaults.auto_start_on_boot);
+            }
+        }
+    }
+
     #[test]
     fn test_window_bounds_default() {
         let bounds = WindowBounds::default();
@@ -269,14 +759,37 @@ mod tests {
                         file_path,
                         pinned,
                         opacity,
+                        invalid_token: None,
+                        valid_values: None,
+                        moved_count: None,
+                        was_new: None,
+                        checksum: None,
+                        checksum_algorithm: None,
                     }
                 },
             )
     }
 
+    fn startup_mode_strategy() -> impl Strategy<Value = StartupMode> {
+        prop_oneof![
+            Just(StartupMode::Windowed),
+            Just(StartupMode::Maximized),
+            Just(StartupMode::Minimal),
+        ]
+    }
+
     proptest! {
         #![proptest_config(ProptestConfig::with_cases(100))]
 
+        /// For any `StartupMode`, serializing to JSON and deserializing back
+        /// should produce the same variant.
+        #[test]
+        fn prop_startup_mode_serialization_round_trip(mode in startup_mode_strategy()) {
+            let serialized = serde_json::to_string(&mode).expect("Failed to serialize StartupMode");
+            let deserialized: StartupMode = serde_json::from_str(&serialized).expect("Failed to deserialize StartupMode");
+            prop_assert_eq!(mode, deserialized);
+        }
+
         /// **Feature: mded-tauri-migration, Property: ApiResult serialization round-trip**
         /// **Validates: Requirements 19.2, 19.3**
         /// 
@@ -1,5 +1,6 @@
 use std::collections::HashMap;
 use std::sync::Mutex;
+use std::time::{Duration, Instant};
 use tauri::{AppHandle, Emitter, Manager, Runtime};
 use tauri_plugin_clipboard_manager::ClipboardExt;
 use tauri_plugin_global_shortcut::{GlobalShortcutExt, Shortcut, ShortcutState};
@@ -9,18 +10,224 @@ use crate::config::ConfigManager;
 use crate::filesystem::FileSystem;
 use crate::models::ApiResult;
 
+/// A user-bindable shortcut action.
+///
+/// This is the data-driven replacement for a bespoke `register_*` method per
+/// action: adding a new action means adding a variant here and a `dispatch`
+/// arm, not another copy-pasted registration method.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Action {
+    ToggleWindow,
+    CaptureClipboard,
+    QuickNote,
+}
+
+impl Action {
+    /// All known actions, in display order.
+    pub const ALL: [Action; 3] = [Action::ToggleWindow, Action::CaptureClipboard, Action::QuickNote];
+
+    /// Stable id used as the key in `registered_shortcuts`.
+    pub fn id(&self) -> &'static str {
+        match self {
+            Action::ToggleWindow => "toggle",
+            Action::CaptureClipboard => "clipboard",
+            Action::QuickNote => "quick_note",
+        }
+    }
+
+    /// Human-readable description, for a settings/help UI's keybinding table.
+    pub fn description(&self) -> &'static str {
+        match self {
+            Action::ToggleWindow => "Show or hide the main window",
+            Action::CaptureClipboard => "Save the clipboard contents as a new note",
+            Action::QuickNote => "Open the quick note popup",
+        }
+    }
+
+    /// The accelerator registered for this action when no user override exists.
+    pub fn default_key(&self) -> &'static str {
+        match self {
+            Action::ToggleWindow => "CommandOrControl+Shift+N",
+            Action::CaptureClipboard => "CommandOrControl+Alt+V",
+            Action::QuickNote => "CommandOrControl+Alt+N",
+        }
+    }
+
+    /// Runs this action's handler in response to its shortcut firing.
+    fn dispatch<R: Runtime>(&self, app: &AppHandle<R>) {
+        match self {
+            Action::ToggleWindow => toggle_window_visibility(app),
+            Action::CaptureClipboard => capture_clipboard_to_note(app),
+            Action::QuickNote => open_quick_note_window(app),
+        }
+    }
+}
+
+/// How long a partial chord sequence stays "live" waiting for its next key.
+const SEQUENCE_TIMEOUT: Duration = Duration::from_millis(1000);
+
+/// Canonical modifier names accepted by `parse_shortcut`, used to resolve
+/// unambiguous prefixes (e.g. "Ct" -> "Ctrl").
+const MODIFIER_NAMES: &[&str] = &[
+    "CommandOrControl",
+    "CmdOrCtrl",
+    "Command",
+    "Cmd",
+    "Control",
+    "Ctrl",
+    "Option",
+    "Alt",
+    "AltGr",
+    "Shift",
+    "Super",
+    "Meta",
+];
+
+/// Canonical key names accepted by `parse_shortcut`, used to resolve
+/// unambiguous prefixes (e.g. "Esc" -> "Escape").
+const KEY_NAMES: &[&str] = &[
+    "A", "B", "C", "D", "E", "F", "G", "H", "I", "J", "K", "L", "M", "N", "O", "P", "Q", "R", "S",
+    "T", "U", "V", "W", "X", "Y", "Z", "0", "1", "2", "3", "4", "5", "6", "7", "8", "9", "F1", "F2",
+    "F3", "F4", "F5", "F6", "F7", "F8", "F9", "F10", "F11", "F12", "F13", "F14", "F15", "F16",
+    "F17", "F18", "F19", "F20", "F21", "F22", "F23", "F24", "Space", "Tab", "Enter", "Escape",
+    "Backspace", "Delete", "Insert", "Home", "End", "PageUp", "PageDown", "ArrowUp", "ArrowDown",
+    "ArrowLeft", "ArrowRight", "CapsLock", "NumLock", "ScrollLock", "PrintScreen", "Pause",
+];
+
+/// A registered keybinding: a single accelerator, or an ordered chord
+/// sequence (e.g. `"CommandOrControl+K"` then `"G"`).
+#[derive(Clone)]
+enum Binding {
+    Single(String),
+    Sequence { chords: Vec<String>, parsed: Vec<Shortcut> },
+}
+
+impl Binding {
+    /// Renders the binding back to its accelerator string - chords joined by
+    /// spaces for sequences - for display in a settings UI.
+    fn display(&self) -> String {
+        match self {
+            Binding::Single(key) => key.clone(),
+            Binding::Sequence { chords, .. } => chords.join(" "),
+        }
+    }
+}
+
+/// A node in the prefix trie of registered chord sequences, keyed by each
+/// chord's accelerator string.
+#[derive(Default)]
+struct SequenceNode {
+    /// The action bound if a sequence terminates at this node.
+    action: Option<Action>,
+    /// Possible next chords from here, keyed by accelerator string.
+    children: HashMap<String, SequenceNode>,
+}
+
+impl SequenceNode {
+    /// Inserts `action` at the end of `remaining`, creating intermediate
+    /// nodes as needed.
+    fn insert(&mut self, remaining: &[String], action: Action) {
+        match remaining.split_first() {
+            None => self.action = Some(action),
+            Some((head, rest)) => self.children.entry(head.clone()).or_default().insert(rest, action),
+        }
+    }
+}
+
+/// Walks `trie` along `path` (a leader chord followed by zero or more
+/// intermediate chords), returning the node at the end if the whole path is
+/// a registered prefix.
+fn walk_trie<'a>(trie: &'a HashMap<String, SequenceNode>, path: &[String]) -> Option<&'a SequenceNode> {
+    let (leader, rest) = path.split_first()?;
+    let mut node = trie.get(leader)?;
+    for chord in rest {
+        node = node.children.get(chord)?;
+    }
+    Some(node)
+}
+
+/// Tracks an in-progress chord sequence: the chords matched so far (used to
+/// re-walk the trie from the root) and the deadline for the next chord.
+struct PendingSequence {
+    path: Vec<String>,
+    deadline: Instant,
+}
+
+/// Structured detail about why `parse_shortcut` rejected an accelerator:
+/// which token was unrecognized or ambiguous, and the full set of values it
+/// could have been, so a settings UI can render a specific "did you mean"
+/// suggestion instead of a generic message.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ShortcutParseError {
+    /// The full accelerator string that was rejected.
+    pub invalid_arg: String,
+    /// The specific token within `invalid_arg` that was unrecognized or
+    /// ambiguous. `None` when the failure isn't about a single token (e.g.
+    /// no modifier/key tokens at all).
+    pub invalid_value: Option<String>,
+    /// Every modifier or key name `invalid_value` could have resolved to -
+    /// the full candidate list if nothing matched, or just the ambiguous
+    /// subset if more than one prefix matched.
+    pub valid_values: Vec<String>,
+    /// The underlying parser's error message, for failures that aren't
+    /// token-level (e.g. the resolved accelerator still failed to parse).
+    pub cause: Option<String>,
+}
+
+impl std::fmt::Display for ShortcutParseError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match (&self.invalid_value, &self.cause) {
+            (Some(token), _) => write!(
+                f,
+                "Invalid shortcut '{}': '{}' is not a recognized modifier or key (valid values: {})",
+                self.invalid_arg,
+                token,
+                self.valid_values.join(", ")
+            ),
+            (None, Some(cause)) => write!(f, "Invalid shortcut '{}': {}", self.invalid_arg, cause),
+            (None, None) => write!(f, "Invalid shortcut '{}'", self.invalid_arg),
+        }
+    }
+}
+
+impl std::error::Error for ShortcutParseError {}
+
+/// Outcome of a transactional [`ShortcutManager::register_all`] call.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct RegisterAllSummary {
+    /// Action ids that registered successfully.
+    pub registered: Vec<String>,
+    /// Action ids that failed to register, paired with their error message.
+    pub failed: Vec<(String, String)>,
+}
+
 /// ShortcutManager handles global keyboard shortcuts for the application.
-/// 
-/// Manages three types of shortcuts:
-/// - Toggle shortcut: Shows/hides the main window
-/// - Clipboard capture shortcut: Creates a note from clipboard content
-/// - Quick note shortcut: Opens the quick note popup window
-/// 
+///
+/// Keeps a registry of named [`Action`]s bound to key combinations, so any
+/// action can be registered, rebound, or listed through the same API rather
+/// than through one-off methods per action. Also layers vim-style leader-key
+/// chord sequences (e.g. "Ctrl+K" then "G") over the OS-level global shortcut
+/// plugin, which only understands single accelerators. Rejects registering
+/// an accelerator that's already bound elsewhere ([`ShortcutManager::check_conflict`])
+/// and tracks each action's pressed/released state so a held key's OS
+/// auto-repeat doesn't re-dispatch the action on every repeat.
+///
 /// # Requirements
 /// Validates: Requirements 7.1, 7.2, 7.3, 7.4, 7.5
 pub struct ShortcutManager {
-    /// Map of shortcut names to their registered key combinations
-    registered_shortcuts: Mutex<HashMap<String, String>>,
+    /// Map of action ids to their registered key combinations
+    registered_shortcuts: Mutex<HashMap<String, Binding>>,
+    /// Chord sequence trie, keyed by leader accelerator string
+    sequence_trie: Mutex<HashMap<String, SequenceNode>>,
+    /// The chord sequence currently being matched, if any
+    pending: Mutex<Option<PendingSequence>>,
+    /// Intermediate-step accelerators currently registered with the OS while
+    /// a sequence is pending, so they can be torn down on fire/timeout/reset
+    temp_registered: Mutex<Vec<Shortcut>>,
+    /// Last-seen pressed/released state per action id, so a held key's OS
+    /// auto-repeat `Pressed` events can be told apart from the initial
+    /// key-down and don't re-dispatch the action on every repeat.
+    key_states: Mutex<HashMap<String, bool>>,
 }
 
 impl ShortcutManager {
@@ -28,179 +235,582 @@ impl ShortcutManager {
     pub fn new() -> Self {
         Self {
             registered_shortcuts: Mutex::new(HashMap::new()),
+            sequence_trie: Mutex::new(HashMap::new()),
+            pending: Mutex::new(None),
+            temp_registered: Mutex::new(Vec::new()),
+            key_states: Mutex::new(HashMap::new()),
+        }
+    }
+
+    /// Records a shortcut event's pressed/released state for `action_id` and
+    /// reports whether a `Pressed` event is an OS auto-repeat of an
+    /// already-held key rather than the initial key-down.
+    ///
+    /// # Returns
+    /// `true` if this `Pressed` event is a repeat (the action should not
+    /// re-dispatch); always `false` for a `Released` event or an initial
+    /// press.
+    fn note_key_event(&self, action_id: &str, state: ShortcutState) -> bool {
+        let mut states = self.key_states.lock().unwrap();
+        match state {
+            ShortcutState::Pressed => states.insert(action_id.to_string(), true).unwrap_or(false),
+            ShortcutState::Released => {
+                states.insert(action_id.to_string(), false);
+                false
+            }
         }
     }
 
     /// Parses a shortcut string into a Shortcut struct.
-    /// 
+    ///
+    /// Each `+`-separated token may be an unambiguous prefix of its canonical
+    /// modifier or key name (e.g. "Ct+S" resolves to "Ctrl+S"), resolved by
+    /// [`Self::resolve_accelerator`] before the canonical string is handed to
+    /// the underlying parser. For the structured failure detail (offending
+    /// token, valid-value list), see [`Self::parse_shortcut_detailed`].
+    ///
     /// # Arguments
     /// * `key` - The shortcut string (e.g., "CommandOrControl+Shift+N")
-    /// 
+    ///
     /// # Returns
     /// * `Ok(Shortcut)` - The parsed shortcut
-    /// * `Err(String)` - If parsing fails
-    /// 
+    /// * `Err(String)` - If a token is invalid, ambiguous, or the resolved
+    ///   accelerator otherwise fails to parse
+    ///
     /// # Requirements
     /// Validates: Requirements 7.5
     pub fn parse_shortcut(key: &str) -> Result<Shortcut, String> {
-        key.parse::<Shortcut>()
-            .map_err(|e| format!("Invalid shortcut '{}': {}", key, e))
+        Self::parse_shortcut_detailed(key).map_err(|e| e.to_string())
     }
 
-    /// Registers all shortcuts on application startup.
-    /// 
-    /// Reads shortcut configurations from the config manager and registers them.
-    /// 
+    /// Parses a shortcut string, returning a [`ShortcutParseError`] with the
+    /// offending token and the full list of valid values on failure, so a
+    /// settings UI can render a specific "did you mean" suggestion instead
+    /// of a generic message.
+    ///
+    /// # Arguments
+    /// * `key` - The shortcut string (e.g., "CommandOrControl+Shift+N")
+    ///
+    /// # Returns
+    /// * `Ok(Shortcut)` - The parsed shortcut
+    /// * `Err(ShortcutParseError)` - Structured detail about the failure
+    pub fn parse_shortcut_detailed(key: &str) -> Result<Shortcut, ShortcutParseError> {
+        let canonical = Self::resolve_accelerator(key)?;
+        let platform_accelerator = Self::to_platform_accelerator(&canonical);
+        platform_accelerator.parse::<Shortcut>().map_err(|e| ShortcutParseError {
+            invalid_arg: key.to_string(),
+            invalid_value: None,
+            valid_values: Vec::new(),
+            cause: Some(e.to_string()),
+        })
+    }
+
+    /// Converts a fully-resolved, portable accelerator string (e.g.
+    /// `"CmdOrCtrl+Shift+S"`) to the concrete form the underlying parser
+    /// expects for the current OS (e.g. `"Command+Shift+S"` on macOS,
+    /// `"Control+Shift+S"` elsewhere), via
+    /// [`Self::normalize_modifier_for_platform`]. The key token is untouched.
+    fn to_platform_accelerator(canonical: &str) -> String {
+        let tokens: Vec<&str> = canonical.split('+').collect();
+        let (modifiers, main_key) = tokens.split_at(tokens.len() - 1);
+        let mut resolved: Vec<String> =
+            modifiers.iter().map(|m| Self::normalize_modifier_for_platform(m)).collect();
+        resolved.push(main_key[0].to_string());
+        resolved.join("+")
+    }
+
+    /// Maps a portable modifier alias to the concrete modifier for the
+    /// current OS, so one keymap definition works across platforms:
+    /// `CommandOrControl`/`CmdOrCtrl` resolves to `Command` on macOS and
+    /// `Control` everywhere else; `Option` resolves to `Alt`, the name the
+    /// underlying parser uses for it on every platform. Every other
+    /// modifier is already platform-independent and passes through
+    /// unchanged.
+    fn normalize_modifier_for_platform(modifier: &str) -> String {
+        match modifier {
+            "CommandOrControl" | "CmdOrCtrl" => {
+                if cfg!(target_os = "macos") { "Command" } else { "Control" }
+            }
+            "Option" => "Alt",
+            other => other,
+        }
+        .to_string()
+    }
+
+    /// Renders `key` to its canonical human-readable form for the current
+    /// platform: symbol glyphs with no separator on macOS (e.g. "⌘⇧S"),
+    /// word names joined by "+" elsewhere (e.g. "Ctrl+Shift+S"). This is
+    /// purely cosmetic - [`Self::parse_shortcut`] is still what resolves and
+    /// registers the accelerator.
+    ///
+    /// # Returns
+    /// The display string, or `key` unchanged if it doesn't parse.
+    pub fn display_shortcut(key: &str) -> String {
+        let Ok(canonical) = Self::resolve_accelerator(key) else {
+            return key.to_string();
+        };
+        let tokens: Vec<&str> = canonical.split('+').collect();
+        let (modifiers, main_key) = tokens.split_at(tokens.len() - 1);
+
+        if cfg!(target_os = "macos") {
+            let symbols: String = modifiers.iter().map(|m| Self::macos_modifier_symbol(m)).collect();
+            format!("{}{}", symbols, main_key[0])
+        } else {
+            let mut parts: Vec<String> = modifiers.iter().map(|m| Self::non_macos_modifier_name(m)).collect();
+            parts.push(main_key[0].to_string());
+            parts.join("+")
+        }
+    }
+
+    /// The macOS glyph for a resolved modifier name.
+    fn macos_modifier_symbol(modifier: &str) -> String {
+        match modifier {
+            "CommandOrControl" | "CmdOrCtrl" | "Command" | "Cmd" => "⌘",
+            "Control" | "Ctrl" => "⌃",
+            "Option" | "Alt" | "AltGr" => "⌥",
+            "Shift" => "⇧",
+            "Super" | "Meta" => "⌘",
+            other => other,
+        }
+        .to_string()
+    }
+
+    /// The Windows/Linux word name for a resolved modifier name.
+    fn non_macos_modifier_name(modifier: &str) -> String {
+        match modifier {
+            "CommandOrControl" | "CmdOrCtrl" | "Control" | "Ctrl" => "Ctrl",
+            "Command" | "Cmd" => "Cmd",
+            "Option" | "Alt" | "AltGr" => "Alt",
+            "Super" | "Meta" => "Super",
+            other => other,
+        }
+        .to_string()
+    }
+
+    /// Resolves every `+`-separated token of `key` to its canonical modifier
+    /// or key name, accepting unambiguous prefixes. The last token is
+    /// resolved against [`KEY_NAMES`]; every token before it against
+    /// [`MODIFIER_NAMES`].
+    ///
+    /// # Returns
+    /// * `Ok(String)` - The canonical, fully-resolved accelerator string
+    /// * `Err(ShortcutParseError)` - If a token matches no candidate,
+    ///   matches more than one candidate (ambiguous), or `key` has no
+    ///   modifier/key tokens
+    fn resolve_accelerator(key: &str) -> Result<String, ShortcutParseError> {
+        let tokens: Vec<&str> = key.split('+').collect();
+        if tokens.len() < 2 || tokens.iter().any(|t| t.is_empty()) {
+            return Err(ShortcutParseError {
+                invalid_arg: key.to_string(),
+                invalid_value: None,
+                valid_values: Vec::new(),
+                cause: Some("expected at least one modifier and a key".to_string()),
+            });
+        }
+
+        let (modifiers, main_key) = tokens.split_at(tokens.len() - 1);
+
+        let mut resolved: Vec<String> = Vec::with_capacity(tokens.len());
+        for token in modifiers {
+            resolved.push(Self::resolve_token(key, token, MODIFIER_NAMES)?);
+        }
+        resolved.push(Self::resolve_token(key, main_key[0], KEY_NAMES)?);
+
+        Ok(resolved.join("+"))
+    }
+
+    /// Resolves `token` against `candidates`: an exact (case-insensitive)
+    /// match always wins, even over a longer candidate it also prefixes;
+    /// otherwise a single case-insensitive prefix match is accepted, and
+    /// more than one is reported as ambiguous. `accelerator` is the full
+    /// original shortcut string, carried into the error for context.
+    ///
+    /// # Returns
+    /// * `Ok(String)` - The resolved canonical candidate
+    /// * `Err(ShortcutParseError)` - If no candidate matches (`valid_values`
+    ///   lists every candidate in this category) or more than one prefix
+    ///   matches (`valid_values` lists just the ambiguous ones)
+    fn resolve_token(accelerator: &str, token: &str, candidates: &[&str]) -> Result<String, ShortcutParseError> {
+        if let Some(exact) = candidates.iter().find(|c| c.eq_ignore_ascii_case(token)) {
+            return Ok((*exact).to_string());
+        }
+
+        let lower = token.to_lowercase();
+        let matches: Vec<String> = candidates
+            .iter()
+            .filter(|c| c.to_lowercase().starts_with(&lower))
+            .map(|c| c.to_string())
+            .collect();
+
+        match matches.len() {
+            0 => Err(ShortcutParseError {
+                invalid_arg: accelerator.to_string(),
+                invalid_value: Some(token.to_string()),
+                valid_values: candidates.iter().map(|c| c.to_string()).collect(),
+                cause: None,
+            }),
+            1 => Ok(matches.into_iter().next().unwrap()),
+            _ => Err(ShortcutParseError {
+                invalid_arg: accelerator.to_string(),
+                invalid_value: Some(token.to_string()),
+                valid_values: matches,
+                cause: None,
+            }),
+        }
+    }
+
+    /// Registers all shortcuts on application startup, transactionally.
+    ///
+    /// Reads shortcut configurations from the config manager, validates every
+    /// accelerator up front, and registers them one by one. If any
+    /// registration fails partway through, everything registered during this
+    /// call is rolled back so startup never leaves the app in a half-bound
+    /// state; the error message lists which binding(s) failed.
+    ///
     /// # Arguments
     /// * `app` - The Tauri application handle
-    /// 
+    ///
     /// # Returns
-    /// * `Ok(())` - If all shortcuts were registered successfully
-    /// * `Err(String)` - If registration fails
-    pub fn register_all<R: Runtime>(&self, app: &AppHandle<R>) -> Result<(), String> {
+    /// * `Ok(RegisterAllSummary)` - If every binding registered successfully
+    /// * `Err(String)` - An aggregated message naming the invalid or
+    ///   conflicting binding(s); nothing from this call remains registered
+    pub fn register_all<R: Runtime>(&self, app: &AppHandle<R>) -> Result<RegisterAllSummary, String> {
         let config_manager = app.state::<ConfigManager>();
         let config = config_manager.get();
 
-        // Register toggle shortcut
-        self.register_toggle_shortcut(app, &config.global_shortcut)?;
+        let bindings = [
+            (Action::ToggleWindow, config.global_shortcut.clone()),
+            (Action::CaptureClipboard, config.clipboard_shortcut.clone()),
+            (Action::QuickNote, config.quick_note_shortcut.clone()),
+        ];
 
-        // Register clipboard capture shortcut
-        self.register_clipboard_shortcut(app, &config.clipboard_shortcut)?;
+        // Validate every accelerator up front so a single typo doesn't leave
+        // earlier bindings registered while a later one is rejected.
+        let parse_failures: Vec<(String, String)> = bindings
+            .iter()
+            .filter_map(|(action, key)| Self::parse_shortcut(key).err().map(|e| (action.id().to_string(), e)))
+            .collect();
+        if !parse_failures.is_empty() {
+            return Err(Self::format_binding_failures(&parse_failures));
+        }
 
-        // Register quick note shortcut
-        self.register_quick_note_shortcut(app, &config.quick_note_shortcut)?;
+        let mut registered: Vec<String> = Vec::new();
+        for (action, key) in &bindings {
+            match self.register_action(app, *action, key) {
+                Ok(()) => registered.push(action.id().to_string()),
+                Err(e) => {
+                    for id in &registered {
+                        let _ = self.unregister_shortcut(app, id);
+                    }
+                    return Err(Self::format_binding_failures(&[(action.id().to_string(), e)]));
+                }
+            }
+        }
 
-        Ok(())
+        Ok(RegisterAllSummary { registered, failed: Vec::new() })
     }
 
-    /// Registers the toggle window visibility shortcut.
-    /// 
-    /// Default: CommandOrControl+Shift+N
-    /// 
+    /// Renders a list of `(action id, error)` pairs as a single aggregated
+    /// error message for `register_all`.
+    fn format_binding_failures(failures: &[(String, String)]) -> String {
+        let details: Vec<String> = failures
+            .iter()
+            .map(|(id, err)| format!("{}: {}", id, err))
+            .collect();
+        format!("Failed to register shortcut(s): {}", details.join("; "))
+    }
+
+    /// Registers (or re-registers) the global hotkey for `action`.
+    ///
+    /// Checks for a conflict with `key` *before* touching `action`'s existing
+    /// binding, so a rejected rebind leaves the old shortcut in place instead
+    /// of tearing it down and then failing to install its replacement - the
+    /// action only ever goes unbound for the instant between the old
+    /// registration's teardown and the new one's.
+    ///
     /// # Arguments
     /// * `app` - The Tauri application handle
+    /// * `action` - The action to bind
     /// * `key` - The shortcut key combination
-    /// 
+    ///
     /// # Returns
     /// * `Ok(())` - If registration was successful
     /// * `Err(String)` - If registration fails
-    /// 
+    ///
     /// # Requirements
-    /// Validates: Requirements 7.1
-    pub fn register_toggle_shortcut<R: Runtime>(
+    /// Validates: Requirements 7.1, 7.2, 7.3
+    pub fn register_action<R: Runtime>(
         &self,
         app: &AppHandle<R>,
+        action: Action,
         key: &str,
     ) -> Result<(), String> {
         let shortcut = Self::parse_shortcut(key)?;
-        
-        // Unregister existing toggle shortcut if any
-        self.unregister_shortcut(app, "toggle")?;
 
-        let app_handle = app.clone();
+        // A conflict with this same action's current binding (rebinding to
+        // the key it already holds) isn't a real conflict - everything else
+        // is, and must be rejected before we touch the existing binding.
+        if let Some(conflict) = self.check_conflict(app, key) {
+            if conflict != action.id() {
+                return Err(format!("shortcut already bound to '{}'", conflict));
+            }
+        }
+
+        self.unregister_shortcut(app, action.id())?;
+
+        let action_id = action.id();
         app.global_shortcut()
-            .on_shortcut(shortcut.clone(), move |_app, _shortcut, event| {
-                if event.state == ShortcutState::Pressed {
-                    toggle_window_visibility(&app_handle);
+            .on_shortcut(shortcut.clone(), move |app, _shortcut, event| {
+                let is_repeat = app.state::<ShortcutManager>().note_key_event(action_id, event.state);
+                if event.state == ShortcutState::Pressed && !is_repeat {
+                    action.dispatch(app);
                 }
             })
-            .map_err(|e| format!("Failed to register toggle shortcut: {}", e))?;
+            .map_err(|e| format!("Failed to register {} shortcut: {}", action.id(), e))?;
 
         // Store the registered shortcut
         let mut shortcuts = self.registered_shortcuts.lock().unwrap();
-        shortcuts.insert("toggle".to_string(), key.to_string());
+        shortcuts.insert(action.id().to_string(), Binding::Single(key.to_string()));
 
         Ok(())
     }
 
-    /// Registers the clipboard capture shortcut.
-    /// 
-    /// Default: CommandOrControl+Alt+V
-    /// Creates a new note from clipboard content and shows a notification.
-    /// 
+    /// Checks whether `key` is already in use, either by a different action
+    /// in `registered_shortcuts` or by an OS-level registration the plugin
+    /// doesn't know the owner of (e.g. another application).
+    ///
     /// # Arguments
     /// * `app` - The Tauri application handle
-    /// * `key` - The shortcut key combination
-    /// 
+    /// * `key` - The accelerator to check
+    ///
     /// # Returns
-    /// * `Ok(())` - If registration was successful
-    /// * `Err(String)` - If registration fails
-    /// 
-    /// # Requirements
-    /// Validates: Requirements 7.2
-    pub fn register_clipboard_shortcut<R: Runtime>(
-        &self,
-        app: &AppHandle<R>,
-        key: &str,
-    ) -> Result<(), String> {
-        let shortcut = Self::parse_shortcut(key)?;
-        
-        // Unregister existing clipboard shortcut if any
-        self.unregister_shortcut(app, "clipboard")?;
-
-        let app_handle = app.clone();
-        app.global_shortcut()
-            .on_shortcut(shortcut.clone(), move |_app, _shortcut, event| {
-                if event.state == ShortcutState::Pressed {
-                    capture_clipboard_to_note(&app_handle);
-                }
+    /// `Some(name)` naming the conflicting action (or `"<external>"` for an
+    /// OS-level conflict with no known owning action) if `key` is already
+    /// in use, `None` if it's free to register.
+    pub fn check_conflict<R: Runtime>(&self, app: &AppHandle<R>, key: &str) -> Option<String> {
+        let conflicting_action = {
+            let shortcuts = self.registered_shortcuts.lock().unwrap();
+            shortcuts.iter().find_map(|(id, binding)| {
+                matches!(binding, Binding::Single(bound_key) if bound_key == key).then(|| id.clone())
             })
-            .map_err(|e| format!("Failed to register clipboard shortcut: {}", e))?;
+        };
+        if conflicting_action.is_some() {
+            return conflicting_action;
+        }
 
-        // Store the registered shortcut
-        let mut shortcuts = self.registered_shortcuts.lock().unwrap();
-        shortcuts.insert("clipboard".to_string(), key.to_string());
+        let shortcut = Self::parse_shortcut(key).ok()?;
+        if app.global_shortcut().is_registered(shortcut) {
+            return Some("<external>".to_string());
+        }
 
-        Ok(())
+        None
     }
 
-    /// Registers the quick note popup shortcut.
-    /// 
-    /// Default: CommandOrControl+Alt+N
-    /// Opens the quick note popup window.
-    /// 
+    /// Registers a multi-key chord sequence for `action`, e.g.
+    /// `"CommandOrControl+K G"` meaning "press Ctrl+K, then G within
+    /// [`SEQUENCE_TIMEOUT`]."
+    ///
+    /// The leader (first chord) is registered with the OS like any other
+    /// single shortcut; later chords are only registered with the OS while a
+    /// matching sequence is pending, and are torn down again on fire,
+    /// mismatch, or timeout.
+    ///
     /// # Arguments
     /// * `app` - The Tauri application handle
-    /// * `key` - The shortcut key combination
-    /// 
+    /// * `action` - The action to bind
+    /// * `sequence` - Space-separated chords, e.g. `"CommandOrControl+K G"`
+    ///
     /// # Returns
-    /// * `Ok(())` - If registration was successful
-    /// * `Err(String)` - If registration fails
-    /// 
-    /// # Requirements
-    /// Validates: Requirements 7.3
-    pub fn register_quick_note_shortcut<R: Runtime>(
+    /// * `Ok(())` - If every chord parsed and the leader didn't collide with
+    ///   an existing single-key shortcut
+    /// * `Err(String)` - If a chord failed to parse, the sequence has fewer
+    ///   than two chords, or the leader collides with an existing shortcut
+    pub fn register_sequence<R: Runtime>(
         &self,
         app: &AppHandle<R>,
-        key: &str,
+        action: Action,
+        sequence: &str,
     ) -> Result<(), String> {
-        let shortcut = Self::parse_shortcut(key)?;
-        
-        // Unregister existing quick note shortcut if any
-        self.unregister_shortcut(app, "quick_note")?;
+        let chords: Vec<String> = sequence.split_whitespace().map(str::to_string).collect();
+        if chords.len() < 2 {
+            return Err(format!(
+                "'{}' is not a chord sequence (expected at least two space-separated chords)",
+                sequence
+            ));
+        }
 
-        let app_handle = app.clone();
-        app.global_shortcut()
-            .on_shortcut(shortcut.clone(), move |_app, _shortcut, event| {
-                if event.state == ShortcutState::Pressed {
-                    open_quick_note_window(&app_handle);
-                }
-            })
-            .map_err(|e| format!("Failed to register quick note shortcut: {}", e))?;
+        let parsed: Vec<Shortcut> = chords
+            .iter()
+            .map(|chord| Self::parse_shortcut(chord))
+            .collect::<Result<_, _>>()?;
 
-        // Store the registered shortcut
-        let mut shortcuts = self.registered_shortcuts.lock().unwrap();
-        shortcuts.insert("quick_note".to_string(), key.to_string());
+        let leader = chords[0].clone();
+        {
+            let shortcuts = self.registered_shortcuts.lock().unwrap();
+            if shortcuts
+                .values()
+                .any(|binding| matches!(binding, Binding::Single(key) if key == &leader))
+            {
+                return Err(format!(
+                    "Leader '{}' collides with an existing single-key shortcut",
+                    leader
+                ));
+            }
+        }
+
+        let is_new_leader = !self.sequence_trie.lock().unwrap().contains_key(&leader);
+
+        self.sequence_trie
+            .lock()
+            .unwrap()
+            .entry(leader.clone())
+            .or_default()
+            .insert(&chords[1..], action);
+
+        if is_new_leader {
+            let leader_shortcut = Self::parse_shortcut(&leader)?;
+            let leader_key = leader.clone();
+            app.global_shortcut()
+                .on_shortcut(leader_shortcut, move |app, _shortcut, event| {
+                    if event.state == ShortcutState::Pressed {
+                        app.state::<ShortcutManager>().start_sequence(app, &leader_key);
+                    }
+                })
+                .map_err(|e| format!("Failed to register chord leader '{}': {}", leader, e))?;
+        }
+
+        self.registered_shortcuts
+            .lock()
+            .unwrap()
+            .insert(action.id().to_string(), Binding::Sequence { chords, parsed });
 
         Ok(())
     }
 
-    /// Unregisters a specific shortcut by name.
-    /// 
+    /// Starts matching a chord sequence from its leader, discarding any
+    /// sequence that was already pending.
+    fn start_sequence<R: Runtime>(&self, app: &AppHandle<R>, leader: &str) {
+        self.clear_pending(app);
+
+        let trie = self.sequence_trie.lock().unwrap();
+        if let Some(node) = trie.get(leader) {
+            self.enter_node(app, vec![leader.to_string()], node);
+        }
+    }
+
+    /// Advances a pending chord sequence with the next chord pressed.
+    ///
+    /// Resets to idle (no-op beyond clearing state) if nothing was pending,
+    /// the pending sequence already timed out, or `chord` isn't a valid next
+    /// step from the current position.
+    fn advance_sequence<R: Runtime>(&self, app: &AppHandle<R>, chord: &str) {
+        let pending = self.pending.lock().unwrap().take();
+        self.clear_temp_accelerators(app);
+
+        let Some(pending) = pending else { return };
+        if pending.deadline < Instant::now() {
+            return;
+        }
+
+        let trie = self.sequence_trie.lock().unwrap();
+        let Some(node) = walk_trie(&trie, &pending.path) else {
+            return;
+        };
+        let Some(next_node) = node.children.get(chord) else {
+            return;
+        };
+
+        let mut path = pending.path;
+        path.push(chord.to_string());
+        self.enter_node(app, path, next_node);
+    }
+
+    /// Enters `node` having matched `path` so far: fires its action if it's a
+    /// leaf, otherwise arms its children as temporary accelerators and arms
+    /// the sequence timeout.
+    fn enter_node<R: Runtime>(&self, app: &AppHandle<R>, path: Vec<String>, node: &SequenceNode) {
+        if node.children.is_empty() {
+            if let Some(action) = node.action {
+                action.dispatch(app);
+            }
+            return;
+        }
+
+        let next_chords: Vec<String> = node.children.keys().cloned().collect();
+        self.arm_temp_accelerators(app, &next_chords);
+
+        let deadline = Instant::now() + SEQUENCE_TIMEOUT;
+        *self.pending.lock().unwrap() = Some(PendingSequence { path, deadline });
+        self.schedule_timeout(app, deadline);
+    }
+
+    /// Registers each of `chords` as a temporary OS-level accelerator that
+    /// advances the pending sequence when pressed.
+    fn arm_temp_accelerators<R: Runtime>(&self, app: &AppHandle<R>, chords: &[String]) {
+        let mut temp = self.temp_registered.lock().unwrap();
+        for chord in chords {
+            let Ok(shortcut) = Self::parse_shortcut(chord) else {
+                continue;
+            };
+            let chord_owned = chord.clone();
+            let result = app
+                .global_shortcut()
+                .on_shortcut(shortcut.clone(), move |app, _shortcut, event| {
+                    if event.state == ShortcutState::Pressed {
+                        app.state::<ShortcutManager>().advance_sequence(app, &chord_owned);
+                    }
+                });
+            match result {
+                Ok(()) => temp.push(shortcut),
+                Err(e) => log::warn!("Failed to arm chord step '{}': {}", chord, e),
+            }
+        }
+    }
+
+    /// Unregisters all currently-armed temporary chord-step accelerators.
+    fn clear_temp_accelerators<R: Runtime>(&self, app: &AppHandle<R>) {
+        let mut temp = self.temp_registered.lock().unwrap();
+        for shortcut in temp.drain(..) {
+            let _ = app.global_shortcut().unregister(shortcut);
+        }
+    }
+
+    /// Clears any pending chord sequence and its temporary accelerators.
+    fn clear_pending<R: Runtime>(&self, app: &AppHandle<R>) {
+        *self.pending.lock().unwrap() = None;
+        self.clear_temp_accelerators(app);
+    }
+
+    /// Schedules the pending sequence to expire at `deadline`, resetting
+    /// state if nothing has superseded it by then.
+    fn schedule_timeout<R: Runtime>(&self, app: &AppHandle<R>, deadline: Instant) {
+        let app_handle = app.clone();
+        let timeout = deadline.saturating_duration_since(Instant::now());
+        tauri::async_runtime::spawn(async move {
+            tokio::time::sleep(timeout).await;
+            let state = app_handle.state::<ShortcutManager>();
+            state.expire_if_still_pending(&app_handle, deadline);
+        });
+    }
+
+    /// Resets the pending sequence if it's still the one scheduled for
+    /// `deadline` (i.e. nothing advanced or reset it in the meantime).
+    fn expire_if_still_pending<R: Runtime>(&self, app: &AppHandle<R>, deadline: Instant) {
+        let mut pending_guard = self.pending.lock().unwrap();
+        let expired = matches!(&*pending_guard, Some(p) if p.deadline == deadline);
+        if expired {
+            *pending_guard = None;
+            drop(pending_guard);
+            self.clear_temp_accelerators(app);
+        }
+    }
+
+    /// Unregisters a specific shortcut by action id.
+    ///
     /// # Arguments
     /// * `app` - The Tauri application handle
-    /// * `name` - The name of the shortcut to unregister ("toggle", "clipboard", or "quick_note")
-    /// 
+    /// * `name` - The action id to unregister (see [`Action::id`])
+    ///
     /// # Returns
     /// * `Ok(())` - If unregistration was successful or shortcut wasn't registered
     /// * `Err(String)` - If unregistration fails
@@ -209,78 +819,119 @@ impl ShortcutManager {
         app: &AppHandle<R>,
         name: &str,
     ) -> Result<(), String> {
-        let key_opt = {
+        let binding_opt = {
             let mut shortcuts = self.registered_shortcuts.lock().unwrap();
             shortcuts.remove(name)
         };
-        
-        if let Some(key) = key_opt {
+        self.key_states.lock().unwrap().remove(name);
+
+        // Sequence leaders may be shared by other actions, so tearing down a
+        // single sequence action here doesn't unregister the shared OS hotkey.
+        if let Some(Binding::Single(key)) = binding_opt {
             if let Ok(shortcut) = Self::parse_shortcut(&key) {
                 let _ = app.global_shortcut().unregister(shortcut);
             }
         }
-        
+
         Ok(())
     }
 
-    /// Unregisters all shortcuts.
-    /// 
+    /// Unregisters all shortcuts and chord sequences.
+    ///
     /// # Arguments
     /// * `app` - The Tauri application handle
     pub fn unregister_all<R: Runtime>(&self, app: &AppHandle<R>) {
         let mut shortcuts = self.registered_shortcuts.lock().unwrap();
-        
-        for key in shortcuts.values() {
-            if let Ok(shortcut) = Self::parse_shortcut(key) {
-                let _ = app.global_shortcut().unregister(shortcut);
+
+        for binding in shortcuts.values() {
+            match binding {
+                Binding::Single(key) => {
+                    if let Ok(shortcut) = Self::parse_shortcut(key) {
+                        let _ = app.global_shortcut().unregister(shortcut);
+                    }
+                }
+                Binding::Sequence { parsed, .. } => {
+                    for shortcut in parsed {
+                        let _ = app.global_shortcut().unregister(shortcut.clone());
+                    }
+                }
             }
         }
-        
+
         shortcuts.clear();
+        drop(shortcuts);
+
+        self.key_states.lock().unwrap().clear();
+        self.sequence_trie.lock().unwrap().clear();
+        self.clear_pending(app);
     }
 
-    /// Updates the toggle shortcut and re-registers it.
-    /// 
+    /// Rebinds `action` to `new_key`, re-registers it, and persists the
+    /// change to config.
+    ///
     /// # Arguments
     /// * `app` - The Tauri application handle
+    /// * `action` - The action to rebind
     /// * `new_key` - The new shortcut key combination
-    /// 
+    ///
     /// # Returns
-    /// * `Ok(())` - If update was successful
-    /// * `Err(String)` - If update fails
-    /// 
+    /// * `Ok(())` - If the rebind was successful
+    /// * `Err(String)` - If the rebind fails
+    ///
     /// # Requirements
     /// Validates: Requirements 7.4
-    pub fn update_toggle_shortcut<R: Runtime>(
+    pub fn rebind_action<R: Runtime>(
         &self,
         app: &AppHandle<R>,
+        action: Action,
         new_key: &str,
     ) -> Result<(), String> {
-        // Validate the new shortcut first
-        let _ = Self::parse_shortcut(new_key)?;
-        
-        // Unregister the old shortcut
-        self.unregister_shortcut(app, "toggle")?;
-        
-        // Register the new shortcut
-        self.register_toggle_shortcut(app, new_key)?;
-        
-        // Update config
+        self.register_action(app, action, new_key)?;
+
         let config_manager = app.state::<ConfigManager>();
-        config_manager.set_global_shortcut(new_key.to_string());
+        config_manager.update(|config| match action {
+            Action::ToggleWindow => config.global_shortcut = new_key.to_string(),
+            Action::CaptureClipboard => config.clipboard_shortcut = new_key.to_string(),
+            Action::QuickNote => config.quick_note_shortcut = new_key.to_string(),
+        });
         if let Err(e) = config_manager.save_sync() {
             log::warn!("Failed to persist shortcut configuration: {}", e);
         }
-        
+
         Ok(())
     }
 
     /// Gets the currently registered shortcuts.
-    /// 
+    ///
     /// # Returns
-    /// A HashMap of shortcut names to their key combinations
+    /// A HashMap of action ids to their key combinations (chord sequences
+    /// rendered as space-separated accelerators)
     pub fn get_registered_shortcuts(&self) -> HashMap<String, String> {
-        self.registered_shortcuts.lock().unwrap().clone()
+        self.registered_shortcuts
+            .lock()
+            .unwrap()
+            .iter()
+            .map(|(id, binding)| (id.clone(), binding.display()))
+            .collect()
+    }
+
+    /// Lists every known action with its description and currently bound key,
+    /// so a settings/help UI can render a full editable keybinding table.
+    ///
+    /// Actions with no keybinding yet (if any are added in the future without
+    /// a default) report `None` for the current key rather than being omitted.
+    pub fn list_actions(&self) -> Vec<(String, String, Option<String>)> {
+        let shortcuts = self.registered_shortcuts.lock().unwrap();
+        Action::ALL
+            .iter()
+            .map(|action| {
+                (
+                    action.id().to_string(),
+                    action.description().to_string(),
+                    shortcuts.get(action.id()).map(Binding::display),
+                )
+            })
+            .collect()
     }
 
     /// Checks if a shortcut string is valid.
@@ -303,83 +954,105 @@ impl Default for ShortcutManager {
 }
 
 /// Toggles the visibility of the main window.
-/// 
+///
 /// If the window is visible, it will be hidden.
 /// If the window is hidden, it will be shown and focused.
-/// 
+/// Delegates to `tray::toggle_window` so the tray's Show/Hide label stays
+/// in sync regardless of whether the toggle was triggered by this shortcut
+/// or by the tray icon itself.
+///
 /// # Requirements
 /// Validates: Requirements 7.1
 fn toggle_window_visibility<R: Runtime>(app: &AppHandle<R>) {
     if let Some(window) = app.get_webview_window("main") {
-        // Check visibility - default to false if we can't determine
-        let is_visible = window.is_visible().unwrap_or(false);
-        let is_minimized = window.is_minimized().unwrap_or(false);
-        
-        if is_visible && !is_minimized {
-            // Window is visible and not minimized - hide it
-            let _ = window.hide();
-        } else {
-            // Window is hidden or minimized - show it
-            // Order matters: show -> unminimize -> focus
-            let _ = window.show();
-            let _ = window.unminimize();
-            let _ = window.set_focus();
-        }
+        crate::tray::toggle_window(app, &window);
     }
 }
 
 /// Captures clipboard content and creates a new note.
-/// 
-/// Reads text from the clipboard, creates a new note with that content,
-/// and shows a notification to the user.
-/// 
+///
+/// Tries a text read first; if the clipboard has no text, falls back to an
+/// image read (e.g. a screenshot copied to the clipboard), saving the PNG
+/// into the assets directory and embedding it as a Markdown image link.
+/// Shows a notification to the user either way.
+///
 /// # Requirements
 /// Validates: Requirements 7.2
 fn capture_clipboard_to_note<R: Runtime>(app: &AppHandle<R>) {
     // Use tauri's async runtime to handle the clipboard operation
     let app_handle = app.clone();
-    
+
     tauri::async_runtime::spawn(async move {
-        // Read clipboard content
-        let clipboard_content: String = match app_handle.clipboard().read_text() {
-            Ok(text) => text,
-            Err(e) => {
-                show_notification(&app_handle, "Clipboard Error", &format!("Failed to read clipboard: {}", e));
-                return;
-            }
-        };
+        let text_content = app_handle.clipboard().read_text().ok().filter(|t| !t.trim().is_empty());
 
-        if clipboard_content.trim().is_empty() {
-            show_notification(&app_handle, "Clipboard Empty", "No text content in clipboard");
+        if let Some(clipboard_content) = text_content {
+            save_clipboard_text_note(&app_handle, &clipboard_content);
             return;
         }
 
-        // Get filesystem to create note
-        let filesystem = app_handle.state::<FileSystem>();
-        
-        // Create a new note
-        match filesystem.create_note(None) {
-            Ok((note_id, _path)) => {
-                // Format the content with a title
-                let content = format!("# Clipboard Note\n\n{}", clipboard_content);
-                
-                // Save the content
-                if let Err(e) = filesystem.save_note(&note_id, &content, None) {
-                    show_notification(&app_handle, "Error", &format!("Failed to save note: {}", e));
-                    return;
-                }
+        match app_handle.clipboard().read_image() {
+            Ok(image) => save_clipboard_image_note(&app_handle, &image),
+            Err(_) => {
+                show_notification(&app_handle, "Clipboard Empty", "No text or image content in clipboard");
+            }
+        }
+    });
+}
+
+/// Creates a new note from clipboard text content.
+fn save_clipboard_text_note<R: Runtime>(app: &AppHandle<R>, clipboard_content: &str) {
+    let filesystem = app.state::<FileSystem>();
 
-                // Show success notification
-                show_notification(&app_handle, "Note Created", "Clipboard content saved as new note");
+    match filesystem.create_note(None) {
+        Ok((note_id, _path)) => {
+            let content = format!("# Clipboard Note\n\n{}", clipboard_content);
 
-                // Emit refresh-notes event to update the UI
-                let _ = app_handle.emit("refresh-notes", note_id);
+            if let Err(e) = filesystem.save_note(&note_id, &content, None) {
+                show_notification(app, "Error", &format!("Failed to save note: {}", e));
+                return;
             }
-            Err(e) => {
-                show_notification(&app_handle, "Error", &format!("Failed to create note: {}", e));
+
+            show_notification(app, "Note Created", "Clipboard content saved as new note");
+            let _ = app.emit("refresh-notes", note_id);
+        }
+        Err(e) => {
+            show_notification(app, "Error", &format!("Failed to create note: {}", e));
+        }
+    }
+}
+
+/// Creates a new note embedding a clipboard image.
+///
+/// Encodes `image`'s RGBA buffer to PNG, saves it into the assets directory,
+/// and links it from the note with a relative Markdown image reference.
+fn save_clipboard_image_note<R: Runtime>(app: &AppHandle<R>, image: &tauri::image::Image) {
+    let filesystem = app.state::<FileSystem>();
+
+    let (image_id, _path) = match filesystem.save_clipboard_image(image.rgba(), image.width(), image.height()) {
+        Ok(result) => result,
+        Err(e) => {
+            show_notification(app, "Error", &format!("Failed to save clipboard image: {}", e));
+            return;
+        }
+    };
+
+    match filesystem.create_note(None) {
+        Ok((note_id, _path)) => {
+            let relative_path = filesystem.relative_asset_path(None, &format!("{}.png", image_id));
+            let content = format!("# Clipboard Image\n\n![clipboard]({})", relative_path);
+
+            if let Err(e) = filesystem.save_note(&note_id, &content, None) {
+                show_notification(app, "Error", &format!("Failed to save note: {}", e));
+                return;
             }
+
+            show_notification(app, "Image Saved", "Clipboard image saved as new note");
+            let _ = app.emit("refresh-notes", note_id);
         }
-    });
+        Err(e) => {
+            show_notification(app, "Error", &format!("Failed to create note: {}", e));
+        }
+    }
 }
 
 /// Opens the quick note popup window.
@@ -409,34 +1082,43 @@ fn open_quick_note_window<R: Runtime>(app: &AppHandle<R>) {
 }
 
 /// Creates the quick note window with proper configuration.
-/// 
+///
+/// Centered on the active monitor (reusing the same display-info/centering
+/// logic used to restore persisted window positions, see
+/// [`crate::window_state::center_on_monitor`]), borderless and
+/// always-on-top, and configured to hide itself as soon as it loses focus so
+/// it behaves like a transient Spotlight-style popup rather than a regular
+/// window.
+///
 /// # Requirements
 /// Validates: Requirements 8.1
 async fn create_quick_note_window<R: Runtime>(app: &AppHandle<R>) -> Result<(), String> {
     use tauri::WebviewWindowBuilder;
     use tauri::WebviewUrl;
-    
-    // Get display info to position in top-right corner
-    let (screen_width, _screen_height) = if let Some(window) = app.get_webview_window("main") {
-        if let Ok(Some(monitor)) = window.primary_monitor() {
+
+    // Quick note window dimensions
+    let window_width: u32 = 400;
+    let window_height: u32 = 200;
+
+    let monitor = app
+        .get_webview_window("main")
+        .and_then(|window| window.current_monitor().ok().flatten());
+
+    let (x, y) = match monitor {
+        Some(monitor) => {
+            let position = monitor.position();
             let size = monitor.size();
-            (size.width as i32, size.height as i32)
-        } else {
-            (1920, 1080) // Default fallback
+            let display = crate::models::DisplayInfo {
+                x: position.x,
+                y: position.y,
+                width: size.width,
+                height: size.height,
+            };
+            crate::window_state::center_on_monitor(window_width, window_height, &display)
         }
-    } else {
-        (1920, 1080) // Default fallback
+        None => (760, 440), // Default fallback, centered on a 1920x1080 screen
     };
-    
-    // Quick note window dimensions
-    let window_width = 400;
-    let window_height = 200;
-    let margin = 20;
-    
-    // Position in top-right corner
-    let x = screen_width - window_width - margin;
-    let y = margin;
-    
+
     // Create the quick note window
     let window = WebviewWindowBuilder::new(
         app,
@@ -455,10 +1137,19 @@ async fn create_quick_note_window<R: Runtime>(app: &AppHandle<R>) -> Result<(),
     .visible(true)
     .build()
     .map_err(|e| format!("Failed to create quick note window: {}", e))?;
-    
+
+    // Auto-hide on blur, same as pressing Escape or saving - the overlay is
+    // meant to be jotted in and dismissed, not left open in the background.
+    let window_clone = window.clone();
+    window.on_window_event(move |event| {
+        if let tauri::WindowEvent::Focused(false) = event {
+            let _ = window_clone.hide();
+        }
+    });
+
     // Set focus to the window
     let _ = window.set_focus();
-    
+
     Ok(())
 }
 
@@ -477,22 +1168,24 @@ fn show_notification<R: Runtime>(app: &AppHandle<R>, title: &str, body: &str) {
 }
 
 /// Validates a shortcut string and returns an error result if invalid.
-/// 
-/// This is a helper function for IPC commands.
-/// 
+///
+/// This is a helper function for IPC commands. The returned `ApiResult`
+/// carries the offending token and the full valid-value list (via
+/// [`ApiResult::from_shortcut_error`]), not just a generic message.
+///
 /// # Arguments
 /// * `key` - The shortcut string to validate
-/// 
+///
 /// # Returns
 /// * `Ok(())` - If the shortcut is valid
 /// * `Err(ApiResult)` - If the shortcut is invalid
-/// 
+///
 /// # Requirements
 /// Validates: Requirements 7.5
 pub fn validate_shortcut(key: &str) -> Result<(), ApiResult> {
-    ShortcutManager::parse_shortcut(key)
+    ShortcutManager::parse_shortcut_detailed(key)
         .map(|_| ())
-        .map_err(|e| ApiResult::error(e))
+        .map_err(ApiResult::from_shortcut_error)
 }
 
 #[cfg(test)]
@@ -507,6 +1200,100 @@ mod tests {
         assert!(shortcuts.is_empty());
     }
 
+    #[test]
+    fn test_action_ids_are_unique() {
+        let ids: Vec<&str> = Action::ALL.iter().map(Action::id).collect();
+        let mut unique = ids.clone();
+        unique.sort();
+        unique.dedup();
+        assert_eq!(ids.len(), unique.len());
+    }
+
+    #[test]
+    fn test_action_default_keys_are_valid_shortcuts() {
+        for action in Action::ALL {
+            assert!(
+                ShortcutManager::is_valid_shortcut(action.default_key()),
+                "default key for {} should be valid",
+                action.id()
+            );
+        }
+    }
+
+    #[test]
+    fn test_list_actions_reports_unregistered_before_any_registration() {
+        let manager = ShortcutManager::new();
+        let actions = manager.list_actions();
+
+        assert_eq!(actions.len(), Action::ALL.len());
+        for (_, _, current_key) in actions {
+            assert!(current_key.is_none());
+        }
+    }
+
+    #[test]
+    fn test_note_key_event_distinguishes_repeat_from_initial_press() {
+        let manager = ShortcutManager::new();
+
+        // Initial press is not a repeat.
+        assert!(!manager.note_key_event("toggle", ShortcutState::Pressed));
+        // A second Pressed event while still held is a repeat.
+        assert!(manager.note_key_event("toggle", ShortcutState::Pressed));
+        assert!(manager.note_key_event("toggle", ShortcutState::Pressed));
+
+        // Releasing resets the state, so the next press is initial again.
+        assert!(!manager.note_key_event("toggle", ShortcutState::Released));
+        assert!(!manager.note_key_event("toggle", ShortcutState::Pressed));
+    }
+
+    #[test]
+    fn test_binding_display() {
+        assert_eq!(
+            Binding::Single("Ctrl+K".to_string()).display(),
+            "Ctrl+K"
+        );
+        assert_eq!(
+            Binding::Sequence {
+                chords: vec!["Ctrl+K".to_string(), "G".to_string()],
+                parsed: vec![],
+            }
+            .display(),
+            "Ctrl+K G"
+        );
+    }
+
+    #[test]
+    fn test_sequence_node_insert_and_walk() {
+        let mut trie: HashMap<String, SequenceNode> = HashMap::new();
+        trie.entry("Ctrl+K".to_string())
+            .or_default()
+            .insert(&["G".to_string()], Action::CaptureClipboard);
+        trie.entry("Ctrl+K".to_string())
+            .or_default()
+            .insert(&["N".to_string()], Action::QuickNote);
+
+        let path = vec!["Ctrl+K".to_string(), "G".to_string()];
+        let node = walk_trie(&trie, &path).expect("path should be registered");
+        assert_eq!(node.action, Some(Action::CaptureClipboard));
+        assert!(node.children.is_empty());
+
+        let leader_node = walk_trie(&trie, &["Ctrl+K".to_string()]).expect("leader should exist");
+        assert!(leader_node.action.is_none());
+        assert_eq!(leader_node.children.len(), 2);
+    }
+
+    #[test]
+    fn test_walk_trie_missing_path_returns_none() {
+        let mut trie: HashMap<String, SequenceNode> = HashMap::new();
+        trie.entry("Ctrl+K".to_string())
+            .or_default()
+            .insert(&["G".to_string()], Action::CaptureClipboard);
+
+        assert!(walk_trie(&trie, &["Ctrl+J".to_string()]).is_none());
+        assert!(walk_trie(&trie, &["Ctrl+K".to_string(), "X".to_string()]).is_none());
+        assert!(walk_trie(&trie, &[]).is_none());
+    }
+
     #[test]
     fn test_parse_valid_shortcut() {
         // Test various valid shortcut formats
@@ -517,6 +1304,120 @@ mod tests {
         assert!(ShortcutManager::parse_shortcut("Super+A").is_ok());
     }
 
+    #[test]
+    fn test_parse_shortcut_accepts_unambiguous_prefixes() {
+        // "Ct" is a prefix of only "Ctrl" among the modifier candidates.
+        assert!(ShortcutManager::parse_shortcut("Ct+S").is_ok(), "expected 'Ct+S' to resolve to 'Ctrl+S'");
+        assert!(ShortcutManager::parse_shortcut("Sh+F1").is_ok(), "expected 'Sh+F1' to resolve to 'Shift+F1'");
+    }
+
+    #[test]
+    fn test_resolve_accelerator_matches_unambiguous_prefix() {
+        assert_eq!(ShortcutManager::resolve_accelerator("Ct+S").unwrap(), "Ctrl+S");
+        assert_eq!(ShortcutManager::resolve_accelerator("Sh+F1").unwrap(), "Shift+F1");
+    }
+
+    #[test]
+    fn test_parse_shortcut_rejects_ambiguous_prefix() {
+        // "C" prefixes Control/Ctrl/Command/Cmd/CommandOrControl/CmdOrCtrl.
+        let result = ShortcutManager::parse_shortcut("C+N");
+        assert!(result.is_err());
+        let err = result.unwrap_err();
+        assert!(err.contains("recognized"), "expected detail error, got: {}", err);
+    }
+
+    #[test]
+    fn test_parse_shortcut_detailed_reports_ambiguous_token_and_candidates() {
+        let err = ShortcutManager::parse_shortcut_detailed("C+N").unwrap_err();
+        assert_eq!(err.invalid_arg, "C+N");
+        assert_eq!(err.invalid_value, Some("C".to_string()));
+        assert!(err.valid_values.len() > 1);
+        assert!(err.valid_values.iter().any(|v| v == "Ctrl"));
+        assert!(err.valid_values.iter().any(|v| v == "Cmd"));
+    }
+
+    #[test]
+    fn test_parse_shortcut_detailed_reports_full_candidates_when_unrecognized() {
+        let err = ShortcutManager::parse_shortcut_detailed("NotAModifier+N").unwrap_err();
+        assert_eq!(err.invalid_value, Some("NotAModifier".to_string()));
+        let expected: Vec<String> = MODIFIER_NAMES.iter().map(|s| s.to_string()).collect();
+        assert_eq!(err.valid_values, expected);
+    }
+
+    #[test]
+    fn test_validate_shortcut_populates_structured_fields() {
+        let result = validate_shortcut("C+N");
+        let api_result = result.expect_err("ambiguous shortcut should fail validation");
+        assert_eq!(api_result.invalid_token, Some("C".to_string()));
+        assert!(api_result.valid_values.is_some());
+    }
+
+    #[test]
+    fn test_parse_shortcut_exact_match_wins_over_prefix() {
+        // "Alt" is an exact match but also a prefix of "AltGr" - this
+        // exercises the exact-match-first branch, which must resolve it to
+        // itself instead of reporting it as ambiguous.
+        let resolved = ShortcutManager::parse_shortcut("Alt+N");
+        assert!(resolved.is_ok());
+    }
+
+    #[test]
+    fn test_parse_shortcut_accepts_portable_modifier_aliases() {
+        assert!(ShortcutManager::parse_shortcut("CmdOrCtrl+S").is_ok());
+        assert!(ShortcutManager::parse_shortcut("Super+A").is_ok());
+        assert!(ShortcutManager::parse_shortcut("Meta+A").is_ok());
+        assert!(ShortcutManager::parse_shortcut("Option+Tab").is_ok());
+    }
+
+    #[test]
+    fn test_normalize_modifier_for_platform_resolves_cmd_or_ctrl() {
+        let normalized = ShortcutManager::normalize_modifier_for_platform("CmdOrCtrl");
+        if cfg!(target_os = "macos") {
+            assert_eq!(normalized, "Command");
+        } else {
+            assert_eq!(normalized, "Control");
+        }
+    }
+
+    #[test]
+    fn test_normalize_modifier_for_platform_resolves_option_to_alt() {
+        assert_eq!(ShortcutManager::normalize_modifier_for_platform("Option"), "Alt");
+    }
+
+    #[test]
+    fn test_normalize_modifier_for_platform_passes_through_platform_independent_modifiers() {
+        assert_eq!(ShortcutManager::normalize_modifier_for_platform("Shift"), "Shift");
+        assert_eq!(ShortcutManager::normalize_modifier_for_platform("Alt"), "Alt");
+    }
+
+    #[test]
+    fn test_display_shortcut_renders_platform_form() {
+        let display = ShortcutManager::display_shortcut("CmdOrCtrl+Shift+S");
+        if cfg!(target_os = "macos") {
+            assert_eq!(display, "⌘⇧S");
+        } else {
+            assert_eq!(display, "Ctrl+Shift+S");
+        }
+    }
+
+    #[test]
+    fn test_display_shortcut_falls_back_to_input_when_unparseable() {
+        assert_eq!(ShortcutManager::display_shortcut("NotAShortcut"), "NotAShortcut");
+    }
+
+    #[test]
+    fn test_portable_to_concrete_to_display_round_trips_without_loss() {
+        // The same portable definition must parse successfully on every
+        // platform and its display form must use this platform's glyphs,
+        // with no information lost along the way.
+        let portable = "CmdOrCtrl+Shift+S";
+        assert!(ShortcutManager::parse_shortcut(portable).is_ok());
+
+        let display = ShortcutManager::display_shortcut(portable);
+        assert!(!display.is_empty());
+        assert!(display.ends_with('S'));
+    }
+
     #[test]
     fn test_parse_invalid_shortcut() {
         // Test invalid shortcut formats
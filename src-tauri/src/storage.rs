@@ -0,0 +1,232 @@
+use std::collections::{HashMap, HashSet};
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+
+/// The primitive storage operations [`crate::filesystem::FileSystem`] needs,
+/// factored out so a backend other than the real disk can stand in for it.
+///
+/// This covers only the handful of raw operations actually used by
+/// `FileSystem` (read/write/rename/remove a file, create a directory, and
+/// check whether a path exists or is a file) — higher-level behavior like
+/// trash handling, validation, and note/folder semantics stays on
+/// `FileSystem` itself and is unaffected by which backend is plugged in.
+pub trait FileSystemLike: Send + Sync {
+    /// Reads the full contents of `path` as a UTF-8 string.
+    fn file_read_to_string(&self, path: &Path) -> std::io::Result<String>;
+    /// Writes `data` to `path`, creating or truncating it.
+    fn file_write(&self, path: &Path, data: &[u8]) -> std::io::Result<()>;
+    /// Renames (or moves) `from` to `to`.
+    fn file_rename(&self, from: &Path, to: &Path) -> std::io::Result<()>;
+    /// Removes the file at `path`.
+    fn file_remove(&self, path: &Path) -> std::io::Result<()>;
+    /// Creates `path` and every missing parent directory.
+    fn dir_create_all(&self, path: &Path) -> std::io::Result<()>;
+    /// Returns whether `path` exists, of any kind.
+    fn path_exists(&self, path: &Path) -> bool;
+    /// Returns whether `path` exists and is a regular file.
+    fn path_is_file(&self, path: &Path) -> bool;
+    /// The backend's root directory, e.g. for display in error messages.
+    fn base(&self) -> &Path;
+}
+
+/// A [`FileSystemLike`] backend that delegates straight to `std::fs`.
+///
+/// This is what `FileSystem` uses outside of tests; it reads and writes the
+/// real disk under `base`.
+pub struct RealFileSystem {
+    base: PathBuf,
+}
+
+impl RealFileSystem {
+    pub fn new(base: PathBuf) -> Self {
+        Self { base }
+    }
+}
+
+impl FileSystemLike for RealFileSystem {
+    fn file_read_to_string(&self, path: &Path) -> std::io::Result<String> {
+        std::fs::read_to_string(path)
+    }
+
+    fn file_write(&self, path: &Path, data: &[u8]) -> std::io::Result<()> {
+        std::fs::write(path, data)
+    }
+
+    fn file_rename(&self, from: &Path, to: &Path) -> std::io::Result<()> {
+        std::fs::rename(from, to)
+    }
+
+    fn file_remove(&self, path: &Path) -> std::io::Result<()> {
+        std::fs::remove_file(path)
+    }
+
+    fn dir_create_all(&self, path: &Path) -> std::io::Result<()> {
+        std::fs::create_dir_all(path)
+    }
+
+    fn path_exists(&self, path: &Path) -> bool {
+        path.exists()
+    }
+
+    fn path_is_file(&self, path: &Path) -> bool {
+        path.is_file()
+    }
+
+    fn base(&self) -> &Path {
+        &self.base
+    }
+}
+
+/// An in-memory [`FileSystemLike`] backend for tests, so note/folder logic
+/// can be exercised without touching disk.
+///
+/// Paths are tracked exactly as given (no canonicalization), which matches
+/// how `FileSystem` already builds its paths by joining onto `base_dir`.
+/// Directories are tracked separately from files so `path_exists` reports
+/// true for an empty directory, same as the real filesystem.
+pub struct MemFileSystem {
+    base: PathBuf,
+    files: Mutex<HashMap<PathBuf, Vec<u8>>>,
+    dirs: Mutex<HashSet<PathBuf>>,
+}
+
+impl MemFileSystem {
+    pub fn new(base: PathBuf) -> Self {
+        let mut dirs = HashSet::new();
+        dirs.insert(base.clone());
+        Self {
+            base,
+            files: Mutex::new(HashMap::new()),
+            dirs: Mutex::new(dirs),
+        }
+    }
+
+    fn not_found(path: &Path) -> std::io::Error {
+        std::io::Error::new(
+            std::io::ErrorKind::NotFound,
+            format!("'{}' does not exist", path.display()),
+        )
+    }
+}
+
+impl FileSystemLike for MemFileSystem {
+    fn file_read_to_string(&self, path: &Path) -> std::io::Result<String> {
+        let files = self.files.lock().unwrap();
+        match files.get(path) {
+            Some(bytes) => String::from_utf8(bytes.clone())
+                .map_err(|e| std::io::Error::new(std::io::ErrorKind::InvalidData, e)),
+            None => Err(Self::not_found(path)),
+        }
+    }
+
+    fn file_write(&self, path: &Path, data: &[u8]) -> std::io::Result<()> {
+        if let Some(parent) = path.parent() {
+            if !self.dirs.lock().unwrap().contains(parent) {
+                return Err(std::io::Error::new(
+                    std::io::ErrorKind::NotFound,
+                    format!("parent directory '{}' does not exist", parent.display()),
+                ));
+            }
+        }
+        self.files.lock().unwrap().insert(path.to_path_buf(), data.to_vec());
+        Ok(())
+    }
+
+    fn file_rename(&self, from: &Path, to: &Path) -> std::io::Result<()> {
+        let mut files = self.files.lock().unwrap();
+        let data = files.remove(from).ok_or_else(|| Self::not_found(from))?;
+        files.insert(to.to_path_buf(), data);
+        Ok(())
+    }
+
+    fn file_remove(&self, path: &Path) -> std::io::Result<()> {
+        self.files
+            .lock()
+            .unwrap()
+            .remove(path)
+            .map(|_| ())
+            .ok_or_else(|| Self::not_found(path))
+    }
+
+    fn dir_create_all(&self, path: &Path) -> std::io::Result<()> {
+        let mut dirs = self.dirs.lock().unwrap();
+        let mut current = PathBuf::new();
+        for component in path.components() {
+            current.push(component);
+            dirs.insert(current.clone());
+        }
+        Ok(())
+    }
+
+    fn path_exists(&self, path: &Path) -> bool {
+        self.files.lock().unwrap().contains_key(path) || self.dirs.lock().unwrap().contains(path)
+    }
+
+    fn path_is_file(&self, path: &Path) -> bool {
+        self.files.lock().unwrap().contains_key(path)
+    }
+
+    fn base(&self) -> &Path {
+        &self.base
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_mem_file_system_round_trips_a_write() {
+        let mem = MemFileSystem::new(PathBuf::from("/base"));
+        mem.file_write(Path::new("/base/note.md"), b"hello").unwrap();
+
+        assert_eq!(mem.file_read_to_string(Path::new("/base/note.md")).unwrap(), "hello");
+        assert!(mem.path_exists(Path::new("/base/note.md")));
+        assert!(mem.path_is_file(Path::new("/base/note.md")));
+    }
+
+    #[test]
+    fn test_mem_file_system_read_missing_file_is_not_found() {
+        let mem = MemFileSystem::new(PathBuf::from("/base"));
+        let err = mem.file_read_to_string(Path::new("/base/missing.md")).unwrap_err();
+        assert_eq!(err.kind(), std::io::ErrorKind::NotFound);
+    }
+
+    #[test]
+    fn test_mem_file_system_write_requires_existing_parent_directory() {
+        let mem = MemFileSystem::new(PathBuf::from("/base"));
+        let err = mem.file_write(Path::new("/base/folder/note.md"), b"hi").unwrap_err();
+        assert_eq!(err.kind(), std::io::ErrorKind::NotFound);
+
+        mem.dir_create_all(Path::new("/base/folder")).unwrap();
+        mem.file_write(Path::new("/base/folder/note.md"), b"hi").unwrap();
+        assert!(mem.path_exists(Path::new("/base/folder/note.md")));
+    }
+
+    #[test]
+    fn test_mem_file_system_rename_moves_the_file() {
+        let mem = MemFileSystem::new(PathBuf::from("/base"));
+        mem.file_write(Path::new("/base/a.md"), b"content").unwrap();
+
+        mem.file_rename(Path::new("/base/a.md"), Path::new("/base/b.md")).unwrap();
+
+        assert!(!mem.path_exists(Path::new("/base/a.md")));
+        assert_eq!(mem.file_read_to_string(Path::new("/base/b.md")).unwrap(), "content");
+    }
+
+    #[test]
+    fn test_mem_file_system_remove_missing_file_is_not_found() {
+        let mem = MemFileSystem::new(PathBuf::from("/base"));
+        let err = mem.file_remove(Path::new("/base/missing.md")).unwrap_err();
+        assert_eq!(err.kind(), std::io::ErrorKind::NotFound);
+    }
+
+    #[test]
+    fn test_mem_file_system_dir_create_all_makes_path_exist() {
+        let mem = MemFileSystem::new(PathBuf::from("/base"));
+        mem.dir_create_all(Path::new("/base/a/b/c")).unwrap();
+
+        assert!(mem.path_exists(Path::new("/base/a/b/c")));
+        assert!(!mem.path_is_file(Path::new("/base/a/b/c")));
+    }
+}
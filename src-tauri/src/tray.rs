@@ -1,16 +1,89 @@
 use tauri::{
-    menu::{Menu, MenuItem},
-    tray::{MouseButton, MouseButtonState, TrayIconBuilder, TrayIconEvent},
-    AppHandle, Manager, Runtime, WebviewWindow,
+    menu::{IsMenuItem, Menu, MenuItem, PredefinedMenuItem, Submenu},
+    tray::{MouseButton, MouseButtonState, TrayIcon, TrayIconBuilder, TrayIconEvent},
+    AppHandle, Emitter, Manager, Runtime, WebviewWindow,
 };
 
+use crate::config::ConfigManager;
+use crate::filesystem::{self, FileSystem};
+use crate::models::RecentFile;
+
+/// Maximum number of recent files shown in the tray submenu.
+const MAX_RECENT_MENU_ITEMS: usize = 10;
+
+/// Menu item id prefix for "Recent Files" entries; the rest of the id encodes
+/// the note's folder and note_id so `handle_menu_event` can route the click
+/// without a side lookup table.
+const RECENT_ID_PREFIX: &str = "recent:";
+
+/// Number of non-empty content lines included in the tray hover preview.
+const HOVER_PREVIEW_LINE_COUNT: usize = 3;
+
+/// Default tray tooltip, shown when no document status has been reported yet.
+const DEFAULT_TOOLTIP: &str = "MDed - Markdown Editor";
+
+/// Tray icon shown while the current document has no unsaved changes.
+const DEFAULT_ICON_BYTES: &[u8] = include_bytes!("../icons/icon.png");
+
+/// Tray icon shown while the current document has unsaved changes.
+const DIRTY_ICON_BYTES: &[u8] = include_bytes!("../icons/icon-dirty.png");
+
+/// Holds the tray icon and its dynamic menu items so they can be kept in sync
+/// with window visibility (tray click, menu click, global toggle shortcut)
+/// and with the recent files list (note opens).
+pub struct TrayMenuState<R: Runtime> {
+    tray_icon: TrayIcon<R>,
+    show_hide_item: MenuItem<R>,
+    quit_item: MenuItem<R>,
+}
+
+impl<R: Runtime> TrayMenuState<R> {
+    /// Updates the menu item label to reflect the window's current visibility.
+    pub fn set_visible(&self, visible: bool) {
+        let label = if visible { "Hide" } else { "Show" };
+        if let Err(e) = self.show_hide_item.set_text(label) {
+            log::error!("Failed to update tray menu label: {}", e);
+        }
+    }
+
+    /// Rebuilds the tray's context menu with a fresh "Recent Files" submenu.
+    fn rebuild_recent_menu(&self, app: &AppHandle<R>, recent_files: &[RecentFile]) -> Result<(), String> {
+        let menu = build_menu(app, &self.show_hide_item, &self.quit_item, recent_files)?;
+        self.tray_icon
+            .set_menu(Some(menu))
+            .map_err(|e| format!("Failed to update tray menu: {}", e))
+    }
+
+    /// Updates the tray icon and tooltip to reflect the current document's
+    /// save state, so it's visible while the window is hidden.
+    ///
+    /// `title` is the current document's display title, if any. `dirty`
+    /// selects the badged icon and appends an "unsaved" marker to the tooltip.
+    fn update_status(&self, title: Option<&str>, dirty: bool) -> Result<(), String> {
+        let tooltip = match title {
+            Some(title) if dirty => format!("{} - {} • unsaved", DEFAULT_TOOLTIP, title),
+            Some(title) => format!("{} - {}", DEFAULT_TOOLTIP, title),
+            None => DEFAULT_TOOLTIP.to_string(),
+        };
+        self.tray_icon
+            .set_tooltip(Some(tooltip))
+            .map_err(|e| format!("Failed to update tray tooltip: {}", e))?;
+
+        let icon_bytes = if dirty { DIRTY_ICON_BYTES } else { DEFAULT_ICON_BYTES };
+        let icon = tauri::image::Image::from_bytes(icon_bytes)
+            .map_err(|e| format!("Failed to load tray icon: {}", e))?;
+        self.tray_icon
+            .set_icon(Some(icon))
+            .map_err(|e| format!("Failed to update tray icon: {}", e))
+    }
+}
+
 /// Sets up the system tray with icon, tooltip, and context menu.
-/// 
+///
 /// Requirements: 4.1, 4.2, 4.3, 4.4
 pub fn setup_tray<R: Runtime>(app: &AppHandle<R>) -> Result<(), String> {
     // Load the tray icon - include_bytes! embeds the icon at compile time
-    let icon_bytes = include_bytes!("../icons/icon.png");
-    let icon = tauri::image::Image::from_bytes(icon_bytes)
+    let icon = tauri::image::Image::from_bytes(DEFAULT_ICON_BYTES)
         .map_err(|e| format!("Failed to load tray icon: {}", e))?;
 
     // Create menu items for the context menu
@@ -19,14 +92,13 @@ pub fn setup_tray<R: Runtime>(app: &AppHandle<R>) -> Result<(), String> {
     let quit_item = MenuItem::with_id(app, "quit", "Quit", true, None::<&str>)
         .map_err(|e| format!("Failed to create Quit menu item: {}", e))?;
 
-    // Build the context menu
-    let menu = Menu::with_items(app, &[&show_item, &quit_item])
-        .map_err(|e| format!("Failed to create tray menu: {}", e))?;
+    let recent_files = app.state::<ConfigManager>().get_recent_files();
+    let menu = build_menu(app, &show_item, &quit_item, &recent_files)?;
 
     // Build and configure the tray icon
-    TrayIconBuilder::new()
+    let tray_icon = TrayIconBuilder::new()
         .icon(icon)
-        .tooltip("MDed - Markdown Editor")
+        .tooltip(DEFAULT_TOOLTIP)
         .menu(&menu)
         .show_menu_on_left_click(false)
         .on_menu_event(|app, event| {
@@ -38,50 +110,256 @@ pub fn setup_tray<R: Runtime>(app: &AppHandle<R>) -> Result<(), String> {
         .build(app)
         .map_err(|e| format!("Failed to build tray icon: {}", e))?;
 
+    app.manage(TrayMenuState {
+        tray_icon,
+        show_hide_item: show_item,
+        quit_item,
+    });
+
     Ok(())
 }
 
+/// Builds the tray context menu: Show/Hide, a "Recent Files" submenu and a
+/// separator (both omitted when there are no recent files), and Quit.
+fn build_menu<R: Runtime>(
+    app: &AppHandle<R>,
+    show_hide_item: &MenuItem<R>,
+    quit_item: &MenuItem<R>,
+    recent_files: &[RecentFile],
+) -> Result<Menu<R>, String> {
+    if recent_files.is_empty() {
+        return Menu::with_items(app, &[show_hide_item, quit_item])
+            .map_err(|e| format!("Failed to create tray menu: {}", e));
+    }
+
+    let mut recent_items = Vec::with_capacity(recent_files.len().min(MAX_RECENT_MENU_ITEMS));
+    for file in recent_files.iter().take(MAX_RECENT_MENU_ITEMS) {
+        let item = MenuItem::with_id(app, encode_recent_id(file), &file.title, true, None::<&str>)
+            .map_err(|e| format!("Failed to create recent file menu item: {}", e))?;
+        recent_items.push(item);
+    }
+    let recent_item_refs: Vec<&dyn IsMenuItem<R>> = recent_items
+        .iter()
+        .map(|item| item as &dyn IsMenuItem<R>)
+        .collect();
+    let recent_submenu = Submenu::with_items(app, "Recent Files", true, &recent_item_refs)
+        .map_err(|e| format!("Failed to create recent files submenu: {}", e))?;
+
+    let separator = PredefinedMenuItem::separator(app)
+        .map_err(|e| format!("Failed to create menu separator: {}", e))?;
+
+    Menu::with_items(app, &[show_hide_item, &recent_submenu, &separator, quit_item])
+        .map_err(|e| format!("Failed to create tray menu: {}", e))
+}
+
+/// Encodes a recent file's folder and note_id into a menu item id.
+fn encode_recent_id(file: &RecentFile) -> String {
+    format!("{}{}::{}", RECENT_ID_PREFIX, file.folder.clone().unwrap_or_default(), file.note_id)
+}
+
+/// Decodes a menu item id produced by `encode_recent_id` back into
+/// `(folder, note_id)`. Returns `None` for ids that aren't recent-file ids.
+fn decode_recent_id(id: &str) -> Option<(Option<String>, String)> {
+    let rest = id.strip_prefix(RECENT_ID_PREFIX)?;
+    let (folder, note_id) = rest.split_once("::")?;
+    let folder = if folder.is_empty() { None } else { Some(folder.to_string()) };
+    Some((folder, note_id.to_string()))
+}
+
+/// Refreshes the tray's "Recent Files" submenu from the current config.
+///
+/// Called whenever the recent files list changes (e.g. a note is opened).
+pub fn refresh_recent_menu<R: Runtime>(app: &AppHandle<R>) {
+    let Some(tray_state) = app.try_state::<TrayMenuState<R>>() else {
+        return;
+    };
+    let recent_files = app.state::<ConfigManager>().get_recent_files();
+    if let Err(e) = tray_state.rebuild_recent_menu(app, &recent_files) {
+        log::error!("Failed to refresh recent files menu: {}", e);
+    }
+}
+
+
+/// Updates the tray icon and tooltip to reflect the document's save state.
+///
+/// No-op (returning `Ok(())`) if the tray hasn't been set up yet.
+///
+/// # Requirements
+/// Validates: Requirements 4.1
+pub fn update_tray_status<R: Runtime>(app: &AppHandle<R>, title: Option<&str>, dirty: bool) -> Result<(), String> {
+    let Some(tray_state) = app.try_state::<TrayMenuState<R>>() else {
+        return Ok(());
+    };
+    tray_state.update_status(title, dirty)
+}
 
 /// Handles tray icon click events.
-/// 
+///
 /// Requirements: 4.2 - Toggle main window visibility on click
 fn handle_tray_event<R: Runtime>(app: &AppHandle<R>, event: TrayIconEvent) {
     match event {
         TrayIconEvent::Click {
             button: MouseButton::Left,
             button_state: MouseButtonState::Up,
+            rect,
             ..
         } => {
             // Toggle main window visibility on left click
             if let Some(window) = app.get_webview_window("main") {
-                match window.is_visible() {
-                    Ok(true) => {
-                         if let Err(e) = window.hide() {
-                             log::error!("Failed to hide window: {}", e);
-                         }
-                    }
-                    Ok(false) => {
-                        show_and_focus_window(&window);
-                    }
-                    Err(e) => {
-                        log::error!("Failed to check window visibility: {}", e);
-                    }
+                let about_to_show =
+                    !window.is_visible().unwrap_or(false) || window.is_minimized().unwrap_or(false);
+                if about_to_show {
+                    position_window_near_tray(app, &window, rect);
                 }
+                toggle_window(app, &window);
             }
         }
+        TrayIconEvent::Enter { position, .. } | TrayIconEvent::Move { position, .. } => {
+            emit_hover_preview(app, position);
+        }
+        TrayIconEvent::Leave { .. } => {
+            let _ = app.emit("tray-hover-leave", ());
+        }
         _ => {}
     }
 }
 
+/// Emits a `tray-hover-preview` event with the current document's title and a
+/// short content snippet, plus the cursor position, so the frontend can show
+/// a small at-a-glance preview without opening the full editor.
+///
+/// No-op if no note has been opened yet, or if it can no longer be read.
+/// Unsaved-edit status isn't tracked here - the frontend owns editor dirty
+/// state and can merge it into the preview itself.
+fn emit_hover_preview<R: Runtime>(app: &AppHandle<R>, position: tauri::PhysicalPosition<f64>) {
+    let last_note = app.state::<ConfigManager>().get_last_note();
+    let Some(note_id) = last_note.note_id.clone() else {
+        return;
+    };
+
+    let Ok(content) = app
+        .state::<FileSystem>()
+        .read_note(&note_id, last_note.folder.as_deref())
+    else {
+        return;
+    };
+
+    let title = filesystem::title_from_content(&content).unwrap_or_else(|| note_id.clone());
+    let preview = hover_preview_snippet(&content);
+
+    let _ = app.emit(
+        "tray-hover-preview",
+        serde_json::json!({
+            "noteId": note_id,
+            "folder": last_note.folder,
+            "title": title,
+            "preview": preview,
+            "x": position.x,
+            "y": position.y,
+        }),
+    );
+}
+
+/// Extracts the first few non-empty, non-title lines of `content` for the
+/// tray hover preview.
+fn hover_preview_snippet(content: &str) -> String {
+    content
+        .lines()
+        .skip(1)
+        .map(str::trim)
+        .filter(|line| !line.is_empty())
+        .take(HOVER_PREVIEW_LINE_COUNT)
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+/// Repositions `window` directly under the tray icon, if menubar mode is enabled.
+///
+/// No-op if menubar mode is off, or if the window's monitor or size can't be
+/// determined. Errors from the platform positioning call are logged rather
+/// than propagated, matching the rest of this file's event-handler style.
+fn position_window_near_tray<R: Runtime>(app: &AppHandle<R>, window: &WebviewWindow<R>, icon_rect: tauri::Rect) {
+    if !app.state::<ConfigManager>().get().menubar_mode {
+        return;
+    }
+
+    let Ok(scale_factor) = window.scale_factor() else {
+        return;
+    };
+    let Ok(Some(monitor)) = window.primary_monitor() else {
+        return;
+    };
+    let Ok(window_size) = window.outer_size() else {
+        return;
+    };
+
+    let icon_pos = icon_rect.position.to_physical::<i32>(scale_factor);
+    let icon_size = icon_rect.size.to_physical::<u32>(scale_factor);
+    let monitor_pos = monitor.position();
+    let monitor_size = monitor.size();
+
+    let (x, y) = compute_menubar_position(
+        icon_pos.x,
+        icon_pos.y,
+        icon_size.width,
+        icon_size.height,
+        window_size.width,
+        window_size.height,
+        monitor_pos.x,
+        monitor_pos.y,
+        monitor_size.width,
+        monitor_size.height,
+    );
+
+    if let Err(e) = window.set_position(tauri::Position::Physical(tauri::PhysicalPosition { x, y })) {
+        log::error!("Failed to position window near tray icon: {}", e);
+    }
+}
+
+/// Computes the top-left position for a menubar-style window anchored below
+/// the tray icon, clamped so the window stays fully within the monitor bounds.
+///
+/// All coordinates are physical pixels. Centers the window horizontally under
+/// the icon and places it just below, then clamps to the monitor's bounds so
+/// the window never ends up partially (or fully) off-screen.
+fn compute_menubar_position(
+    icon_x: i32,
+    icon_y: i32,
+    icon_width: u32,
+    icon_height: u32,
+    window_width: u32,
+    window_height: u32,
+    monitor_x: i32,
+    monitor_y: i32,
+    monitor_width: u32,
+    monitor_height: u32,
+) -> (i32, i32) {
+    let centered_x = icon_x + (icon_width as i32 / 2) - (window_width as i32 / 2);
+    let below_y = icon_y + icon_height as i32;
+
+    let max_x = (monitor_x + monitor_width as i32 - window_width as i32).max(monitor_x);
+    let max_y = (monitor_y + monitor_height as i32 - window_height as i32).max(monitor_y);
+
+    let x = centered_x.clamp(monitor_x, max_x);
+    let y = below_y.clamp(monitor_y, max_y);
+
+    (x, y)
+}
+
 /// Handles context menu item clicks.
-/// 
-/// Requirements: 4.3, 4.4 - Show and Quit menu items
+///
+/// Requirements: 4.3, 4.4 - Show/Hide and Quit menu items
 fn handle_menu_event<R: Runtime>(app: &AppHandle<R>, menu_id: &str) {
+    if let Some((folder, note_id)) = decode_recent_id(menu_id) {
+        open_recent_file(app, note_id, folder);
+        return;
+    }
+
     match menu_id {
         "show" => {
-            // Show and focus the main window
+            // Mirrors the tray-click toggle so the label and behavior stay in sync
             if let Some(window) = app.get_webview_window("main") {
-                show_and_focus_window(&window);
+                toggle_window(app, &window);
             }
         }
         "quit" => {
@@ -92,6 +370,50 @@ fn handle_menu_event<R: Runtime>(app: &AppHandle<R>, menu_id: &str) {
     }
 }
 
+/// Opens a note from the "Recent Files" submenu.
+///
+/// Shows and focuses the main window if it's hidden, then emits
+/// `open-recent-note` so the editor loads the selected note.
+fn open_recent_file<R: Runtime>(app: &AppHandle<R>, note_id: String, folder: Option<String>) {
+    if let Some(window) = app.get_webview_window("main") {
+        if window.is_visible().unwrap_or(false) {
+            let _ = window.set_focus();
+        } else {
+            toggle_window(app, &window);
+        }
+    }
+
+    let _ = app.emit("open-recent-note", serde_json::json!({ "noteId": note_id, "folder": folder }));
+}
+
+/// Toggles the main window's visibility and updates the tray menu label to match.
+///
+/// Shared by the tray click handler, the "Show"/"Hide" menu item, and the
+/// global toggle shortcut so all three stay consistent.
+///
+/// Requirements: 4.2, 4.3
+pub fn toggle_window<R: Runtime>(app: &AppHandle<R>, window: &WebviewWindow<R>) {
+    let is_visible = window.is_visible().unwrap_or(false);
+    let is_minimized = window.is_minimized().unwrap_or(false);
+
+    if is_visible && !is_minimized {
+        if let Err(e) = window.hide() {
+            log::error!("Failed to hide window: {}", e);
+        }
+        update_menu_label(app, false);
+    } else {
+        show_and_focus_window(window);
+        update_menu_label(app, true);
+    }
+}
+
+/// Updates the tray menu's Show/Hide label, if the tray has been set up.
+fn update_menu_label<R: Runtime>(app: &AppHandle<R>, visible: bool) {
+    if let Some(state) = app.try_state::<TrayMenuState<R>>() {
+        state.set_visible(visible);
+    }
+}
+
 /// Helper function to show, focus and unminimize a window with error logging
 fn show_and_focus_window<R: Runtime>(window: &WebviewWindow<R>) {
     if let Err(e) = window.show() {
@@ -104,3 +426,94 @@ fn show_and_focus_window<R: Runtime>(window: &WebviewWindow<R>) {
         log::error!("Failed to unminimize window: {}", e);
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use proptest::prelude::*;
+
+    #[test]
+    fn test_hover_preview_snippet_skips_title_and_blank_lines() {
+        let content = "# My Title\n\nFirst line\n\nSecond line\nThird line\nFourth line";
+        assert_eq!(
+            hover_preview_snippet(content),
+            "First line\nSecond line\nThird line"
+        );
+    }
+
+    #[test]
+    fn test_hover_preview_snippet_empty_body() {
+        let content = "# My Title\n\n";
+        assert_eq!(hover_preview_snippet(content), "");
+    }
+
+    #[test]
+    fn test_compute_menubar_position_centers_below_icon() {
+        let (x, y) = compute_menubar_position(1000, 20, 24, 24, 400, 600, 0, 0, 1920, 1080);
+        assert_eq!(x, 1000 + 12 - 200);
+        assert_eq!(y, 44);
+    }
+
+    #[test]
+    fn test_compute_menubar_position_clamps_to_monitor_right_edge() {
+        let (x, _y) = compute_menubar_position(1900, 20, 24, 24, 400, 600, 0, 0, 1920, 1080);
+        assert_eq!(x, 1920 - 400);
+    }
+
+    #[test]
+    fn test_compute_menubar_position_clamps_to_monitor_left_edge() {
+        let (x, _y) = compute_menubar_position(0, 20, 24, 24, 400, 600, 0, 0, 1920, 1080);
+        assert_eq!(x, 0);
+    }
+
+    #[test]
+    fn test_compute_menubar_position_respects_monitor_origin() {
+        let (x, y) = compute_menubar_position(2100, 20, 24, 24, 400, 600, 1920, 0, 1920, 1080);
+        // Centered under the icon would be 1912, left of this monitor's origin.
+        assert_eq!(x, 1920);
+        assert!(x >= 1920);
+        assert_eq!(y, 44);
+    }
+
+    fn monitor_strategy() -> impl Strategy<Value = (i32, i32, u32, u32)> {
+        (
+            -2000i32..=2000i32,
+            -2000i32..=2000i32,
+            400u32..=4000u32,
+            300u32..=3000u32,
+        )
+    }
+
+    proptest! {
+        #![proptest_config(ProptestConfig::with_cases(100))]
+
+        /// For any icon position and monitor bounds, the computed window
+        /// position always keeps the window fully within the monitor.
+        #[test]
+        fn prop_menubar_position_stays_within_monitor(
+            icon_x in -2000i32..=4000i32,
+            icon_y in -2000i32..=4000i32,
+            icon_width in 8u32..=64u32,
+            icon_height in 8u32..=64u32,
+            window_width in 200u32..=1200u32,
+            window_height in 200u32..=900u32,
+            (monitor_x, monitor_y, monitor_width, monitor_height) in monitor_strategy(),
+        ) {
+            let (x, y) = compute_menubar_position(
+                icon_x, icon_y, icon_width, icon_height,
+                window_width, window_height,
+                monitor_x, monitor_y, monitor_width, monitor_height,
+            );
+
+            prop_assert!(x >= monitor_x);
+            prop_assert!(y >= monitor_y);
+            // Only guaranteed to fit if the window isn't larger than the monitor itself.
+            if window_width <= monitor_width {
+                prop_assert!(x + window_width as i32 <= monitor_x + monitor_width as i32);
+            }
+            if window_height <= monitor_height {
+                prop_assert!(y + window_height as i32 <= monitor_y + monitor_height as i32);
+            }
+        }
+    }
+}
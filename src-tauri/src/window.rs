@@ -1,16 +1,30 @@
+use std::collections::HashMap;
 use std::sync::atomic::{AtomicBool, Ordering};
 use std::sync::Mutex;
 
-use crate::models::WindowBounds;
+use tauri::{Color, Runtime, WebviewWindow};
+
+use crate::models::{DisplayInfo, WindowBounds};
+use crate::window_state::{center_on_monitor, WindowState};
 
 /// Minimum window width in pixels
 pub const MIN_WINDOW_WIDTH: u32 = 300;
 /// Minimum window height in pixels
 pub const MIN_WINDOW_HEIGHT: u32 = 200;
+/// Maximum window width in pixels
+pub const MAX_WINDOW_WIDTH: u32 = 7680;
+/// Maximum window height in pixels
+pub const MAX_WINDOW_HEIGHT: u32 = 4320;
 /// Minimum opacity value (30%)
 pub const MIN_OPACITY: f64 = 0.3;
 /// Maximum opacity value (100%)
 pub const MAX_OPACITY: f64 = 1.0;
+/// Minimum fraction of a window's area that must be visible on some display
+/// before [`WindowManager::ensure_visible`] leaves it alone.
+const MIN_VISIBLE_AREA_FRACTION: f64 = 0.25;
+/// Below this remaining distance, [`WindowManager::step_opacity`] snaps
+/// straight to the target instead of asymptotically crawling toward it.
+const OPACITY_STEP_EPSILON: f64 = 0.001;
 
 /// WindowManager handles window state and operations.
 /// 
@@ -24,6 +38,31 @@ pub struct WindowManager {
     normal_bounds: Mutex<Option<WindowBounds>>,
     /// Whether the window is currently in minimal mode
     is_minimal_mode: AtomicBool,
+    /// Last known non-maximized window state per window label, so
+    /// un-maximizing restores sane dimensions instead of the maximized
+    /// bounds. See [`crate::window_state::WindowState::update_from_live_snapshot`].
+    window_states: Mutex<HashMap<String, WindowState>>,
+    /// The visible-on-all-workspaces value in effect just before minimal
+    /// mode force-enabled it, so exiting minimal mode can restore it.
+    previous_all_workspaces: Mutex<Option<bool>>,
+    /// Detached note windows currently open, keyed by window label, so the
+    /// backend can target events at a specific window instead of
+    /// broadcasting to all of them. The value is the note identity
+    /// (note id, folder) that window is displaying.
+    note_windows: Mutex<HashMap<String, (String, Option<String>)>>,
+    /// The opacity currently applied to the live main window, tracked
+    /// separately from `ConfigManager`'s persisted value so a fade in
+    /// progress has a starting point to step from.
+    live_opacity: Mutex<f64>,
+    /// The (min_width, min_height, max_width, max_height) pair `clamp_bounds`
+    /// enforces, overridable via [`WindowManager::set_size_constraints`].
+    size_constraints: Mutex<(u32, u32, u32, u32)>,
+    /// The opacity to apply while out of minimal mode, mirroring
+    /// `Config::normal_opacity`. See [`WindowManager::target_opacity`].
+    normal_opacity: Mutex<f64>,
+    /// The opacity to apply while in minimal mode, mirroring
+    /// `Config::minimal_opacity`. See [`WindowManager::target_opacity`].
+    minimal_opacity: Mutex<f64>,
 }
 
 impl WindowManager {
@@ -32,9 +71,146 @@ impl WindowManager {
         Self {
             normal_bounds: Mutex::new(None),
             is_minimal_mode: AtomicBool::new(false),
+            window_states: Mutex::new(HashMap::new()),
+            previous_all_workspaces: Mutex::new(None),
+            note_windows: Mutex::new(HashMap::new()),
+            live_opacity: Mutex::new(MAX_OPACITY),
+            size_constraints: Mutex::new((MIN_WINDOW_WIDTH, MIN_WINDOW_HEIGHT, MAX_WINDOW_WIDTH, MAX_WINDOW_HEIGHT)),
+            normal_opacity: Mutex::new(MAX_OPACITY),
+            minimal_opacity: Mutex::new(MAX_OPACITY),
         }
     }
 
+    /// Overrides the min/max window dimensions `clamp_bounds` enforces,
+    /// mirroring tao's split min/max size constraints.
+    ///
+    /// # Arguments
+    /// * `min` - The `(width, height)` floor
+    /// * `max` - The `(width, height)` ceiling
+    pub fn set_size_constraints(&self, min: (u32, u32), max: (u32, u32)) {
+        *self.size_constraints.lock().unwrap() = (min.0, min.1, max.0, max.1);
+    }
+
+    /// Returns the opacity last applied to the live main window.
+    pub fn get_live_opacity(&self) -> f64 {
+        *self.live_opacity.lock().unwrap()
+    }
+
+    /// Records `opacity` as the value last applied to the live main window.
+    pub fn set_live_opacity(&self, opacity: f64) {
+        *self.live_opacity.lock().unwrap() = opacity;
+    }
+
+    /// Returns the configured opacity for normal (non-minimal) mode.
+    pub fn get_normal_opacity(&self) -> f64 {
+        *self.normal_opacity.lock().unwrap()
+    }
+
+    /// Sets the configured opacity for normal (non-minimal) mode.
+    pub fn set_normal_opacity(&self, opacity: f64) {
+        *self.normal_opacity.lock().unwrap() = Self::clamp_opacity(opacity);
+    }
+
+    /// Returns the configured opacity for minimal mode.
+    pub fn get_minimal_opacity(&self) -> f64 {
+        *self.minimal_opacity.lock().unwrap()
+    }
+
+    /// Sets the configured opacity for minimal mode.
+    pub fn set_minimal_opacity(&self, opacity: f64) {
+        *self.minimal_opacity.lock().unwrap() = Self::clamp_opacity(opacity);
+    }
+
+    /// Returns the opacity that should be in effect right now: the minimal
+    /// mode opacity if [`WindowManager::is_in_minimal_mode`], otherwise the
+    /// normal mode opacity.
+    pub fn target_opacity(&self) -> f64 {
+        if self.is_in_minimal_mode() {
+            self.get_minimal_opacity()
+        } else {
+            self.get_normal_opacity()
+        }
+    }
+
+    /// Linearly moves `current` toward `target` by `rate_per_sec * dt_ms /
+    /// 1000`, never overshooting, and snapping exactly to `target` once the
+    /// remaining distance drops below [`OPACITY_STEP_EPSILON`]. Both
+    /// `current` and `target` are clamped via
+    /// [`WindowManager::clamp_opacity`] first, so the result always stays
+    /// within `[MIN_OPACITY, MAX_OPACITY]`.
+    pub fn step_opacity(current: f64, target: f64, dt_ms: u32, rate_per_sec: f64) -> f64 {
+        let current = Self::clamp_opacity(current);
+        let target = Self::clamp_opacity(target);
+
+        let delta = target - current;
+        if delta.abs() < OPACITY_STEP_EPSILON {
+            return target;
+        }
+
+        let max_step = rate_per_sec * (dt_ms as f64 / 1000.0);
+        let step = delta.signum() * max_step.min(delta.abs());
+        Self::clamp_opacity(current + step)
+    }
+
+    /// Records that the window labeled `label` is displaying `note_id`
+    /// (in `folder`, if any), so it can later be found by
+    /// [`WindowManager::find_note_window`] or enumerated by
+    /// [`WindowManager::list_note_windows`].
+    pub fn track_note_window(&self, label: impl Into<String>, note_id: impl Into<String>, folder: Option<String>) {
+        self.note_windows.lock().unwrap().insert(label.into(), (note_id.into(), folder));
+    }
+
+    /// Stops tracking the window labeled `label`, returning the note
+    /// identity it was displaying, if it was tracked.
+    pub fn untrack_note_window(&self, label: &str) -> Option<(String, Option<String>)> {
+        self.note_windows.lock().unwrap().remove(label)
+    }
+
+    /// Finds the label of the window currently displaying `note_id` in
+    /// `folder`, if one is open.
+    pub fn find_note_window(&self, note_id: &str, folder: Option<&str>) -> Option<String> {
+        self.note_windows
+            .lock()
+            .unwrap()
+            .iter()
+            .find(|(_, (id, f))| id == note_id && f.as_deref() == folder)
+            .map(|(label, _)| label.clone())
+    }
+
+    /// Lists every currently tracked detached note window as
+    /// `(label, note_id, folder)`.
+    pub fn list_note_windows(&self) -> Vec<(String, String, Option<String>)> {
+        self.note_windows
+            .lock()
+            .unwrap()
+            .iter()
+            .map(|(label, (note_id, folder))| (label.clone(), note_id.clone(), folder.clone()))
+            .collect()
+    }
+
+    /// Returns the last snapshot recorded for the window labeled `label`,
+    /// if any, for use as `previous` in [`WindowState::update_from_live_snapshot`].
+    pub fn get_window_state(&self, label: &str) -> Option<WindowState> {
+        self.window_states.lock().unwrap().get(label).cloned()
+    }
+
+    /// Records `state` as the latest snapshot for the window labeled `label`.
+    pub fn set_window_state(&self, label: &str, state: WindowState) {
+        self.window_states.lock().unwrap().insert(label.to_string(), state);
+    }
+
+    /// Remembers `value` as the visible-on-all-workspaces setting in effect
+    /// before minimal mode force-enabled it.
+    pub fn save_previous_all_workspaces(&self, value: bool) {
+        *self.previous_all_workspaces.lock().unwrap() = Some(value);
+    }
+
+    /// Takes (and clears) the remembered pre-minimal-mode
+    /// visible-on-all-workspaces value, if any.
+    pub fn take_previous_all_workspaces(&self) -> Option<bool> {
+        self.previous_all_workspaces.lock().unwrap().take()
+    }
+
     /// Returns whether the window is currently in minimal mode.
     pub fn is_in_minimal_mode(&self) -> bool {
         self.is_minimal_mode.load(Ordering::SeqCst)
@@ -84,22 +260,65 @@ impl WindowManager {
         opacity.clamp(MIN_OPACITY, MAX_OPACITY)
     }
 
-    /// Validates and clamps window bounds to minimum dimensions.
-    /// 
+    /// Validates and clamps window bounds to the configured min/max
+    /// dimensions (see [`WindowManager::set_size_constraints`]; defaults to
+    /// [`MIN_WINDOW_WIDTH`]/[`MIN_WINDOW_HEIGHT`]/[`MAX_WINDOW_WIDTH`]/[`MAX_WINDOW_HEIGHT`]).
+    ///
     /// # Arguments
     /// * `bounds` - The bounds to validate
-    /// 
+    ///
     /// # Returns
-    /// Bounds with width/height clamped to minimums
-    /// 
+    /// Bounds with width/height clamped to the configured range
+    ///
     /// # Requirements
     /// Validates: Requirements 2.2
-    pub fn clamp_bounds(bounds: WindowBounds) -> WindowBounds {
+    pub fn clamp_bounds(&self, bounds: WindowBounds) -> WindowBounds {
+        let (min_width, min_height, max_width, max_height) = *self.size_constraints.lock().unwrap();
+        WindowBounds {
+            width: bounds.width.clamp(min_width, max_width),
+            height: bounds.height.clamp(min_height, max_height),
+            ..bounds
+        }
+    }
+
+    /// Ensures `bounds` lands somewhere visible across `displays`, so a
+    /// window saved on a monitor that's since been disconnected (or had its
+    /// resolution change) doesn't restore off-screen. Width/height are
+    /// clamped via [`WindowManager::clamp_bounds`] first; if less than
+    /// [`MIN_VISIBLE_AREA_FRACTION`] of the resulting rect overlaps any
+    /// display, it's recentered on whichever display it overlaps most (or
+    /// the first display, if it overlaps none), with the centered position
+    /// clamped so the full rect fits inside that display.
+    pub fn ensure_visible(&self, bounds: WindowBounds, displays: &[DisplayInfo]) -> WindowBounds {
+        let bounds = self.clamp_bounds(bounds);
+
+        let (x, y) = match (bounds.x, bounds.y) {
+            (Some(x), Some(y)) => (x, y),
+            _ => return bounds,
+        };
+
+        let best_match = displays
+            .iter()
+            .map(|display| (display, intersection_area(x, y, bounds.width, bounds.height, display)))
+            .max_by_key(|(_, area)| *area);
+
+        let window_area = bounds.width as i64 * bounds.height as i64;
+        let threshold = (window_area as f64 * MIN_VISIBLE_AREA_FRACTION) as i64;
+        let sufficiently_visible = matches!(best_match, Some((_, area)) if area >= threshold);
+        if sufficiently_visible {
+            return bounds;
+        }
+
+        let target = best_match.map(|(display, _)| display).or_else(|| displays.first());
+        let Some(display) = target else {
+            return bounds;
+        };
+
+        let (cx, cy) = center_on_monitor(bounds.width, bounds.height, display);
         WindowBounds {
-            width: bounds.width.max(MIN_WINDOW_WIDTH),
-            height: bounds.height.max(MIN_WINDOW_HEIGHT),
-            x: bounds.x,
-            y: bounds.y,
+            x: Some(clamp_to_display_axis(cx, bounds.width, display.x, display.width)),
+            y: Some(clamp_to_display_axis(cy, bounds.height, display.y, display.height)),
+            ..bounds
         }
     }
 }
@@ -110,6 +329,44 @@ impl Default for WindowManager {
     }
 }
 
+/// Applies `opacity` to the live window by blending it into the webview's
+/// background alpha channel - the window must be created with
+/// `transparent: true` for this to have any visible effect. `opacity` is
+/// not re-clamped here; callers should clamp via
+/// [`WindowManager::clamp_opacity`] first.
+pub fn apply_window_opacity<R: Runtime>(window: &WebviewWindow<R>, opacity: f64) -> Result<(), String> {
+    let alpha = (opacity * 255.0).round() as u8;
+    window
+        .set_background_color(Some(Color(0, 0, 0, alpha)))
+        .map_err(|e| format!("Failed to apply window opacity: {}", e))
+}
+
+/// Area, in pixels, where the `width`x`height` rect at `(x, y)` overlaps
+/// `display`. Zero if they don't overlap at all.
+fn intersection_area(x: i32, y: i32, width: u32, height: u32, display: &DisplayInfo) -> i64 {
+    let left = x.max(display.x);
+    let right = (x + width as i32).min(display.x + display.width as i32);
+    let top = y.max(display.y);
+    let bottom = (y + height as i32).min(display.y + display.height as i32);
+
+    if right <= left || bottom <= top {
+        return 0;
+    }
+    (right - left) as i64 * (bottom - top) as i64
+}
+
+/// Clamps `pos` so a `window_len`-sized span starting there fits within
+/// `display_pos..display_pos+display_len`. Falls back to `display_pos` if
+/// the span is larger than the display itself.
+fn clamp_to_display_axis(pos: i32, window_len: u32, display_pos: i32, display_len: u32) -> i32 {
+    let max_pos = display_pos + display_len as i32 - window_len as i32;
+    if max_pos < display_pos {
+        display_pos
+    } else {
+        pos.clamp(display_pos, max_pos)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -123,7 +380,7 @@ mod tests {
             prop_oneof![Just(None), (-2000i32..=2000i32).prop_map(Some)],
             prop_oneof![Just(None), (-2000i32..=2000i32).prop_map(Some)],
         )
-            .prop_map(|(width, height, x, y)| WindowBounds { width, height, x, y })
+            .prop_map(|(width, height, x, y)| WindowBounds { width, height, x, y, ..Default::default() })
     }
 
     // Strategy for generating any window bounds (including invalid ones)
@@ -134,7 +391,7 @@ mod tests {
             prop_oneof![Just(None), (-5000i32..=5000i32).prop_map(Some)],
             prop_oneof![Just(None), (-5000i32..=5000i32).prop_map(Some)],
         )
-            .prop_map(|(width, height, x, y)| WindowBounds { width, height, x, y })
+            .prop_map(|(width, height, x, y)| WindowBounds { width, height, x, y, ..Default::default() })
     }
 
     // Strategy for generating any opacity value
@@ -171,8 +428,9 @@ mod tests {
             height: 600,
             x: Some(100),
             y: Some(200),
+            ..Default::default()
         };
-        
+
         wm.save_normal_bounds(bounds.clone());
         
         let retrieved = wm.get_normal_bounds();
@@ -193,8 +451,9 @@ mod tests {
             height: 600,
             x: None,
             y: None,
+            ..Default::default()
         };
-        
+
         wm.save_normal_bounds(bounds);
         assert!(wm.get_normal_bounds().is_some());
         
@@ -202,6 +461,101 @@ mod tests {
         assert!(wm.get_normal_bounds().is_none());
     }
 
+    #[test]
+    fn test_window_state_tracking() {
+        let wm = WindowManager::new();
+        assert!(wm.get_window_state("main").is_none());
+
+        let state = WindowState { x: Some(10), y: Some(20), width: Some(800), height: Some(600), ..Default::default() };
+        wm.set_window_state("main", state.clone());
+        assert_eq!(wm.get_window_state("main"), Some(state));
+
+        // A different label is tracked independently.
+        assert!(wm.get_window_state("quick-note").is_none());
+    }
+
+    #[test]
+    fn test_previous_all_workspaces_save_and_take() {
+        let wm = WindowManager::new();
+        assert!(wm.take_previous_all_workspaces().is_none());
+
+        wm.save_previous_all_workspaces(true);
+        assert_eq!(wm.take_previous_all_workspaces(), Some(true));
+
+        // Taking clears it, so a second take finds nothing.
+        assert!(wm.take_previous_all_workspaces().is_none());
+    }
+
+    #[test]
+    fn test_note_window_tracking() {
+        let wm = WindowManager::new();
+        assert!(wm.find_note_window("note-1", None).is_none());
+        assert!(wm.list_note_windows().is_empty());
+
+        wm.track_note_window("note-abc123", "note-1", Some("journal".to_string()));
+        assert_eq!(wm.find_note_window("note-1", Some("journal")), Some("note-abc123".to_string()));
+        assert!(wm.find_note_window("note-1", None).is_none());
+        assert_eq!(wm.list_note_windows(), vec![("note-abc123".to_string(), "note-1".to_string(), Some("journal".to_string()))]);
+
+        let removed = wm.untrack_note_window("note-abc123");
+        assert_eq!(removed, Some(("note-1".to_string(), Some("journal".to_string()))));
+        assert!(wm.find_note_window("note-1", Some("journal")).is_none());
+        assert!(wm.list_note_windows().is_empty());
+    }
+
+    #[test]
+    fn test_live_opacity_defaults_to_fully_opaque_and_tracks_updates() {
+        let wm = WindowManager::new();
+        assert_eq!(wm.get_live_opacity(), MAX_OPACITY);
+
+        wm.set_live_opacity(0.6);
+        assert_eq!(wm.get_live_opacity(), 0.6);
+    }
+
+    #[test]
+    fn test_target_opacity_switches_with_minimal_mode() {
+        let wm = WindowManager::new();
+        wm.set_normal_opacity(1.0);
+        wm.set_minimal_opacity(0.5);
+
+        assert_eq!(wm.target_opacity(), 1.0);
+
+        wm.set_minimal_mode(true);
+        assert_eq!(wm.target_opacity(), 0.5);
+
+        wm.set_minimal_mode(false);
+        assert_eq!(wm.target_opacity(), 1.0);
+    }
+
+    #[test]
+    fn test_set_normal_and_minimal_opacity_clamp_on_write() {
+        let wm = WindowManager::new();
+        wm.set_normal_opacity(5.0);
+        wm.set_minimal_opacity(-1.0);
+
+        assert_eq!(wm.get_normal_opacity(), MAX_OPACITY);
+        assert_eq!(wm.get_minimal_opacity(), MIN_OPACITY);
+    }
+
+    #[test]
+    fn test_step_opacity_moves_toward_target_without_overshoot() {
+        let stepped = WindowManager::step_opacity(0.3, 1.0, 100, 1.0);
+        // At 1.0/sec over 100ms, at most 0.1 of movement is allowed.
+        assert!(stepped > 0.3 && stepped <= 0.4, "stepped = {}", stepped);
+    }
+
+    #[test]
+    fn test_step_opacity_snaps_to_target_within_epsilon() {
+        let stepped = WindowManager::step_opacity(0.6999, 0.7, 1000, 1.0);
+        assert_eq!(stepped, 0.7);
+    }
+
+    #[test]
+    fn test_step_opacity_never_overshoots_a_large_step() {
+        let stepped = WindowManager::step_opacity(0.3, 1.0, 5000, 10.0);
+        assert_eq!(stepped, 1.0);
+    }
+
     #[test]
     fn test_opacity_clamping() {
         // Test values below minimum
@@ -222,42 +576,137 @@ mod tests {
 
     #[test]
     fn test_bounds_clamping() {
+        let wm = WindowManager::new();
+
         // Test bounds below minimum
         let small_bounds = WindowBounds {
             width: 100,
             height: 100,
             x: Some(50),
             y: Some(50),
+            ..Default::default()
         };
-        let clamped = WindowManager::clamp_bounds(small_bounds);
+        let clamped = wm.clamp_bounds(small_bounds);
         assert_eq!(clamped.width, 300);
         assert_eq!(clamped.height, 200);
         assert_eq!(clamped.x, Some(50));
         assert_eq!(clamped.y, Some(50));
-        
+
         // Test bounds at minimum
         let min_bounds = WindowBounds {
             width: 300,
             height: 200,
             x: None,
             y: None,
+            ..Default::default()
         };
-        let clamped = WindowManager::clamp_bounds(min_bounds);
+        let clamped = wm.clamp_bounds(min_bounds);
         assert_eq!(clamped.width, 300);
         assert_eq!(clamped.height, 200);
-        
+
         // Test bounds above minimum
         let large_bounds = WindowBounds {
             width: 1920,
             height: 1080,
             x: Some(0),
             y: Some(0),
+            ..Default::default()
         };
-        let clamped = WindowManager::clamp_bounds(large_bounds);
+        let clamped = wm.clamp_bounds(large_bounds);
         assert_eq!(clamped.width, 1920);
         assert_eq!(clamped.height, 1080);
     }
 
+    #[test]
+    fn test_bounds_clamping_respects_max_size_constraints() {
+        let wm = WindowManager::new();
+
+        // The default constraints allow up to MAX_WINDOW_WIDTH/HEIGHT.
+        let oversized = WindowBounds { width: 9000, height: 5000, x: None, y: None, ..Default::default() };
+        let clamped = wm.clamp_bounds(oversized.clone());
+        assert_eq!(clamped.width, MAX_WINDOW_WIDTH);
+        assert_eq!(clamped.height, MAX_WINDOW_HEIGHT);
+
+        // A caller-supplied smaller ceiling is respected instead.
+        wm.set_size_constraints((MIN_WINDOW_WIDTH, MIN_WINDOW_HEIGHT), (1280, 720));
+        let clamped = wm.clamp_bounds(oversized);
+        assert_eq!(clamped.width, 1280);
+        assert_eq!(clamped.height, 720);
+    }
+
+    fn display(x: i32, y: i32, width: u32, height: u32) -> DisplayInfo {
+        DisplayInfo { x, y, width, height }
+    }
+
+    #[test]
+    fn test_ensure_visible_leaves_onscreen_bounds_untouched() {
+        let wm = WindowManager::new();
+        let bounds = WindowBounds { width: 800, height: 600, x: Some(100), y: Some(100), ..Default::default() };
+        let displays = vec![display(0, 0, 1920, 1080)];
+
+        let result = wm.ensure_visible(bounds.clone(), &displays);
+        assert_eq!(result.x, bounds.x);
+        assert_eq!(result.y, bounds.y);
+    }
+
+    #[test]
+    fn test_ensure_visible_recenters_fully_offscreen_bounds() {
+        let wm = WindowManager::new();
+        let bounds = WindowBounds { width: 800, height: 600, x: Some(5000), y: Some(5000), ..Default::default() };
+        let displays = vec![display(0, 0, 1920, 1080)];
+
+        let result = wm.ensure_visible(bounds, &displays);
+        assert_eq!(result.x, Some((1920 - 800) / 2));
+        assert_eq!(result.y, Some((1080 - 600) / 2));
+    }
+
+    #[test]
+    fn test_ensure_visible_recenters_onto_display_with_largest_overlap() {
+        let wm = WindowManager::new();
+        // Barely overlaps either display (well under the 25% threshold), but
+        // overlaps the second display more than the first.
+        let bounds = WindowBounds { width: 800, height: 600, x: Some(1910), y: Some(1000), ..Default::default() };
+        let displays = vec![display(0, 0, 1920, 1080), display(1920, 0, 1920, 1080)];
+
+        let result = wm.ensure_visible(bounds, &displays);
+        assert_eq!(result.x, Some(1920 + (1920 - 800) / 2));
+        assert_eq!(result.y, Some((1080 - 600) / 2));
+    }
+
+    #[test]
+    fn test_ensure_visible_with_no_displays_leaves_bounds_as_clamped() {
+        let wm = WindowManager::new();
+        let bounds = WindowBounds { width: 800, height: 600, x: Some(5000), y: Some(5000), ..Default::default() };
+
+        let result = wm.ensure_visible(bounds.clone(), &[]);
+        assert_eq!(result.x, bounds.x);
+        assert_eq!(result.y, bounds.y);
+    }
+
+    #[test]
+    fn test_to_physical_and_to_logical_round_trip() {
+        for scale_factor in [1.0, 1.25, 1.5, 2.0] {
+            let logical = WindowBounds {
+                width: 800,
+                height: 600,
+                x: Some(100),
+                y: Some(50),
+                scale_factor,
+            };
+
+            let physical = logical.to_physical();
+            assert_eq!(physical.width, (800.0 * scale_factor).round() as u32);
+            assert_eq!(physical.height, (600.0 * scale_factor).round() as u32);
+            assert_eq!(physical.scale_factor, scale_factor);
+
+            let round_tripped = physical.to_logical();
+            assert_eq!(round_tripped.width, logical.width);
+            assert_eq!(round_tripped.height, logical.height);
+            assert_eq!(round_tripped.x, logical.x);
+            assert_eq!(round_tripped.y, logical.y);
+        }
+    }
+
     proptest! {
         #![proptest_config(ProptestConfig::with_cases(100))]
 
@@ -367,7 +816,8 @@ mod tests {
         /// the minimum size (300x200).
         #[test]
         fn prop_minimum_window_size_enforcement(bounds in any_window_bounds_strategy()) {
-            let clamped = WindowManager::clamp_bounds(bounds.clone());
+            let wm = WindowManager::new();
+            let clamped = wm.clamp_bounds(bounds.clone());
             
             // Width should be at least minimum
             prop_assert!(clamped.width >= MIN_WINDOW_WIDTH,
@@ -389,5 +839,31 @@ mod tests {
                 prop_assert_eq!(clamped.height, bounds.height);
             }
         }
+
+        /// For any current/target opacity pair, stepping toward the target
+        /// should never overshoot it and should always stay in range.
+        #[test]
+        fn prop_step_opacity_never_overshoots(
+            current in any_opacity_strategy(),
+            target in any_opacity_strategy(),
+            dt_ms in 0u32..=2000u32,
+            rate_per_sec in 0.0f64..=5.0f64,
+        ) {
+            let stepped = WindowManager::step_opacity(current, target, dt_ms, rate_per_sec);
+            let clamped_target = WindowManager::clamp_opacity(target);
+            let clamped_current = WindowManager::clamp_opacity(current);
+
+            prop_assert!(stepped >= MIN_OPACITY && stepped <= MAX_OPACITY);
+
+            // Moving from clamped_current toward clamped_target, stepped
+            // should never land past clamped_target.
+            if clamped_current <= clamped_target {
+                prop_assert!(stepped <= clamped_target + f64::EPSILON);
+                prop_assert!(stepped >= clamped_current - f64::EPSILON);
+            } else {
+                prop_assert!(stepped >= clamped_target - f64::EPSILON);
+                prop_assert!(stepped <= clamped_current + f64::EPSILON);
+            }
+        }
     }
 }
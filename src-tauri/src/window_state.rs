@@ -0,0 +1,283 @@
+use serde::{Deserialize, Serialize};
+use tauri::Runtime;
+
+use crate::models::DisplayInfo;
+
+/// Bit flags selecting which parts of a window's state to capture or
+/// restore, so a caller can e.g. persist position without touching the
+/// maximized/fullscreen flags. [`WindowState`]'s own fields are `Option`, so
+/// a flag left unset simply leaves the corresponding field `None` rather
+/// than requiring a separate mask at apply time.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct StateFlags(u8);
+
+impl StateFlags {
+    pub const POSITION: StateFlags = StateFlags(1 << 0);
+    pub const SIZE: StateFlags = StateFlags(1 << 1);
+    pub const MAXIMIZED: StateFlags = StateFlags(1 << 2);
+    pub const FULLSCREEN: StateFlags = StateFlags(1 << 3);
+    pub const VISIBLE: StateFlags = StateFlags(1 << 4);
+    pub const ALWAYS_ON_TOP: StateFlags = StateFlags(1 << 5);
+    pub const ALL: StateFlags = StateFlags(0b0011_1111);
+    pub const NONE: StateFlags = StateFlags(0);
+
+    /// Whether every bit set in `other` is also set in `self`.
+    pub fn contains(self, other: StateFlags) -> bool {
+        self.0 & other.0 == other.0
+    }
+}
+
+impl std::ops::BitOr for StateFlags {
+    type Output = StateFlags;
+    fn bitor(self, rhs: StateFlags) -> StateFlags {
+        StateFlags(self.0 | rhs.0)
+    }
+}
+
+impl std::ops::BitOrAssign for StateFlags {
+    fn bitor_assign(&mut self, rhs: StateFlags) {
+        self.0 |= rhs.0;
+    }
+}
+
+/// A window's persisted position/size/mode, one per window label. Every
+/// field is optional so a partial capture (see [`StateFlags`]) or a config
+/// file predating this subsystem doesn't force the rest to a default.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct WindowState {
+    pub x: Option<i32>,
+    pub y: Option<i32>,
+    pub width: Option<u32>,
+    pub height: Option<u32>,
+    pub maximized: Option<bool>,
+    pub fullscreen: Option<bool>,
+    pub visible: Option<bool>,
+    pub always_on_top: Option<bool>,
+}
+
+impl WindowState {
+    /// Folds a freshly observed `live` snapshot on top of `previous`.
+    ///
+    /// When the window is currently maximized, its live position/size *are*
+    /// the maximized bounds, which would make a poor "restore" target -
+    /// `previous`'s position/size are kept instead, so un-maximizing later
+    /// lands back at sane, pre-maximize dimensions. When not maximized, the
+    /// live position/size become the new baseline.
+    pub fn update_from_live_snapshot(previous: Option<&WindowState>, live: WindowState) -> WindowState {
+        if live.maximized == Some(true) {
+            WindowState {
+                x: previous.and_then(|p| p.x).or(live.x),
+                y: previous.and_then(|p| p.y).or(live.y),
+                width: previous.and_then(|p| p.width).or(live.width),
+                height: previous.and_then(|p| p.height).or(live.height),
+                ..live
+            }
+        } else {
+            live
+        }
+    }
+}
+
+/// Captures a live snapshot of `window`'s state, populating only the fields
+/// selected by `flags` (every other field is left `None`). There's no
+/// underlying getter for always-on-top, so [`StateFlags::ALWAYS_ON_TOP`] is
+/// accepted but never populates `always_on_top` - callers that track it
+/// should merge it in separately.
+pub fn capture_window_state<R: Runtime>(
+    window: &tauri::WebviewWindow<R>,
+    flags: StateFlags,
+) -> Result<WindowState, String> {
+    let mut state = WindowState::default();
+
+    if flags.contains(StateFlags::POSITION) {
+        let position = window.outer_position().map_err(|e| format!("Failed to get window position: {}", e))?;
+        state.x = Some(position.x);
+        state.y = Some(position.y);
+    }
+    if flags.contains(StateFlags::SIZE) {
+        let size = window.outer_size().map_err(|e| format!("Failed to get window size: {}", e))?;
+        state.width = Some(size.width);
+        state.height = Some(size.height);
+    }
+    if flags.contains(StateFlags::MAXIMIZED) {
+        state.maximized =
+            Some(window.is_maximized().map_err(|e| format!("Failed to check maximized state: {}", e))?);
+    }
+    if flags.contains(StateFlags::FULLSCREEN) {
+        state.fullscreen =
+            Some(window.is_fullscreen().map_err(|e| format!("Failed to check fullscreen state: {}", e))?);
+    }
+    if flags.contains(StateFlags::VISIBLE) {
+        state.visible = Some(window.is_visible().map_err(|e| format!("Failed to check visibility: {}", e))?);
+    }
+
+    Ok(state)
+}
+
+/// Applies every field `state` has set to `window`, gated by presence -
+/// fields left `None` (e.g. because they predate this subsystem, or were
+/// excluded from the original capture) are left untouched. The position is
+/// clamped to the window's current primary monitor first, so a window saved
+/// on a now-disconnected display doesn't open off-screen.
+pub fn apply_window_state<R: Runtime>(window: &tauri::WebviewWindow<R>, state: &WindowState) {
+    let clamped = match window.primary_monitor() {
+        Ok(Some(monitor)) => {
+            let position = monitor.position();
+            let size = monitor.size();
+            let display = DisplayInfo { x: position.x, y: position.y, width: size.width, height: size.height };
+            clamp_position_to_monitor(state, &display)
+        }
+        _ => state.clone(),
+    };
+
+    if let (Some(width), Some(height)) = (clamped.width, clamped.height) {
+        let _ = window.set_size(tauri::Size::Physical(tauri::PhysicalSize { width, height }));
+    }
+    if let (Some(x), Some(y)) = (clamped.x, clamped.y) {
+        let _ = window.set_position(tauri::Position::Physical(tauri::PhysicalPosition { x, y }));
+    }
+    if clamped.maximized == Some(true) {
+        let _ = window.maximize();
+    }
+    if let Some(fullscreen) = clamped.fullscreen {
+        let _ = window.set_fullscreen(fullscreen);
+    }
+    if let Some(always_on_top) = clamped.always_on_top {
+        let _ = window.set_always_on_top(always_on_top);
+    }
+    if clamped.visible == Some(false) {
+        let _ = window.hide();
+    }
+}
+
+/// Clamps `state`'s saved position so the window overlaps `monitor`'s work
+/// area, falling back to centering on it if there's no overlap at all (e.g.
+/// the window was saved on a monitor that's since been disconnected).
+/// Leaves `state` untouched if it has no saved position.
+pub fn clamp_position_to_monitor(state: &WindowState, monitor: &DisplayInfo) -> WindowState {
+    let mut clamped = state.clone();
+
+    let (Some(x), Some(y)) = (state.x, state.y) else {
+        return clamped;
+    };
+    let width = state.width.unwrap_or(800) as i32;
+    let height = state.height.unwrap_or(600) as i32;
+
+    let overlaps = x < monitor.x + monitor.width as i32
+        && x + width > monitor.x
+        && y < monitor.y + monitor.height as i32
+        && y + height > monitor.y;
+
+    if !overlaps {
+        let (cx, cy) = center_on_monitor(width as u32, height as u32, monitor);
+        clamped.x = Some(cx);
+        clamped.y = Some(cy);
+    }
+
+    clamped
+}
+
+/// Computes the top-left position that centers a `width`x`height` window on
+/// `monitor`.
+pub(crate) fn center_on_monitor(width: u32, height: u32, monitor: &DisplayInfo) -> (i32, i32) {
+    let x = monitor.x + (monitor.width as i32 - width as i32) / 2;
+    let y = monitor.y + (monitor.height as i32 - height as i32) / 2;
+    (x, y)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn primary_monitor() -> DisplayInfo {
+        DisplayInfo { x: 0, y: 0, width: 1920, height: 1080 }
+    }
+
+    #[test]
+    fn test_state_flags_contains() {
+        let flags = StateFlags::POSITION | StateFlags::SIZE;
+        assert!(flags.contains(StateFlags::POSITION));
+        assert!(flags.contains(StateFlags::SIZE));
+        assert!(!flags.contains(StateFlags::MAXIMIZED));
+    }
+
+    #[test]
+    fn test_state_flags_all_contains_every_flag() {
+        for flag in [
+            StateFlags::POSITION,
+            StateFlags::SIZE,
+            StateFlags::MAXIMIZED,
+            StateFlags::FULLSCREEN,
+            StateFlags::VISIBLE,
+            StateFlags::ALWAYS_ON_TOP,
+        ] {
+            assert!(StateFlags::ALL.contains(flag));
+        }
+    }
+
+    #[test]
+    fn test_update_from_live_snapshot_keeps_live_bounds_when_not_maximized() {
+        let previous = WindowState { x: Some(10), y: Some(10), width: Some(400), height: Some(300), ..Default::default() };
+        let live = WindowState { x: Some(50), y: Some(60), width: Some(800), height: Some(600), maximized: Some(false), ..Default::default() };
+
+        let merged = WindowState::update_from_live_snapshot(Some(&previous), live.clone());
+        assert_eq!(merged.x, live.x);
+        assert_eq!(merged.width, live.width);
+    }
+
+    #[test]
+    fn test_update_from_live_snapshot_preserves_pre_maximize_bounds_when_maximized() {
+        let previous = WindowState { x: Some(10), y: Some(10), width: Some(400), height: Some(300), ..Default::default() };
+        let live = WindowState { x: Some(0), y: Some(0), width: Some(1920), height: Some(1080), maximized: Some(true), ..Default::default() };
+
+        let merged = WindowState::update_from_live_snapshot(Some(&previous), live);
+        assert_eq!(merged.x, Some(10));
+        assert_eq!(merged.y, Some(10));
+        assert_eq!(merged.width, Some(400));
+        assert_eq!(merged.height, Some(300));
+        assert_eq!(merged.maximized, Some(true));
+    }
+
+    #[test]
+    fn test_update_from_live_snapshot_falls_back_to_live_when_no_previous() {
+        let live = WindowState { x: Some(0), y: Some(0), width: Some(1920), height: Some(1080), maximized: Some(true), ..Default::default() };
+        let merged = WindowState::update_from_live_snapshot(None, live.clone());
+        assert_eq!(merged, live);
+    }
+
+    #[test]
+    fn test_clamp_position_to_monitor_leaves_overlapping_position_untouched() {
+        let state = WindowState { x: Some(100), y: Some(100), width: Some(800), height: Some(600), ..Default::default() };
+        let clamped = clamp_position_to_monitor(&state, &primary_monitor());
+        assert_eq!(clamped.x, Some(100));
+        assert_eq!(clamped.y, Some(100));
+    }
+
+    #[test]
+    fn test_clamp_position_to_monitor_centers_when_off_screen() {
+        // Saved on a monitor far to the right that's since been disconnected.
+        let state = WindowState { x: Some(5000), y: Some(5000), width: Some(800), height: Some(600), ..Default::default() };
+        let monitor = primary_monitor();
+        let clamped = clamp_position_to_monitor(&state, &monitor);
+
+        assert_eq!(clamped.x, Some((monitor.width as i32 - 800) / 2));
+        assert_eq!(clamped.y, Some((monitor.height as i32 - 600) / 2));
+    }
+
+    #[test]
+    fn test_clamp_position_to_monitor_leaves_unset_position_untouched() {
+        let state = WindowState { width: Some(800), height: Some(600), ..Default::default() };
+        let clamped = clamp_position_to_monitor(&state, &primary_monitor());
+        assert_eq!(clamped.x, None);
+        assert_eq!(clamped.y, None);
+    }
+
+    #[test]
+    fn test_center_on_monitor() {
+        let monitor = primary_monitor();
+        let (x, y) = center_on_monitor(800, 600, &monitor);
+        assert_eq!(x, 560);
+        assert_eq!(y, 240);
+    }
+}